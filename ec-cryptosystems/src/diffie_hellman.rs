@@ -2,39 +2,52 @@ use crate::gen_random_biguint;
 use num_bigint::BigUint;
 use num_traits::One;
 use rust_ec::projective_point::EcPointP;
+use rust_ec::traits::{DomainParameters, Group, PointEncoding, PointOf};
 use rust_ec::EcInfo;
 
-pub struct PublicKey {
-    pub(crate) ec_info: EcInfo,
-    shared_point: EcPointP,
+pub struct PublicKey<D: DomainParameters = EcInfo> {
+    pub(crate) ec_info: D,
+    shared_point: PointOf<D>,
 }
 
-#[derive(PartialOrd, PartialEq, Debug, Clone)]
-pub struct SharedSecret {
-    ec_info: EcInfo,
-    shared_point: EcPointP,
+#[derive(PartialEq, Debug, Clone)]
+pub struct SharedSecret<D: DomainParameters = EcInfo> {
+    ec_info: D,
+    shared_point: PointOf<D>,
 }
 
 /// **EphemeralSecret** -- A short-lived Diffie-Hellman secret key that can only be used to compute a single SharedSecret.
-pub struct EphemeralSecret {
-    pub(crate) ec_info: EcInfo,
+/// Works over any domain parameters, e.g. `EcInfo`, `BinaryEcInfo` or `EdwardsEcInfo`
+pub struct EphemeralSecret<D: DomainParameters = EcInfo> {
+    pub(crate) ec_info: D,
     k: BigUint,
 }
 
-impl EphemeralSecret {
-    pub fn random(ec_info: &EcInfo) -> EphemeralSecret {
-        let k = gen_random_biguint(&BigUint::from(2_u8), &(ec_info.n.clone() - BigUint::one()));
+impl<D: DomainParameters + Clone> EphemeralSecret<D> {
+    /// **new** -- creates secret from already known key **k**, e.g. decoded X25519 scalar
+    pub fn new(ec_info: &D, k: &BigUint) -> EphemeralSecret<D> {
+        EphemeralSecret {
+            ec_info: ec_info.clone(),
+            k: k.clone(),
+        }
+    }
+
+    pub fn random(ec_info: &D) -> EphemeralSecret<D> {
+        let k = gen_random_biguint(&BigUint::from(2_u8), &(ec_info.order() - BigUint::one()));
         EphemeralSecret {
             ec_info: ec_info.clone(),
             k,
         }
     }
-    pub fn diffie_hellman(&self, pub_key: PublicKey) -> SharedSecret {
-        let curve = &self.ec_info.ecurve;
+
+    /// **diffie_hellman** -- k * Q, multiplication is constant-time if the backend supports it
+    /// (`DomainParameters::secret_mul`), e.g. ladder with fixed number of steps for `EcInfo`
+    pub fn diffie_hellman(&self, pub_key: PublicKey<D>) -> SharedSecret<D> {
+        let curve = self.ec_info.curve();
         SharedSecret {
             ec_info: self.ec_info.clone(),
             shared_point: curve
-                .transform_proj_point(&curve.proj_point_mul(&pub_key.shared_point, &self.k))
+                .normalize(&self.ec_info.secret_mul(&pub_key.shared_point, &self.k))
                 .unwrap(),
         }
     }
@@ -44,33 +57,62 @@ impl EphemeralSecret {
     }
 }
 
-impl From<&EphemeralSecret> for PublicKey {
-    fn from(value: &EphemeralSecret) -> Self {
+impl<D: DomainParameters + Clone> From<&EphemeralSecret<D>> for PublicKey<D> {
+    fn from(value: &EphemeralSecret<D>) -> Self {
         PublicKey {
             ec_info: value.ec_info.clone(),
-            shared_point: value
-                .ec_info
-                .ecurve
-                .proj_point_mul(&value.ec_info.bp, &value.k),
+            shared_point: value.ec_info.base_point_mul(&value.k),
         }
     }
 }
-impl PublicKey {
-    pub fn get_point_proj(&self) -> EcPointP {
+impl<D: DomainParameters + Clone> PublicKey<D> {
+    /// **new** -- creates peer's public key from the point, the key is fully validated
+    /// (SEC 1, section 3.2.2.1): not infinity, coordinates in range, on curve and n * Q = O
+    pub fn new(ec_info: &D, point: &PointOf<D>) -> rust_ec::Result<PublicKey<D>> {
+        ec_info.validate_point(point)?;
+        Ok(PublicKey {
+            ec_info: ec_info.clone(),
+            shared_point: ec_info.curve().normalize(point)?,
+        })
+    }
+
+    /// **from_bytes** -- decodes the key with `PointEncoding` of the curve
+    /// (SEC 1 octet string of any form for `EcInfo`) and validates it as `new`
+    pub fn from_bytes(ec_info: &D, bytes: &[u8]) -> rust_ec::Result<PublicKey<D>>
+    where
+        D::Curve: PointEncoding,
+    {
+        let point = ec_info.curve().decode(bytes)?;
+        PublicKey::new(ec_info, &point)
+    }
+
+    pub fn get_point(&self) -> PointOf<D> {
         self.shared_point.clone()
     }
 }
 
-impl SharedSecret {
+impl PublicKey<EcInfo> {
     pub fn get_point_proj(&self) -> EcPointP {
         self.shared_point.clone()
     }
+}
+
+impl<D: DomainParameters + Clone> SharedSecret<D> {
+    pub fn get_point(&self) -> PointOf<D> {
+        self.shared_point.clone()
+    }
 
-    pub fn get_ec_info(&self) -> EcInfo {
+    pub fn get_ec_info(&self) -> D {
         self.ec_info.clone()
     }
 }
 
+impl SharedSecret<EcInfo> {
+    pub fn get_point_proj(&self) -> EcPointP {
+        self.shared_point.clone()
+    }
+}
+
 // # use rand_core::OsRng;
 // # use x25519_dalek::{EphemeralSecret, PublicKey};
 // # let alice_secret = EphemeralSecret::new(OsRng);
@@ -2,46 +2,42 @@ use crate::diffie_hellman::{EphemeralSecret, PublicKey};
 use crate::hash;
 use num_bigint::{BigInt, BigUint, Sign};
 use num_traits::Zero;
-use rust_ec::affine_point::EcPointA;
 use rust_ec::helpers::{inverse, take_by_bigint_module, take_by_biguint_module};
+use rust_ec::traits::{CurveModel, DomainParameters, Field, Group};
 
 pub struct Signature {
     r: Vec<u8>,
     s: Vec<u8>,
 }
 
+/// **Signer** -- ECDSA over any domain parameters, x coordinate of kG is converted
+/// into integer with `Field::to_integer`
 pub struct Signer {}
 
 impl Signer {
-    pub fn sign(m: &[u8], priv_key: &EphemeralSecret) -> Signature {
+    pub fn sign<D: DomainParameters + Clone>(m: &[u8], priv_key: &EphemeralSecret<D>) -> Signature {
         let digest_num = BigInt::from_bytes_be(Sign::Plus, &hash(m));
-        let (_one_time_priv_key, _pub_key, r, k) = {
-            let (mut r, mut k, mut one_time_priv_key, mut pub_key) =
-                (BigInt::zero(), BigUint::zero(), None, None);
+        let (n, curve) = (priv_key.ec_info.order(), priv_key.ec_info.curve());
+        let (r, k) = {
+            let (mut r, mut k) = (BigInt::zero(), BigUint::zero());
             while r == BigInt::zero() {
-                let _ = one_time_priv_key.insert(EphemeralSecret::random(&priv_key.ec_info));
-                let _ = pub_key.insert(PublicKey::from(one_time_priv_key.as_ref().unwrap()));
-                let k_p: EcPointA = pub_key
-                    .as_ref()
-                    .unwrap()
-                    .get_point_proj()
-                    .to_affine(&one_time_priv_key.as_ref().unwrap().ec_info.ecurve)
+                let one_time_priv_key = EphemeralSecret::random(&priv_key.ec_info);
+                let pub_key = PublicKey::from(&one_time_priv_key);
+                let (k_p_x, _) = curve
+                    .to_affine(&pub_key.get_point())
                     .expect("Unable to generate inverse in signing!");
-                k = one_time_priv_key.as_ref().unwrap().get_key();
+                k = one_time_priv_key.get_key();
                 r = take_by_biguint_module(
-                    &k_p.get_x(),
-                    &one_time_priv_key.as_ref().unwrap().ec_info.n,
+                    &BigInt::from(curve.base_field().to_integer(&k_p_x)),
+                    &n,
                 );
             }
-            (one_time_priv_key.unwrap(), pub_key.unwrap(), r, k)
+            (r, k)
         };
-        let k_inv = inverse(&BigInt::from(k), &BigInt::from(priv_key.ec_info.n.clone()))
+        let k_inv = inverse(&BigInt::from(k), &BigInt::from(n.clone()))
             .expect("Failed to generate inverse in creating sign.");
         let d_a = BigInt::from(priv_key.get_key());
-        let s = take_by_biguint_module(
-            &(k_inv * (digest_num + d_a * r.clone())),
-            &priv_key.ec_info.n,
-        );
+        let s = take_by_biguint_module(&(k_inv * (digest_num + d_a * r.clone())), &n);
 
         Signature {
             r: r.to_bytes_be().1.to_vec(),
@@ -53,9 +49,14 @@ impl Signer {
 pub struct Verifier {}
 
 impl Verifier {
-    pub fn verify(m: &[u8], sign: &Signature, pub_key: &PublicKey) -> bool {
+    pub fn verify<D: DomainParameters + Clone>(
+        m: &[u8],
+        sign: &Signature,
+        pub_key: &PublicKey<D>,
+    ) -> bool {
         let digest = BigInt::from_bytes_be(Sign::Plus, &hash(m));
-        let n = BigInt::from(pub_key.ec_info.n.clone());
+        let (ec_info, curve) = (&pub_key.ec_info, pub_key.ec_info.curve());
+        let n = BigInt::from(ec_info.order());
         let (r, s) = (
             BigInt::from_bytes_be(Sign::Plus, &sign.r),
             BigInt::from_bytes_be(Sign::Plus, &sign.s),
@@ -66,22 +67,20 @@ impl Verifier {
             take_by_bigint_module(&(s_inv.clone() * &r), &n),
         );
 
-        let (part1, part2) = (
-            pub_key
-                .ec_info
-                .ecurve
-                .proj_point_mul(&pub_key.ec_info.bp, &u1.to_biguint().unwrap()),
-            pub_key
-                .ec_info
-                .ecurve
-                .proj_point_mul(&pub_key.get_point_proj(), &u2.to_biguint().unwrap()),
-        );
-
-        let lhs = pub_key.ec_info.ecurve.proj_point_add(&part1, &part2);
+        // u1 * G + u2 * Q with one chain of doublings
+        let lhs = curve.multi_mul(&[
+            (ec_info.generator(), u1.to_biguint().unwrap()),
+            (pub_key.get_point(), u2.to_biguint().unwrap()),
+        ]);
 
-        take_by_biguint_module(
-            &lhs.to_affine(&pub_key.ec_info.ecurve).unwrap().get_x(),
-            &pub_key.ec_info.n,
-        ) == r
+        match curve.to_affine(&lhs) {
+            Ok((x, _)) => {
+                take_by_biguint_module(
+                    &BigInt::from(curve.base_field().to_integer(&x)),
+                    &ec_info.order(),
+                ) == r
+            }
+            Err(_) => false,
+        }
     }
 }
@@ -1,6 +1,8 @@
 use crate::diffie_hellman::SharedSecret;
 use aes_gcm::aead::{Aead, OsRng};
 use aes_gcm::{AeadCore, Aes256Gcm, Key, KeyInit, Nonce};
+use rust_ec::traits::{CurveModel, DomainParameters, Field};
+use rust_ec::EcError;
 
 const BYTE_KEY_LEN: usize = 32;
 const NONCE_LEN: usize = 12;
@@ -13,48 +15,47 @@ pub struct EncryptedMessage {
 pub struct Encryptor {}
 
 impl Encryptor {
-    pub fn encrypt(msg: &[u8], shared_secret: &SharedSecret) -> EncryptedMessage {
+    /// **encrypt** -- fails for curves over fields larger than 256 bits, since x coordinate
+    /// of the shared point is used as AES-256 key
+    pub fn encrypt<D: DomainParameters + Clone>(
+        msg: &[u8],
+        shared_secret: &SharedSecret<D>,
+    ) -> rust_ec::Result<EncryptedMessage> {
         let key_bytes: [u8; BYTE_KEY_LEN] = rand::random();
         let ct_m = enc(&key_bytes, msg);
+        let ct_k = enc(&shared_key(shared_secret)?, &key_bytes);
 
-        let (proj_shared_point, ec_info) =
-            (shared_secret.get_point_proj(), shared_secret.get_ec_info());
-        let affine_shared_point = proj_shared_point
-            .to_affine(&ec_info.ecurve)
-            .expect("Failed to extract affine point.");
-        let ct_k = enc(
-            &{
-                let mut key = affine_shared_point.get_x().to_bytes_be().1;
-                if key.len() < BYTE_KEY_LEN {
-                    key.extend_from_slice(&vec![0; BYTE_KEY_LEN - key.len()])
-                }
-                key
-            },
-            &key_bytes,
-        );
-
-        EncryptedMessage { ct_m, ct_k }
+        Ok(EncryptedMessage { ct_m, ct_k })
     }
 
-    pub fn decrypt(enc_msg: &EncryptedMessage, shared_secret: &SharedSecret) -> Vec<u8> {
-        let (proj_shared_point, ec_info) =
-            (shared_secret.get_point_proj(), shared_secret.get_ec_info());
-        let affine_shared_point = proj_shared_point
-            .to_affine(&ec_info.ecurve)
-            .expect("Failed to extract affine point.");
+    pub fn decrypt<D: DomainParameters + Clone>(
+        enc_msg: &EncryptedMessage,
+        shared_secret: &SharedSecret<D>,
+    ) -> rust_ec::Result<Vec<u8>> {
+        let pt_k = dec(&shared_key(shared_secret)?, &enc_msg.ct_k);
+        Ok(dec(&pt_k, &enc_msg.ct_m))
+    }
+}
 
-        let pt_k = dec(
-            &{
-                let mut key = affine_shared_point.get_x().to_bytes_be().1;
-                if key.len() < BYTE_KEY_LEN {
-                    key.extend_from_slice(&vec![0; BYTE_KEY_LEN - key.len()])
-                }
-                key
-            },
-            &enc_msg.ct_k,
-        );
-        dec(&pt_k, &enc_msg.ct_m)
+// x coordinate of the shared point as integer, padded with zeros up to the key length
+fn shared_key<D: DomainParameters + Clone>(
+    shared_secret: &SharedSecret<D>,
+) -> rust_ec::Result<Vec<u8>> {
+    let ec_info = shared_secret.get_ec_info();
+    let curve = ec_info.curve();
+    let field = curve.base_field();
+    if field.bits() > 8 * BYTE_KEY_LEN as u64 {
+        return Err(EcError::IncorrectParameters(format!(
+            "x coordinate of {} bits doesn't fit into AES-256 key",
+            field.bits()
+        )));
+    }
+    let (x, _) = curve.to_affine(&shared_secret.get_point())?;
+    let mut key = field.to_integer(&x).to_bytes_be();
+    if key.len() < BYTE_KEY_LEN {
+        key.extend_from_slice(&vec![0; BYTE_KEY_LEN - key.len()])
     }
+    Ok(key)
 }
 
 fn enc(key: &[u8], msg: &[u8]) -> Vec<u8> {
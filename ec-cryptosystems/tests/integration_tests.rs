@@ -4,7 +4,17 @@ mod tests {
     use ec_cryptosystems::digital_signature::{Signer, Verifier};
     use ec_cryptosystems::directed_encryption::Encryptor;
 
-    use rust_ec::{EcInfo, PreGeneratedParams};
+    use num_bigint::BigInt;
+    use num_traits::One;
+    use rust_ec::binary_curve::{BinaryEcInfo, PreGeneratedBinaryParams};
+    use rust_ec::edwards::{EdwardsEcInfo, PreGeneratedEdwardsParams};
+    use rust_ec::encoding::PointFormat;
+    use rust_ec::montgomery::{
+        decode_scalar25519, encode_u_coordinate, MontgomeryCurve, PreGeneratedMontgomeryParams,
+    };
+    use rust_ec::projective_point::EcPointP;
+    use rust_ec::traits::{CurveModel, DomainParameters, Field};
+    use rust_ec::{EcError, EcInfo, PreGeneratedParams};
 
     #[test]
     fn sign() {
@@ -143,9 +153,184 @@ mod tests {
         let alice_shared_secret = alice_secret.diffie_hellman(bob_pub_key);
         let bob_shared_secret = bob_secret.diffie_hellman(alice_pub_key);
 
-        let ct = Encryptor::encrypt(&msg, &alice_shared_secret);
-        let pt = Encryptor::decrypt(&ct, &bob_shared_secret);
+        let ct = Encryptor::encrypt(&msg, &alice_shared_secret).unwrap();
+        let pt = Encryptor::decrypt(&ct, &bob_shared_secret).unwrap();
 
         assert_eq!(msg.as_slice(), pt.as_slice())
     }
+
+    #[test]
+    fn secp256k1() {
+        let ec = EcInfo::from(PreGeneratedParams::Secp256k1);
+        let msg = "Checking message for integrity".as_bytes().to_vec();
+        let flawed_msg = "Check1ng message for 1ntegr1ty".as_bytes().to_vec();
+
+        let alice_secret = EphemeralSecret::random(&ec);
+        let alice_pub_key = PublicKey::from(&alice_secret);
+        let bob_secret = EphemeralSecret::random(&ec);
+        let bob_pub_key = PublicKey::from(&bob_secret);
+
+        let sign = Signer::sign(&msg, &alice_secret);
+        assert!(Verifier::verify(&msg, &sign, &alice_pub_key));
+        assert!(!Verifier::verify(&flawed_msg, &sign, &alice_pub_key));
+
+        let alice_shared_secret = alice_secret.diffie_hellman(bob_pub_key);
+        let bob_shared_secret = bob_secret.diffie_hellman(alice_pub_key);
+        assert_eq!(alice_shared_secret, bob_shared_secret)
+    }
+
+    fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn diffie_hellman_curve25519() {
+        // https://datatracker.ietf.org/doc/html/rfc7748#section-6.1
+        let ec = EcInfo::from(PreGeneratedParams::Curve25519);
+        let montgomery = MontgomeryCurve::from(PreGeneratedMontgomeryParams::Curve25519);
+        let to_u_bytes = |p: &EcPointP| {
+            encode_u_coordinate(
+                &montgomery.from_weierstrass_proj_point(p).unwrap().get_x(),
+                32,
+            )
+        };
+
+        let alice_secret = EphemeralSecret::new(
+            &ec,
+            &decode_scalar25519(
+                &from_hex("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a")
+                    .try_into()
+                    .unwrap(),
+            ),
+        );
+        let alice_pub_key = PublicKey::from(&alice_secret);
+        let bob_secret = EphemeralSecret::new(
+            &ec,
+            &decode_scalar25519(
+                &from_hex("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb")
+                    .try_into()
+                    .unwrap(),
+            ),
+        );
+        let bob_pub_key = PublicKey::from(&bob_secret);
+        assert_eq!(
+            to_u_bytes(&alice_pub_key.get_point_proj()),
+            from_hex("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+        );
+        assert_eq!(
+            to_u_bytes(&bob_pub_key.get_point_proj()),
+            from_hex("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f")
+        );
+
+        let alice_shared_secret = alice_secret.diffie_hellman(bob_pub_key);
+        let bob_shared_secret = bob_secret.diffie_hellman(alice_pub_key);
+        assert_eq!(alice_shared_secret, bob_shared_secret);
+        assert_eq!(
+            to_u_bytes(&alice_shared_secret.get_point_proj()),
+            from_hex("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742")
+        );
+    }
+    #[test]
+    fn public_key_validation() {
+        let ec = EcInfo::from(PreGeneratedParams::P256);
+        let alice_secret = EphemeralSecret::random(&ec);
+        let bob_secret = EphemeralSecret::random(&ec);
+        let bob_point = PublicKey::from(&bob_secret).get_point_proj();
+
+        // peer's key received as a point or as SEC 1 octet string
+        let bob_pub_key = PublicKey::new(&ec, &bob_point).unwrap();
+        let encoded = ec
+            .ecurve
+            .encode_proj_point(&bob_point, PointFormat::Compressed)
+            .unwrap();
+        let decoded_pub_key = PublicKey::from_bytes(&ec, &encoded).unwrap();
+        assert_eq!(
+            decoded_pub_key
+                .get_point_proj()
+                .to_affine(&ec.ecurve)
+                .unwrap(),
+            bob_point.to_affine(&ec.ecurve).unwrap()
+        );
+        assert_eq!(
+            alice_secret.diffie_hellman(bob_pub_key),
+            alice_secret.diffie_hellman(decoded_pub_key)
+        );
+
+        let bob_affine = bob_point.to_affine(&ec.ecurve).unwrap();
+        let off_curve = EcPointP::new(
+            &bob_affine.get_x(),
+            &(bob_affine.get_y() + BigInt::one()),
+            &BigInt::one(),
+        );
+        for point in [EcPointP::neutral(), off_curve] {
+            assert!(matches!(
+                PublicKey::new(&ec, &point),
+                Err(EcError::InvalidPublicKey(_))
+            ));
+        }
+        assert!(PublicKey::from_bytes(&ec, &[0x00]).is_err());
+        let mut corrupted = encoded.clone();
+        corrupted[0] = 0x04;
+        assert!(PublicKey::from_bytes(&ec, &corrupted).is_err());
+
+        // small subgroup attack on Wei25519: n * P = O doesn't hold for points of small order
+        let c25519 = EcInfo::from(PreGeneratedParams::Curve25519);
+        let small_order = (1_u8..)
+            .filter_map(|x| c25519.ecurve.lift_x(&BigInt::from(x), false).ok())
+            .map(|p| {
+                c25519
+                    .ecurve
+                    .proj_point_mul(&EcPointP::from_affine(&p), &c25519.n)
+            })
+            .find(|p| !p.is_inf())
+            .unwrap();
+        assert!(matches!(
+            PublicKey::new(&c25519, &small_order),
+            Err(EcError::InvalidPublicKey(_))
+        ));
+    }
+
+    #[test]
+    fn other_curve_models() {
+        fn check<D: DomainParameters + Clone + PartialEq + std::fmt::Debug>(ec: &D) {
+            let msg = "Checking message for integrity".as_bytes().to_vec();
+            let flawed_msg = "Check1ng message for 1ntegr1ty".as_bytes().to_vec();
+
+            let alice_secret = EphemeralSecret::random(ec);
+            let alice_pub_key = PublicKey::from(&alice_secret);
+            let bob_secret = EphemeralSecret::random(ec);
+            let bob_pub_key =
+                PublicKey::new(ec, &PublicKey::from(&bob_secret).get_point()).unwrap();
+
+            let sign = Signer::sign(&msg, &alice_secret);
+            assert!(Verifier::verify(&msg, &sign, &alice_pub_key));
+            assert!(!Verifier::verify(&flawed_msg, &sign, &alice_pub_key));
+
+            let alice_shared_secret = alice_secret.diffie_hellman(bob_pub_key);
+            let bob_shared_secret = bob_secret.diffie_hellman(alice_pub_key);
+            assert_eq!(alice_shared_secret, bob_shared_secret);
+
+            // x coordinate is used as AES-256 key, it doesn't fit for fields over 256 bits
+            match ec.curve().base_field().bits() {
+                bits if bits <= 256 => {
+                    let ct = Encryptor::encrypt(&msg, &alice_shared_secret).unwrap();
+                    assert_eq!(Encryptor::decrypt(&ct, &bob_shared_secret).unwrap(), msg);
+                }
+                _ => assert!(matches!(
+                    Encryptor::encrypt(&msg, &alice_shared_secret),
+                    Err(EcError::IncorrectParameters(_))
+                )),
+            }
+        }
+        check(&BinaryEcInfo::from(PreGeneratedBinaryParams::B163));
+        check(&BinaryEcInfo::from(PreGeneratedBinaryParams::K233));
+        check(&BinaryEcInfo::from(PreGeneratedBinaryParams::K283));
+        check(&EcInfo::from(PreGeneratedParams::P384));
+        check(&EdwardsEcInfo::from(
+            PreGeneratedEdwardsParams::Edwards25519,
+        ));
+    }
 }
@@ -0,0 +1,33 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use num_bigint::BigUint;
+use num_traits::Num;
+use rust_ec::{EcInfo, FieldArithmetic, PreGeneratedParams};
+
+// proj_point_mul with BigInt and fixed-width Montgomery field elements on the same curves
+fn proj_point_mul(c: &mut Criterion) {
+    let k = BigUint::from_str_radix(
+        "c51e4753afdec1e6b6c6a5b992f43f8dd0c7a8933072708b6522468b2ffb06fd",
+        16,
+    )
+    .unwrap();
+    let mut group = c.benchmark_group("proj_point_mul");
+    for params in [
+        PreGeneratedParams::P192,
+        PreGeneratedParams::P256,
+        PreGeneratedParams::P384,
+    ] {
+        let ec_info = EcInfo::from(&params);
+        for arithmetic in [FieldArithmetic::BigInt, FieldArithmetic::FixedWidth] {
+            let curve = ec_info.ecurve.clone().with_arithmetic(arithmetic);
+            group.bench_with_input(
+                BenchmarkId::new(format!("{arithmetic:?}"), format!("{params:?}")),
+                &k,
+                |b, k| b.iter(|| curve.proj_point_mul(black_box(&ec_info.bp), black_box(k))),
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, proj_point_mul);
+criterion_main!(benches);
@@ -0,0 +1,579 @@
+use crate::affine_point::EcPointA;
+use crate::binary_field::BinaryField;
+use crate::helpers::bigint_cswap;
+use crate::ld_point::EcPointLD;
+use crate::traits::{CurveModel, DomainParameters, Group};
+use crate::EcError;
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::{Num, One, Zero};
+
+/// **BinaryCurve** -- represents non-supersingular elliptic curve over GF(2^m)
+/// points satisfy the following equation
+/// y^2 + xy = x^3 + ax^2 + b, where b != 0
+#[derive(PartialOrd, PartialEq, Clone, Debug)]
+pub struct BinaryCurve {
+    a: BigInt,
+    b: BigInt,
+    field: BinaryField,
+}
+
+/// **BinaryEcInfo** -- EC domain parameters over GF(2^m), the same as `EcInfo` for prime fields
+#[derive(PartialEq, Clone, Debug)]
+pub struct BinaryEcInfo {
+    pub bp: EcPointA,
+    /// **n** -- order of base point
+    pub n: BigUint,
+    /// **h** -- cofactor, i.e. #E(GF(2^m)) = n * h
+    pub h: BigUint,
+    pub curve: BinaryCurve,
+}
+
+/// **PreGeneratedBinaryParams** -- Koblitz (K-) and pseudo-random (B-) curves from FIPS 186-4,
+/// D.1.3 and the example curve from DSTU 4145-2002. Only M163 with the base point from the appendix
+/// of DSTU 4145-2002 is provided, other recommended curves of the standard aren't included,
+/// for their field sizes there are only reduction polynomials `BinaryField::dstu4145`
+#[derive(Debug)]
+pub enum PreGeneratedBinaryParams {
+    K163,
+    B163,
+    K233,
+    B233,
+    K283,
+    B283,
+    K409,
+    B409,
+    K571,
+    B571,
+    /// curve over GF(2^163) with a = 1 from appendix of DSTU 4145-2002
+    Dstu4145M163,
+}
+
+impl From<PreGeneratedBinaryParams> for BinaryEcInfo {
+    fn from(value: PreGeneratedBinaryParams) -> Self {
+        BinaryEcInfo::from(&value)
+    }
+}
+impl From<&PreGeneratedBinaryParams> for BinaryEcInfo {
+    fn from(value: &PreGeneratedBinaryParams) -> Self {
+        // (m, middle terms of f(x), a, b, x, y, n, h)
+        let (m, exponents, a, b, x, y, n, h): (u64, &[u64], _, _, _, _, _, u8) = match value {
+            PreGeneratedBinaryParams::K163 => (
+                163,
+                &[7, 6, 3],
+                "1",
+                "1",
+                "2fe13c0537bbc11acaa07d793de4e6d5e5c94eee8",
+                "289070fb05d38ff58321f2e800536d538ccdaa3d9",
+                "4000000000000000000020108a2e0cc0d99f8a5ef",
+                2,
+            ),
+            PreGeneratedBinaryParams::B163 => (
+                163,
+                &[7, 6, 3],
+                "1",
+                "20a601907b8c953ca1481eb10512f78744a3205fd",
+                "3f0eba16286a2d57ea0991168d4994637e8343e36",
+                "0d51fbc6c71a0094fa2cdd545b11c5c0c797324f1",
+                "40000000000000000000292fe77e70c12a4234c33",
+                2,
+            ),
+            PreGeneratedBinaryParams::K233 => (
+                233,
+                &[74],
+                "0",
+                "1",
+                "17232ba853a7e731af129f22ff4149563a419c26bf50a4c9d6eefad6126",
+                "1db537dece819b7f70f555a67c427a8cd9bf18aeb9b56e0c11056fae6a3",
+                "8000000000000000000000000000069d5bb915bcd46efb1ad5f173abdf",
+                4,
+            ),
+            PreGeneratedBinaryParams::B233 => (
+                233,
+                &[74],
+                "1",
+                "66647ede6c332c7f8c0923bb58213b333b20e9ce4281fe115f7d8f90ad",
+                "0fac9dfcbac8313bb2139f1bb755fef65bc391f8b36f8f8eb7371fd558b",
+                "1006a08a41903350678e58528bebf8a0beff867a7ca36716f7e01f81052",
+                "1000000000000000000000000000013e974e72f8a6922031d2603cfe0d7",
+                2,
+            ),
+            PreGeneratedBinaryParams::K283 => (
+                283,
+                &[12, 7, 5],
+                "0",
+                "1",
+                "503213f78ca44883f1a3b8162f188e553cd265f23c1567a16876913b0c2ac2458492836",
+                "1ccda380f1c9e318d90f95d07e5426fe87e45c0e8184698e45962364e34116177dd2259",
+                "1ffffffffffffffffffffffffffffffffffe9ae2ed07577265dff7f94451e061e163c61",
+                4,
+            ),
+            PreGeneratedBinaryParams::B283 => (
+                283,
+                &[12, 7, 5],
+                "1",
+                "27b680ac8b8596da5a4af8a19a0303fca97fd7645309fa2a581485af6263e313b79a2f5",
+                "5f939258db7dd90e1934f8c70b0dfec2eed25b8557eac9c80e2e198f8cdbecd86b12053",
+                "3676854fe24141cb98fe6d4b20d02b4516ff702350eddb0826779c813f0df45be8112f4",
+                "3ffffffffffffffffffffffffffffffffffef90399660fc938a90165b042a7cefadb307",
+                2,
+            ),
+            PreGeneratedBinaryParams::K409 => (
+                409,
+                &[87],
+                "0",
+                "1",
+                "060f05f658f49c1ad3ab1890f7184210efd0987e307c84c27accfb8f9f67cc2c460189eb5aaaa62ee222eb1b35540cfe9023746",
+                "1e369050b7c4e42acba1dacbf04299c3460782f918ea427e6325165e9ea10e3da5f6c42e9c55215aa9ca27a5863ec48d8e0286b",
+                "7ffffffffffffffffffffffffffffffffffffffffffffffffffe5f83b2d4ea20400ec4557d5ed3e3e7ca5b4b5c83b8e01e5fcf",
+                4,
+            ),
+            PreGeneratedBinaryParams::B409 => (
+                409,
+                &[87],
+                "1",
+                "21a5c2c8ee9feb5c4b9a753b7b476b7fd6422ef1f3dd674761fa99d6ac27c8a9a197b272822f6cd57a55aa4f50ae317b13545f",
+                "15d4860d088ddb3496b0c6064756260441cde4af1771d4db01ffe5b34e59703dc255a868a1180515603aeab60794e54bb7996a7",
+                "061b1cfab6be5f32bbfa78324ed106a7636b9c5a7bd198d0158aa4f5488d08f38514f1fdf4b4f40d2181b3681c364ba0273c706",
+                "10000000000000000000000000000000000000000000000000001e2aad6a612f33307be5fa47c3c9e052f838164cd37d9a21173",
+                2,
+            ),
+            PreGeneratedBinaryParams::K571 => (
+                571,
+                &[10, 5, 2],
+                "0",
+                "1",
+                "26eb7a859923fbc82189631f8103fe4ac9ca2970012d5d46024804801841ca44370958493b205e647da304db4ceb08cbbd1ba39494776fb988b47174dca88c7e2945283a01c8972",
+                "349dc807f4fbf374f4aeade3bca95314dd58cec9f307a54ffc61efc006d8a2c9d4979c0ac44aea74fbebbb9f772aedcb620b01a7ba7af1b320430c8591984f601cd4c143ef1c7a3",
+                "20000000000000000000000000000000000000000000000000000000000000000000000131850e1f19a63e4b391a8db917f4138b630d84be5d639381e91deb45cfe778f637c1001",
+                4,
+            ),
+            PreGeneratedBinaryParams::B571 => (
+                571,
+                &[10, 5, 2],
+                "1",
+                "2f40e7e2221f295de297117b7f3d62f5c6a97ffcb8ceff1cd6ba8ce4a9a18ad84ffabbd8efa59332be7ad6756a66e294afd185a78ff12aa520e4de739baca0c7ffeff7f2955727a",
+                "303001d34b856296c16c0d40d3cd7750a93d1d2955fa80aa5f40fc8db7b2abdbde53950f4c0d293cdd711a35b67fb1499ae60038614f1394abfa3b4c850d927e1e7769c8eec2d19",
+                "37bf27342da639b6dccfffeb73d69d78c6c27a6009cbbca1980f8533921e8a684423e43bab08a576291af8f461bb2a8b3531d2f0485c19b16e2f1516e23dd3c1a4827af1b8ac15b",
+                "3ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe661ce18ff55987308059b186823851ec7dd9ca1161de93d5174d66e8382e9bb2fe84e47",
+                2,
+            ),
+            PreGeneratedBinaryParams::Dstu4145M163 => (
+                163,
+                &[7, 6, 3],
+                "1",
+                "5ff6108462a2dc8210ab403925e638a19c1455d21",
+                "72d867f93a93ac27df9ff01affe74885c8c540420",
+                "0224a9c3947852b97c5599d5f4ab81122adc3fd9b",
+                "400000000000000000002bec12be2262d39bcf14d",
+                2,
+            ),
+        };
+        let parse = |x: &str| BigInt::from_str_radix(x, 16).unwrap();
+        let field = BinaryField::new(m, exponents).unwrap();
+        BinaryEcInfo::new(
+            EcPointA::new(&parse(x), &parse(y)),
+            BigUint::from_str_radix(n, 16).unwrap(),
+            BigUint::from(h),
+            BinaryCurve::new(&parse(a), &parse(b), field).unwrap(),
+        )
+        .unwrap()
+    }
+}
+
+impl BinaryEcInfo {
+    /// **new** -- creates EC domain parameters, checks that base point lies on the curve
+    /// and that it has order **n**, i.e. n * bp = O
+    pub fn new(bp: EcPointA, n: BigUint, h: BigUint, curve: BinaryCurve) -> crate::Result<Self> {
+        if !curve.check_affine_point(&bp) || bp.is_inf() {
+            return Err(EcError::IncorrectBasePoint(format!(
+                "({:x}, {:x}) doesn't lie on the curve",
+                bp.get_x(),
+                bp.get_y()
+            )));
+        }
+        if !curve
+            .ld_point_mul(&EcPointLD::from_affine(&bp), &n)
+            .is_inf()
+        {
+            return Err(EcError::IncorrectBasePoint(format!("n * bp != O, n: {n}")));
+        }
+        Ok(BinaryEcInfo { bp, n, h, curve })
+    }
+
+    /// **base_point_mul** -- k * bp in affine coordinates with constant-time ladder
+    pub fn base_point_mul(&self, k: &BigUint) -> crate::Result<EcPointA> {
+        self.curve
+            .ld_to_affine(&DomainParameters::base_point_mul(self, k))
+    }
+}
+
+impl BinaryCurve {
+    pub fn new(a: &BigInt, b: &BigInt, field: BinaryField) -> crate::Result<Self> {
+        if a.sign() == Sign::Minus || b.sign() == Sign::Minus {
+            return Err(EcError::IncorrectParameters(
+                "a and b have to be non-negative bit strings".to_string(),
+            ));
+        }
+        let (a, b) = (field.reduce(a), field.reduce(b));
+        if b.is_zero() {
+            return Err(EcError::IncorrectParameters(
+                "b = 0, curve is singular".to_string(),
+            ));
+        }
+        Ok(BinaryCurve { a, b, field })
+    }
+
+    pub fn get_a(&self) -> BigInt {
+        self.a.clone()
+    }
+    pub fn get_b(&self) -> BigInt {
+        self.b.clone()
+    }
+    pub fn get_field(&self) -> &BinaryField {
+        &self.field
+    }
+
+    // y^2 + xy = x^3 + ax^2 + b
+    pub fn check_affine_point(&self, p: &EcPointA) -> bool {
+        if p.is_inf() {
+            return true;
+        }
+        let f = &self.field;
+        let (x, y) = (p.get_x(), p.get_y());
+        if !f.contains(&x) || !f.contains(&y) {
+            return false;
+        }
+        let x2 = f.square(&x);
+        let lhs = f.square(&y) ^ f.mul(&x, &y);
+        let rhs = f.mul(&x2, &(&x ^ &self.a)) ^ &self.b;
+        lhs == rhs
+    }
+
+    // Y^2 + XYZ = X^3Z + aX^2Z^2 + bZ^4
+    pub fn check_ld_point(&self, p: &EcPointLD) -> bool {
+        if p.is_inf() {
+            return true;
+        }
+        let f = &self.field;
+        let (x2, z2) = (f.square(&p.x), f.square(&p.z));
+        let lhs = f.square(&p.y) ^ f.mul(&f.mul(&p.x, &p.y), &p.z);
+        let rhs =
+            f.mul(&x2, &(f.mul(&p.x, &p.z) ^ f.mul(&self.a, &z2))) ^ f.mul(&self.b, &f.square(&z2));
+        lhs == rhs
+    }
+
+    /// **affine_point_negative** -- -(x, y) = (x, x + y)
+    pub fn affine_point_negative(&self, p: &EcPointA) -> EcPointA {
+        if p.is_inf() {
+            return p.clone();
+        }
+        EcPointA::new(&p.get_x(), &(p.get_x() ^ p.get_y()))
+    }
+
+    /// **affine_point_add** -- lambda = (y1 + y2) / (x1 + x2),
+    /// x3 = lambda^2 + lambda + x1 + x2 + a, y3 = lambda(x1 + x3) + x3 + y1
+    pub fn affine_point_add(&self, p1: &EcPointA, p2: &EcPointA) -> crate::Result<EcPointA> {
+        if p1.is_inf() {
+            return Ok(p2.clone());
+        }
+        if p2.is_inf() {
+            return Ok(p1.clone());
+        }
+        let f = &self.field;
+        let (x1, y1, x2, y2) = (p1.get_x(), p1.get_y(), p2.get_x(), p2.get_y());
+        if x1 == x2 {
+            return match y1 == y2 {
+                true => self.affine_point_double(p1),
+                false => Ok(infinity()),
+            };
+        }
+        let lambda = f.mul(&(&y1 ^ &y2), &f.inverse(&(&x1 ^ &x2))?);
+        let x3 = f.square(&lambda) ^ &lambda ^ &x1 ^ &x2 ^ &self.a;
+        let y3 = f.mul(&lambda, &(&x1 ^ &x3)) ^ &x3 ^ &y1;
+        Ok(EcPointA::new(&x3, &y3))
+    }
+
+    /// **affine_point_double** -- lambda = x + y / x,
+    /// x3 = lambda^2 + lambda + a, y3 = x^2 + (lambda + 1)x3
+    pub fn affine_point_double(&self, p: &EcPointA) -> crate::Result<EcPointA> {
+        let x = p.get_x();
+        if p.is_inf() || x.is_zero() {
+            return Ok(infinity());
+        }
+        let f = &self.field;
+        let lambda = &x ^ f.mul(&p.get_y(), &f.inverse(&x)?);
+        let x3 = f.square(&lambda) ^ &lambda ^ &self.a;
+        let y3 = f.square(&x) ^ f.mul(&(lambda ^ BigInt::one()), &x3);
+        Ok(EcPointA::new(&x3, &y3))
+    }
+
+    /// **affine_point_mul** -- k * P, computed in Lopez-Dahab coordinates
+    pub fn affine_point_mul(&self, p: &EcPointA, k: &BigUint) -> crate::Result<EcPointA> {
+        self.ld_to_affine(&self.ld_point_mul(&EcPointLD::from_affine(p), k))
+    }
+
+    /// **ld_to_affine** -- transforms (X : Y : Z) => (X/Z, Y/Z^2)
+    pub fn ld_to_affine(&self, p: &EcPointLD) -> crate::Result<EcPointA> {
+        if p.is_inf() {
+            return Ok(infinity());
+        }
+        let f = &self.field;
+        let z_inv = f.inverse(&p.z)?;
+        Ok(EcPointA::new(
+            &f.mul(&p.x, &z_inv),
+            &f.mul(&p.y, &f.square(&z_inv)),
+        ))
+    }
+
+    /// **ld_point_negative** -- -(X : Y : Z) = (X : XZ + Y : Z)
+    pub fn ld_point_negative(&self, p: &EcPointLD) -> EcPointLD {
+        if p.is_inf() {
+            return p.clone();
+        }
+        EcPointLD {
+            x: p.x.clone(),
+            y: self.field.mul(&p.x, &p.z) ^ &p.y,
+            z: p.z.clone(),
+        }
+    }
+
+    /// **ld_point_double** -- Z3 = X1^2 * Z1^2, X3 = X1^4 + b * Z1^4,
+    /// Y3 = b * Z1^4 * Z3 + X3 * (a * Z3 + Y1^2 + b * Z1^4)
+    pub fn ld_point_double(&self, p: &EcPointLD) -> EcPointLD {
+        if p.is_inf() || p.x.is_zero() {
+            return EcPointLD::neutral();
+        }
+        let f = &self.field;
+        let (x2, z2) = (f.square(&p.x), f.square(&p.z));
+        let bz4 = f.mul(&self.b, &f.square(&z2));
+        let z3 = f.mul(&x2, &z2);
+        let x3 = f.square(&x2) ^ &bz4;
+        let y3 = f.mul(&bz4, &z3) ^ f.mul(&x3, &(f.mul(&self.a, &z3) ^ f.square(&p.y) ^ &bz4));
+        EcPointLD {
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    /// **ld_point_add** -- A = Y1 * Z2^2 + Y2 * Z1^2, B = X1 * Z2 + X2 * Z1, C = B * Z1 * Z2,
+    /// so lambda = A / C and Z3 = C^2, X3 = A^2 + AC + B^2C + aC^2,
+    /// Y3 = (AC + Z3)X3 + C^2 * B * Z2 * (A * X1 + B * Z2 * Y1). Z2 = 1 saves a few multiplications
+    pub fn ld_point_add(&self, p1: &EcPointLD, p2: &EcPointLD) -> EcPointLD {
+        if p1.is_inf() {
+            return p2.clone();
+        }
+        if p2.is_inf() {
+            return p1.clone();
+        }
+        let f = &self.field;
+        let (z1_2, z2_2) = (f.square(&p1.z), f.square(&p2.z));
+        let a = f.mul(&p1.y, &z2_2) ^ f.mul(&p2.y, &z1_2);
+        let (b1, b2) = (f.mul(&p1.x, &p2.z), f.mul(&p2.x, &p1.z));
+        let b = &b1 ^ &b2;
+        if b.is_zero() {
+            return match a.is_zero() {
+                true => self.ld_point_double(p1),
+                false => EcPointLD::neutral(),
+            };
+        }
+        let c = match p2.z.is_one() {
+            true => f.mul(&b, &p1.z),
+            false => f.mul(&f.mul(&b, &p1.z), &p2.z),
+        };
+        let c2 = f.square(&c);
+        let e = f.mul(&a, &c);
+        let x3 = f.square(&a) ^ &e ^ f.mul(&f.square(&b), &c) ^ f.mul(&self.a, &c2);
+        // B * Z2 * Y1 and B * Z2
+        let (bz2, bz2y1) = match p2.z.is_one() {
+            true => (b.clone(), f.mul(&b, &p1.y)),
+            false => {
+                let bz2 = f.mul(&b, &p2.z);
+                let bz2y1 = f.mul(&bz2, &p1.y);
+                (bz2, bz2y1)
+            }
+        };
+        let y3 = f.mul(&(&e ^ &c2), &x3) ^ f.mul(&f.mul(&c2, &bz2), &(f.mul(&a, &p1.x) ^ bz2y1));
+        EcPointLD {
+            x: x3,
+            y: y3,
+            z: c2,
+        }
+    }
+
+    /// **ld_point_mul** -- double-and-add from MSB to LSB in Lopez-Dahab coordinates
+    pub fn ld_point_mul(&self, p: &EcPointLD, k: &BigUint) -> EcPointLD {
+        let mut r = EcPointLD::neutral();
+        for i in (0..k.bits()).rev() {
+            r = self.ld_point_double(&r);
+            if k.bit(i) {
+                r = self.ld_point_add(&r, p);
+            }
+        }
+        r
+    }
+
+    /// **ld_point_mul_ct** -- k * P for secret k with Lopez-Dahab Montgomery ladder (x-only,
+    /// Guide to ECC, algorithm 3.40) of bit length of `order` steps, (X : Z) pairs are swapped
+    /// with masks, y is recovered from kP and (k + 1)P in the end with inversion by Fermat
+    pub fn ld_point_mul_ct(&self, p: &EcPointLD, k: &BigUint, order: &BigUint) -> EcPointLD {
+        let f = &self.field;
+        // x-only arithmetic doesn't work for O and points of order 2, none of them is secret
+        let a = match self.ld_to_affine(p) {
+            Ok(a) if !a.is_inf() && !a.get_x().is_zero() => a,
+            _ => return self.ld_point_mul(p, &(k % order)),
+        };
+        let (x, y) = (a.get_x(), a.get_y());
+
+        let bits = order.bits();
+        // k as little-endian limbs padded to the number of steps
+        let mut k_limbs = (k % order).to_u64_digits();
+        k_limbs.resize(bits.div_ceil(64) as usize + 1, 0);
+        let k_bit = |i: u64| (k_limbs[(i / 64) as usize] >> (i % 64)) & 1;
+
+        let (q, limbs) = (BigInt::one() << f.get_m(), f.get_m().div_ceil(64) as usize);
+        // r0 = (X1 : Z1) = O, r1 = (X2 : Z2) = P, invariant: r1 - r0 = P
+        let (mut x1, mut z1) = (BigInt::one(), BigInt::zero());
+        let (mut x2, mut z2) = (x.clone(), BigInt::one());
+        let mut swap = 0;
+        for i in (0..bits).rev() {
+            let bit = k_bit(i);
+            bigint_cswap(&q, &mut x1, &mut x2, swap ^ bit, limbs);
+            bigint_cswap(&q, &mut z1, &mut z2, swap ^ bit, limbs);
+            swap = bit;
+            // r1 = r0 + r1: Z = (X1 * Z2 + X2 * Z1)^2, X = x * Z + X1 * Z2 * X2 * Z1
+            let (t1, t2) = (f.mul(&x1, &z2), f.mul(&x2, &z1));
+            z2 = f.square(&(&t1 ^ &t2));
+            x2 = f.mul(&x, &z2) ^ f.mul(&t1, &t2);
+            // r0 = 2 * r0: X = X1^4 + b * Z1^4, Z = X1^2 * Z1^2
+            let (x1_2, z1_2) = (f.square(&x1), f.square(&z1));
+            x1 = f.square(&x1_2) ^ f.mul(&self.b, &f.square(&z1_2));
+            z1 = f.mul(&x1_2, &z1_2);
+        }
+        bigint_cswap(&q, &mut x1, &mut x2, swap, limbs);
+        bigint_cswap(&q, &mut z1, &mut z2, swap, limbs);
+
+        // kP = O or (k + 1)P = O, i.e. kP = -P
+        if z1.is_zero() {
+            return EcPointLD::neutral();
+        }
+        if z2.is_zero() {
+            return self.ld_point_negative(&EcPointLD::from_affine(&a));
+        }
+        // x3 = X1/Z1, y3 = (x + x3) * ((X1 + x * Z1) * (X2 + x * Z2) + (x^2 + y) * Z1 * Z2)
+        // / (x * Z1 * Z2) + y
+        let z1z2 = f.mul(&z1, &z2);
+        let inv = f.pow(
+            &f.mul(&x, &z1z2),
+            &((BigInt::one() << f.get_m()) - BigInt::from(2_u8)),
+        );
+        let x3 = f.mul(&f.mul(&x1, &f.mul(&x, &z2)), &inv);
+        let t = f.mul(&(&x1 ^ f.mul(&x, &z1)), &(&x2 ^ f.mul(&x, &z2)))
+            ^ f.mul(&(f.square(&x) ^ &y), &z1z2);
+        let y3 = f.mul(&f.mul(&(&x ^ &x3), &t), &inv) ^ &y;
+        EcPointLD::from_affine(&EcPointA::new(&x3, &y3))
+    }
+
+    /// **lift_x** -- point (x, y) with the given bit y~ (SEC 1, 2.3.4: the rightmost bit of y/x),
+    /// y = xz, where z^2 + z = x + a + b/x^2, and y = sqrt(b) for x = 0
+    pub fn lift_x(&self, x: &BigInt, y_bit: bool) -> crate::Result<EcPointA> {
+        let f = &self.field;
+        let x = f.reduce(x);
+        if x.is_zero() {
+            return Ok(EcPointA::new(&x, &f.sqrt(&self.b)));
+        }
+        let beta = &x ^ &self.a ^ f.mul(&self.b, &f.inverse(&f.square(&x))?);
+        let z = f.solve_quadratic(&beta).ok_or_else(|| {
+            EcError::PointNotOnCurve(format!(
+                "z^2 + z = x + a + b/x^2 has no roots for x = {x:x}"
+            ))
+        })?;
+        let z = match z.bit(0) == y_bit {
+            true => z,
+            false => z ^ BigInt::one(),
+        };
+        Ok(EcPointA::new(&x, &f.mul(&x, &z)))
+    }
+}
+
+fn infinity() -> EcPointA {
+    EcPointA {
+        x: BigInt::zero(),
+        y: BigInt::zero(),
+        is_inf: true,
+    }
+}
+
+impl Group for BinaryCurve {
+    type Point = EcPointLD;
+
+    fn identity(&self) -> EcPointLD {
+        EcPointLD::neutral()
+    }
+    fn is_identity(&self, p: &EcPointLD) -> bool {
+        p.is_inf()
+    }
+    fn add(&self, a: &EcPointLD, b: &EcPointLD) -> EcPointLD {
+        self.ld_point_add(a, b)
+    }
+    fn neg(&self, a: &EcPointLD) -> EcPointLD {
+        self.ld_point_negative(a)
+    }
+    fn normalize(&self, p: &EcPointLD) -> crate::Result<EcPointLD> {
+        Ok(EcPointLD::from_affine(&self.ld_to_affine(p)?))
+    }
+    fn double(&self, a: &EcPointLD) -> EcPointLD {
+        self.ld_point_double(a)
+    }
+    fn mul(&self, a: &EcPointLD, k: &BigUint) -> EcPointLD {
+        self.ld_point_mul(a, k)
+    }
+}
+
+impl CurveModel for BinaryCurve {
+    type BaseField = BinaryField;
+
+    fn base_field(&self) -> BinaryField {
+        self.field.clone()
+    }
+    fn contains(&self, p: &EcPointLD) -> bool {
+        self.check_ld_point(p)
+    }
+    fn to_affine(&self, p: &EcPointLD) -> crate::Result<(BigInt, BigInt)> {
+        if p.is_inf() {
+            return Err(EcError::PointNotOnCurve(
+                "point at infinity has no affine coordinates".to_string(),
+            ));
+        }
+        let a = self.ld_to_affine(p)?;
+        Ok((a.get_x(), a.get_y()))
+    }
+    /// a and b are reduced by `new`, so the derived comparison is enough
+    fn is_same_curve(&self, other: &BinaryCurve) -> bool {
+        self == other
+    }
+}
+
+impl DomainParameters for BinaryEcInfo {
+    type Curve = BinaryCurve;
+
+    fn curve(&self) -> &BinaryCurve {
+        &self.curve
+    }
+    fn generator(&self) -> EcPointLD {
+        EcPointLD::from_affine(&self.bp)
+    }
+    fn order(&self) -> BigUint {
+        self.n.clone()
+    }
+    fn cofactor(&self) -> BigUint {
+        self.h.clone()
+    }
+    fn base_point_mul(&self, k: &BigUint) -> EcPointLD {
+        self.secret_mul(&self.generator(), k)
+    }
+    /// constant-time ladder with bit length of n * h steps
+    fn secret_mul(&self, p: &EcPointLD, k: &BigUint) -> EcPointLD {
+        self.curve.ld_point_mul_ct(p, k, &(&self.n * &self.h))
+    }
+}
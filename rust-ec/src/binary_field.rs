@@ -0,0 +1,317 @@
+use crate::factorization::factorize;
+use crate::traits::Field;
+use crate::EcError;
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::{One, ToPrimitive, Zero};
+
+/// **BinaryField** -- GF(2^m) in polynomial basis, element a_(m-1)x^(m-1) + ... + a_1x + a_0
+/// is stored as non-negative BigInt with bits a_i. Reduction polynomial
+/// f(x) = x^m + x^k_1 + ... + 1 is a trinomial or a pentanomial, so reduction takes
+/// a few shifts and xors instead of division
+#[derive(PartialOrd, PartialEq, Clone, Debug)]
+pub struct BinaryField {
+    m: u64,
+    /// **exponents** -- middle terms k_i of f(x), m > k_1 > ... > 0
+    exponents: Vec<u64>,
+    /// **f** -- reduction polynomial f(x) as bit string
+    f: BigInt,
+}
+
+impl BinaryField {
+    /// **new** -- GF(2^m) with f(x) = x^m + sum(x^k_i) + 1, f(x) has to be irreducible
+    pub fn new(m: u64, exponents: &[u64]) -> crate::Result<Self> {
+        let mut exponents = exponents.to_vec();
+        exponents.sort_unstable_by(|a, b| b.cmp(a));
+        exponents.dedup();
+        if m < 2 || exponents.iter().any(|&k| k == 0 || k >= m) {
+            return Err(EcError::IncorrectParameters(format!(
+                "middle terms of f(x) have to be in [1, m - 1], m: {m}, k: {exponents:?}"
+            )));
+        }
+        let mut f = (BigInt::one() << m) | BigInt::one();
+        for &k in &exponents {
+            f |= BigInt::one() << k;
+        }
+        let field = BinaryField { m, exponents, f };
+        match field.is_irreducible() {
+            true => Ok(field),
+            false => Err(EcError::IncorrectParameters(format!(
+                "f(x) = {:x} is reducible over GF(2)",
+                field.f
+            ))),
+        }
+    }
+
+    /// **dstu4145** -- GF(2^m) with reduction polynomial recommended by DSTU 4145-2002
+    /// for m = 163, 167, 173, 179, 191, 233, 257, 307, 367 and 431
+    pub fn dstu4145(m: u64) -> crate::Result<Self> {
+        let exponents: &[u64] = match m {
+            163 => &[7, 6, 3],
+            167 => &[6],
+            173 => &[10, 2, 1],
+            179 => &[4, 2, 1],
+            191 => &[9],
+            233 => &[9, 4, 1],
+            257 => &[12],
+            307 => &[8, 4, 2],
+            367 => &[21],
+            431 => &[5, 3, 1],
+            _ => {
+                return Err(EcError::IncorrectParameters(format!(
+                    "DSTU 4145 doesn't recommend field of degree {m}"
+                )))
+            }
+        };
+        BinaryField::new(m, exponents)
+    }
+
+    pub fn get_m(&self) -> u64 {
+        self.m
+    }
+    /// **get_f** -- reduction polynomial as bit string
+    pub fn get_f(&self) -> BigInt {
+        self.f.clone()
+    }
+
+    /// **contains** -- a is a polynomial of degree < m
+    pub fn contains(&self, a: &BigInt) -> bool {
+        a.sign() != Sign::Minus && a.bits() <= self.m
+    }
+
+    /// **add** -- a + b = a xor b, subtraction is the same operation
+    pub fn add(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        a ^ b
+    }
+
+    /// **reduce** -- c mod f(x) for polynomial c of any degree,
+    /// x^m = x^k_1 + ... + 1, so the part above x^m is folded down with shifts
+    pub fn reduce(&self, c: &BigInt) -> BigInt {
+        let mask = (BigInt::one() << self.m) - BigInt::one();
+        let mut c = c.clone();
+        while c.bits() > self.m {
+            let high = &c >> self.m;
+            c &= &mask;
+            c ^= &high;
+            for &k in &self.exponents {
+                c ^= &high << k;
+            }
+        }
+        c
+    }
+
+    /// **mul** -- carry-less product of polynomials, then reduction by f(x)
+    pub fn mul(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        self.reduce(&clmul(a, b))
+    }
+
+    /// **square** -- squaring is linear in GF(2^m): bits are spread with zeros in between
+    pub fn square(&self, a: &BigInt) -> BigInt {
+        let mut spread = Vec::new();
+        for limb in a.iter_u64_digits() {
+            spread.push(spread_bits(limb as u32));
+            spread.push(spread_bits((limb >> 32) as u32));
+        }
+        self.reduce(&from_limbs(&spread))
+    }
+
+    /// **pow** -- a^e with square-and-multiply
+    pub fn pow(&self, a: &BigInt, e: &BigInt) -> BigInt {
+        let mut r = BigInt::one();
+        for i in (0..e.bits()).rev() {
+            r = self.square(&r);
+            if e.bit(i) {
+                r = self.mul(&r, a);
+            }
+        }
+        r
+    }
+
+    /// **inverse** -- a^(-1) with extended Euclidean algorithm for binary polynomials
+    pub fn inverse(&self, a: &BigInt) -> crate::Result<BigInt> {
+        let mut u = self.reduce(a);
+        if u.is_zero() {
+            return Err(EcError::ImpossibleToFindInverse(format!(
+                "0 in GF(2^{})",
+                self.m
+            )));
+        }
+        // invariants: g1 * a = u, g2 * a = v mod f
+        let mut v = self.f.clone();
+        let (mut g1, mut g2) = (BigInt::one(), BigInt::zero());
+        while !u.is_one() {
+            let j = u.bits() as i64 - v.bits() as i64;
+            if j < 0 {
+                std::mem::swap(&mut u, &mut v);
+                std::mem::swap(&mut g1, &mut g2);
+            }
+            let j = j.unsigned_abs();
+            u ^= &v << j;
+            g1 ^= &g2 << j;
+        }
+        Ok(self.reduce(&g1))
+    }
+
+    /// **sqrt** -- a^(2^(m - 1)), every element of GF(2^m) is a square
+    pub fn sqrt(&self, a: &BigInt) -> BigInt {
+        (1..self.m).fold(a.clone(), |r, _| self.square(&r))
+    }
+
+    /// **trace** -- Tr(a) = a + a^2 + a^4 + ... + a^(2^(m - 1)), either 0 or 1
+    pub fn trace(&self, a: &BigInt) -> u8 {
+        let mut t = a.clone();
+        let mut r = a.clone();
+        for _ in 1..self.m {
+            t = self.square(&t);
+            r ^= &t;
+        }
+        r.to_u8().unwrap()
+    }
+
+    /// **solve_quadratic** -- z with z^2 + z = c, the other root is z + 1.
+    /// Solution exists iff Tr(c) = 0. Half-trace is used for odd m,
+    /// IEEE 1363, A.4.7 for even m
+    pub fn solve_quadratic(&self, c: &BigInt) -> Option<BigInt> {
+        let c = self.reduce(c);
+        if self.trace(&c) != 0 {
+            return None;
+        }
+        if self.m & 1 == 1 {
+            // H(c) = sum(c^(2^(2i))), i = 0..(m - 1) / 2
+            let mut t = c.clone();
+            let mut z = c.clone();
+            for _ in 0..(self.m - 1) / 2 {
+                t = self.square(&self.square(&t));
+                z ^= &t;
+            }
+            return Some(z);
+        }
+        // tau with Tr(tau) = 1 is searched among x, x + 1, x^2, ...
+        let mut tau = BigInt::from(2_u8);
+        loop {
+            if self.trace(&tau) == 1 {
+                let (mut z, mut w) = (BigInt::zero(), c.clone());
+                for _ in 1..self.m {
+                    let w2 = self.square(&w);
+                    z = self.square(&z) ^ self.mul(&w2, &tau);
+                    w = w2 ^ &c;
+                }
+                if self.square(&z) ^ &z == c {
+                    return Some(z);
+                }
+            }
+            tau += 1;
+        }
+    }
+
+    // Rabin's test: x^(2^m) = x mod f and gcd(x^(2^(m/r)) - x, f) = 1 for prime r | m
+    fn is_irreducible(&self) -> bool {
+        let x = BigInt::from(2_u8);
+        let frobenius = |k: u64| (0..k).fold(x.clone(), |r, _| self.square(&r));
+        if frobenius(self.m) != x {
+            return false;
+        }
+        factorize(&BigUint::from(self.m))
+            .factors
+            .iter()
+            .all(|(r, _)| {
+                poly_gcd(&(frobenius(self.m / r.to_u64().unwrap()) ^ &x), &self.f).is_one()
+            })
+    }
+}
+
+// product of binary polynomials without reduction, comb method over 64-bit limbs
+fn clmul(a: &BigInt, b: &BigInt) -> BigInt {
+    let (a, b): (Vec<u64>, Vec<u64>) =
+        (a.iter_u64_digits().collect(), b.iter_u64_digits().collect());
+    if a.is_empty() || b.is_empty() {
+        return BigInt::zero();
+    }
+    let mut r = vec![0_u64; a.len() + b.len() + 1];
+    // shifted holds b * x^j
+    let mut shifted = b.clone();
+    shifted.push(0);
+    for j in 0..64 {
+        for (i, limb) in a.iter().enumerate() {
+            if (limb >> j) & 1 == 1 {
+                for (k, s) in shifted.iter().enumerate() {
+                    r[i + k] ^= s;
+                }
+            }
+        }
+        let mut carry = 0;
+        for s in shifted.iter_mut() {
+            let next = *s >> 63;
+            *s = (*s << 1) | carry;
+            carry = next;
+        }
+    }
+    from_limbs(&r)
+}
+
+fn from_limbs(limbs: &[u64]) -> BigInt {
+    let digits: Vec<u32> = limbs
+        .iter()
+        .flat_map(|x| [*x as u32, (*x >> 32) as u32])
+        .collect();
+    BigInt::from_slice(Sign::Plus, &digits)
+}
+
+// b_31 ... b_1 b_0 -> 0 b_31 ... 0 b_1 0 b_0
+fn spread_bits(x: u32) -> u64 {
+    let mut x = x as u64;
+    x = (x | (x << 16)) & 0x0000_ffff_0000_ffff;
+    x = (x | (x << 8)) & 0x00ff_00ff_00ff_00ff;
+    x = (x | (x << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+    x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+    (x | (x << 1)) & 0x5555_5555_5555_5555
+}
+
+fn poly_gcd(a: &BigInt, b: &BigInt) -> BigInt {
+    let (mut a, mut b) = (a.clone(), b.clone());
+    while !b.is_zero() {
+        // a mod b
+        while a.bits() >= b.bits() {
+            let shift = a.bits() - b.bits();
+            a ^= &b << shift;
+        }
+        std::mem::swap(&mut a, &mut b);
+    }
+    a
+}
+
+impl Field for BinaryField {
+    type Element = BigInt;
+
+    fn zero(&self) -> BigInt {
+        BigInt::zero()
+    }
+    fn one(&self) -> BigInt {
+        BigInt::one()
+    }
+    fn add(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        BinaryField::add(self, a, b)
+    }
+    fn sub(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        BinaryField::add(self, a, b)
+    }
+    fn mul(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        BinaryField::mul(self, a, b)
+    }
+    fn inverse(&self, a: &BigInt) -> crate::Result<BigInt> {
+        BinaryField::inverse(self, a)
+    }
+    /// bit string of the polynomial read as an integer
+    fn to_integer(&self, a: &BigInt) -> BigUint {
+        self.reduce(a).to_biguint().unwrap()
+    }
+    fn bits(&self) -> u64 {
+        self.m
+    }
+
+    fn neg(&self, a: &BigInt) -> BigInt {
+        a.clone()
+    }
+    fn square(&self, a: &BigInt) -> BigInt {
+        BinaryField::square(self, a)
+    }
+}
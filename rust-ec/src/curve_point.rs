@@ -0,0 +1,313 @@
+use crate::traits::{CurveModel, DomainParameters, Group, PointOf};
+use crate::{EcError, EcInfo};
+use num_bigint::{BigInt, BigUint, Sign};
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::sync::Arc;
+
+/// **CurvePoint** -- point bound to its domain parameters, supports `+`, `-`, `*` by a scalar,
+/// `+=`, `-=` and `Iterator::sum`. Operators panic if the points belong to different domain
+/// parameters, `checked_add`, `checked_sub` and `sum` return `EcError::CurveMismatch` instead,
+/// summing into `Option<CurvePoint>` gives None
+pub struct CurvePoint<D: DomainParameters = EcInfo> {
+    info: Arc<D>,
+    point: PointOf<D>,
+}
+
+impl<D: DomainParameters> CurvePoint<D> {
+    /// **new** -- binds P to the domain parameters, P has to be O or lie on the curve
+    pub fn new(info: &Arc<D>, p: &PointOf<D>) -> crate::Result<Self> {
+        let curve = info.curve();
+        if !curve.is_identity(p) && !curve.contains(p) {
+            return Err(EcError::PointNotOnCurve(format!("({p:?})")));
+        }
+        Ok(CurvePoint {
+            info: info.clone(),
+            point: p.clone(),
+        })
+    }
+
+    /// **generator** -- base point of the domain parameters
+    pub fn generator(info: &Arc<D>) -> Self {
+        CurvePoint {
+            info: info.clone(),
+            point: info.generator(),
+        }
+    }
+
+    /// **identity** -- point at infinity (neutral element) of the curve
+    pub fn identity(info: &Arc<D>) -> Self {
+        CurvePoint {
+            info: info.clone(),
+            point: info.curve().identity(),
+        }
+    }
+
+    pub fn get_point(&self) -> &PointOf<D> {
+        &self.point
+    }
+
+    pub fn get_info(&self) -> &Arc<D> {
+        &self.info
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.info.curve().is_identity(&self.point)
+    }
+
+    /// **is_same_curve** -- both points are bound to the same domain parameters: curve
+    /// equation, base point, n and h. Settings of scalar multiplication aren't compared
+    pub fn is_same_curve(&self, other: &CurvePoint<D>) -> bool {
+        let (a, b) = (&self.info, &other.info);
+        Arc::ptr_eq(a, b)
+            || (a.curve().is_same_curve(b.curve())
+                && a.order() == b.order()
+                && a.cofactor() == b.cofactor()
+                && a.curve().point_eq(&a.generator(), &b.generator()))
+    }
+
+    /// **normalize** -- canonical representation of the point, see `Group::normalize`
+    pub fn normalize(&self) -> crate::Result<Self> {
+        Ok(self.with_point(self.info.curve().normalize(&self.point)?))
+    }
+
+    /// **checked_add** -- P + Q, fails if the points are bound to different domain parameters
+    pub fn checked_add(&self, other: &CurvePoint<D>) -> crate::Result<Self> {
+        self.check_same_curve(other)?;
+        Ok(self.with_point(self.info.curve().add(&self.point, &other.point)))
+    }
+
+    /// **checked_sub** -- P - Q, fails if the points are bound to different domain parameters
+    pub fn checked_sub(&self, other: &CurvePoint<D>) -> crate::Result<Self> {
+        self.check_same_curve(other)?;
+        Ok(self.with_point(self.info.curve().sub(&self.point, &other.point)))
+    }
+
+    /// **sum** -- P_1 + ... + P_m, the identity of the curve for no points, unlike `Iterator::sum`.
+    /// Fails if any of the points isn't bound to `info`
+    pub fn sum<I>(info: &Arc<D>, points: I) -> crate::Result<Self>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<CurvePoint<D>>,
+    {
+        points
+            .into_iter()
+            .try_fold(CurvePoint::identity(info), |acc, p| {
+                acc.checked_add(p.borrow())
+            })
+    }
+
+    // None for no points, the domain parameters are taken from the first point
+    fn try_sum<I>(points: I) -> Option<crate::Result<Self>>
+    where
+        I: Iterator,
+        I::Item: Borrow<CurvePoint<D>>,
+    {
+        let mut points = points.peekable();
+        let info = points.peek()?.borrow().info.clone();
+        Some(CurvePoint::sum(&info, points))
+    }
+
+    fn check_same_curve(&self, other: &CurvePoint<D>) -> crate::Result<()> {
+        match self.is_same_curve(other) {
+            true => Ok(()),
+            false => Err(EcError::CurveMismatch(format!(
+                "({:?}) and ({:?})",
+                self.point, other.point
+            ))),
+        }
+    }
+
+    fn with_point(&self, point: PointOf<D>) -> Self {
+        CurvePoint {
+            info: self.info.clone(),
+            point,
+        }
+    }
+}
+
+impl<D: DomainParameters> Clone for CurvePoint<D> {
+    fn clone(&self) -> Self {
+        self.with_point(self.point.clone())
+    }
+}
+
+// points are equal, if they are bound to the same curve and equal after normalization
+impl<D: DomainParameters> PartialEq for CurvePoint<D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_same_curve(other) && self.info.curve().point_eq(&self.point, &other.point)
+    }
+}
+
+impl<D: DomainParameters> Debug for CurvePoint<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.point)
+    }
+}
+
+/// Panics if the points are bound to different domain parameters, see `checked_add`
+impl<D: DomainParameters> Add<&CurvePoint<D>> for &CurvePoint<D> {
+    type Output = CurvePoint<D>;
+
+    fn add(self, rhs: &CurvePoint<D>) -> CurvePoint<D> {
+        self.checked_add(rhs).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+/// Panics if the points are bound to different domain parameters, see `checked_add`
+impl<D: DomainParameters> Add<&CurvePoint<D>> for CurvePoint<D> {
+    type Output = CurvePoint<D>;
+
+    fn add(self, rhs: &CurvePoint<D>) -> CurvePoint<D> {
+        &self + rhs
+    }
+}
+
+/// Panics if the points are bound to different domain parameters, see `checked_add`
+impl<D: DomainParameters> Add for CurvePoint<D> {
+    type Output = CurvePoint<D>;
+
+    fn add(self, rhs: CurvePoint<D>) -> CurvePoint<D> {
+        &self + &rhs
+    }
+}
+
+/// Panics if the points are bound to different domain parameters, see `checked_sub`
+impl<D: DomainParameters> Sub<&CurvePoint<D>> for &CurvePoint<D> {
+    type Output = CurvePoint<D>;
+
+    fn sub(self, rhs: &CurvePoint<D>) -> CurvePoint<D> {
+        self.checked_sub(rhs).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+/// Panics if the points are bound to different domain parameters, see `checked_sub`
+impl<D: DomainParameters> Sub<&CurvePoint<D>> for CurvePoint<D> {
+    type Output = CurvePoint<D>;
+
+    fn sub(self, rhs: &CurvePoint<D>) -> CurvePoint<D> {
+        &self - rhs
+    }
+}
+
+/// Panics if the points are bound to different domain parameters, see `checked_sub`
+impl<D: DomainParameters> Sub for CurvePoint<D> {
+    type Output = CurvePoint<D>;
+
+    fn sub(self, rhs: CurvePoint<D>) -> CurvePoint<D> {
+        &self - &rhs
+    }
+}
+
+impl<D: DomainParameters> Neg for &CurvePoint<D> {
+    type Output = CurvePoint<D>;
+
+    fn neg(self) -> CurvePoint<D> {
+        self.with_point(self.info.curve().neg(&self.point))
+    }
+}
+
+impl<D: DomainParameters> Neg for CurvePoint<D> {
+    type Output = CurvePoint<D>;
+
+    fn neg(self) -> CurvePoint<D> {
+        -&self
+    }
+}
+
+impl<D: DomainParameters> Mul<&BigUint> for &CurvePoint<D> {
+    type Output = CurvePoint<D>;
+
+    fn mul(self, k: &BigUint) -> CurvePoint<D> {
+        self.with_point(self.info.curve().mul(&self.point, k))
+    }
+}
+
+impl<D: DomainParameters> Mul<&BigUint> for CurvePoint<D> {
+    type Output = CurvePoint<D>;
+
+    fn mul(self, k: &BigUint) -> CurvePoint<D> {
+        &self * k
+    }
+}
+
+// k * P = |k| * (-P) for negative k
+impl<D: DomainParameters> Mul<&BigInt> for &CurvePoint<D> {
+    type Output = CurvePoint<D>;
+
+    fn mul(self, k: &BigInt) -> CurvePoint<D> {
+        match k.sign() {
+            Sign::Minus => &(-self) * k.magnitude(),
+            _ => self * k.magnitude(),
+        }
+    }
+}
+
+impl<D: DomainParameters> Mul<&BigInt> for CurvePoint<D> {
+    type Output = CurvePoint<D>;
+
+    fn mul(self, k: &BigInt) -> CurvePoint<D> {
+        &self * k
+    }
+}
+
+/// Panics if the points are bound to different domain parameters, see `checked_add`
+impl<D: DomainParameters> AddAssign<&CurvePoint<D>> for CurvePoint<D> {
+    fn add_assign(&mut self, rhs: &CurvePoint<D>) {
+        *self = &*self + rhs;
+    }
+}
+
+/// Panics if the points are bound to different domain parameters, see `checked_add`
+impl<D: DomainParameters> AddAssign for CurvePoint<D> {
+    fn add_assign(&mut self, rhs: CurvePoint<D>) {
+        *self += &rhs;
+    }
+}
+
+/// Panics if the points are bound to different domain parameters, see `checked_sub`
+impl<D: DomainParameters> SubAssign<&CurvePoint<D>> for CurvePoint<D> {
+    fn sub_assign(&mut self, rhs: &CurvePoint<D>) {
+        *self = &*self - rhs;
+    }
+}
+
+/// Panics if the points are bound to different domain parameters, see `checked_sub`
+impl<D: DomainParameters> SubAssign for CurvePoint<D> {
+    fn sub_assign(&mut self, rhs: CurvePoint<D>) {
+        *self -= &rhs;
+    }
+}
+
+/// Panics for no points (the curve is unknown) or points bound to different domain parameters,
+/// see `CurvePoint::sum` and the implementation for `Option<CurvePoint>`
+impl<D: DomainParameters> Sum for CurvePoint<D> {
+    fn sum<I: Iterator<Item = CurvePoint<D>>>(iter: I) -> CurvePoint<D> {
+        CurvePoint::try_sum(iter)
+            .expect("sum of no points, use CurvePoint::sum with domain parameters")
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+impl<'a, D: DomainParameters> Sum<&'a CurvePoint<D>> for CurvePoint<D> {
+    fn sum<I: Iterator<Item = &'a CurvePoint<D>>>(iter: I) -> CurvePoint<D> {
+        CurvePoint::try_sum(iter)
+            .expect("sum of no points, use CurvePoint::sum with domain parameters")
+            .unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+/// None for no points or points bound to different domain parameters
+impl<D: DomainParameters> Sum<CurvePoint<D>> for Option<CurvePoint<D>> {
+    fn sum<I: Iterator<Item = CurvePoint<D>>>(iter: I) -> Option<CurvePoint<D>> {
+        CurvePoint::try_sum(iter)?.ok()
+    }
+}
+
+impl<'a, D: DomainParameters> Sum<&'a CurvePoint<D>> for Option<CurvePoint<D>> {
+    fn sum<I: Iterator<Item = &'a CurvePoint<D>>>(iter: I) -> Option<CurvePoint<D>> {
+        CurvePoint::try_sum(iter)?.ok()
+    }
+}
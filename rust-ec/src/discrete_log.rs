@@ -0,0 +1,310 @@
+use crate::affine_point::EcPointA;
+use crate::factorization::factorize;
+use crate::projective_point::EcPointP;
+use crate::{ECurve, EcError, EcInfo};
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, ToPrimitive, Zero};
+use std::collections::HashMap;
+
+/// **RHO_PARTITIONS** -- number of precomputed steps in r-adding walk (Teske recommends r = 20)
+const RHO_PARTITIONS: usize = 20;
+/// **RHO_MAX_WALKS** -- number of walks after which `pollard_rho` gives up
+const RHO_MAX_WALKS: u64 = 1 << 16;
+/// **LAMBDA_ATTEMPTS** -- number of tame kangaroos (with different jump functions) in `pollard_lambda`
+const LAMBDA_ATTEMPTS: u64 = 8;
+/// **PH_BSGS_BITS** -- in `pohlig_hellman` prime factors up to this size are handled with BSGS,
+/// the bigger ones with Pollard's rho
+const PH_BSGS_BITS: u64 = 32;
+
+/// **DlpSolution** -- k, such that Q = k * P, and number of group operations spent on it
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DlpSolution {
+    pub k: BigUint,
+    pub iterations: u64,
+}
+
+/// **bsgs** -- baby-step giant-step for k in [0, order): Q - i * (m * P) = j * P for m = ceil(sqrt(order)),
+/// takes ~2sqrt(order) additions and sqrt(order) points of memory
+pub fn bsgs(
+    ec: &ECurve,
+    p: &EcPointP,
+    q: &EcPointP,
+    order: &BigUint,
+) -> crate::Result<DlpSolution> {
+    let p = normalize(ec, p)?;
+    let q = normalize(ec, q)?;
+    let m = order.sqrt() + BigUint::one();
+    let m_u64 = to_u64(&m, "order is too big for baby-step giant-step")?;
+
+    let mut table = HashMap::with_capacity(m_u64 as usize);
+    let mut cur = EcPointA::from_projective(&EcPointP::neutral(), ec)?;
+    let mut iterations = 0;
+    for j in 0..m_u64 {
+        table.entry(key(&cur)).or_insert(j);
+        cur = add(ec, &cur, &p)?;
+        iterations += 1;
+    }
+
+    // cur = m * P
+    let minus_giant = normalize(ec, &cur.negative().to_projective())?;
+    let mut gamma = q;
+    for i in 0..m_u64 {
+        if let Some(j) = table.get(&key(&gamma)) {
+            let k = (&m * i + j) % order;
+            return Ok(DlpSolution { k, iterations });
+        }
+        gamma = add(ec, &gamma, &minus_giant)?;
+        iterations += 1;
+    }
+    not_found("Q isn't a multiple of P")
+}
+
+/// **pollard_rho** -- Pollard's rho with Teske's r-adding walk and distinguished points
+/// (van Oorschot-Wiener): walks of X = aP + bQ stop at points with d low zero bits of x,
+/// that are stored, and collision of two of them gives k = (a1 - a2) / (b2 - b1) mod order.
+/// Order has to be prime, takes ~sqrt(pi * order / 2) additions
+pub fn pollard_rho(
+    ec: &ECurve,
+    p: &EcPointP,
+    q: &EcPointP,
+    order: &BigUint,
+) -> crate::Result<DlpSolution> {
+    let p = normalize(ec, p)?;
+    let q = normalize(ec, q)?;
+    if q.is_inf() {
+        return Ok(DlpSolution {
+            k: BigUint::zero(),
+            iterations: 0,
+        });
+    }
+    let mut rng = SplitMix64(order.iter_u64_digits().next().unwrap_or(0));
+    let combination = |a: &BigUint, b: &BigUint| -> crate::Result<EcPointA> {
+        let ap = ec.proj_point_mul(&p.to_projective(), a);
+        let bq = ec.proj_point_mul(&q.to_projective(), b);
+        normalize(ec, &ec.proj_point_add(&ap, &bq))
+    };
+
+    let mut steps = Vec::with_capacity(RHO_PARTITIONS);
+    for _ in 0..RHO_PARTITIONS {
+        let (a, b) = (rng.below(order), rng.below(order));
+        steps.push((combination(&a, &b)?, a, b));
+    }
+    // expected walk length is 2^d, and there're ~sqrt(order) / 2^d walks
+    let d = order.bits() / 4;
+    let mask = (BigInt::one() << d) - 1;
+    let max_walk = 20 << d;
+
+    let mut iterations = 0;
+    let mut distinguished: HashMap<(BigInt, BigInt, bool), (BigUint, BigUint)> = HashMap::new();
+    for _ in 0..RHO_MAX_WALKS {
+        let (mut a, mut b) = (rng.below(order), rng.below(order));
+        let mut x = combination(&a, &b)?;
+        iterations += 1;
+        for _ in 0..max_walk {
+            if !x.is_inf() && (&x.x & &mask).is_zero() {
+                match distinguished.get(&key(&x)) {
+                    Some((a2, b2)) if *b2 != b => {
+                        // a1 P + b1 Q = a2 P + b2 Q => k = (a1 - a2) / (b2 - b1)
+                        let db = (b2 + order - &b) % order;
+                        let da = (&a + order - a2) % order;
+                        if let Some(k) = inverse_mod(&db, order).map(|inv| da * inv % order) {
+                            if check(ec, &p, &q, &k)? {
+                                return Ok(DlpSolution { k, iterations });
+                            }
+                        }
+                    }
+                    Some(_) => {}
+                    None => {
+                        distinguished.insert(key(&x), (a, b));
+                    }
+                }
+                break;
+            }
+            let i = partition(&x, RHO_PARTITIONS as u64, 0);
+            let (step, sa, sb) = &steps[i];
+            x = add(ec, &x, step)?;
+            a = (a + sa) % order;
+            b = (b + sb) % order;
+            iterations += 1;
+        }
+    }
+    not_found("Pollard's rho exceeded the number of walks")
+}
+
+/// **pollard_lambda** -- Pollard's kangaroo method for k in [lower, upper]: tame kangaroo
+/// jumps from upper * P and sets a trap, wild one jumps from Q until it falls into it or passes it.
+/// Jumps are powers of 2 with mean ~sqrt(upper - lower) / 2, takes ~2sqrt(upper - lower) additions.
+/// Found k is at least lower, but it can be bigger than upper, if the interval is wider than order of P
+pub fn pollard_lambda(
+    ec: &ECurve,
+    p: &EcPointP,
+    q: &EcPointP,
+    lower: &BigUint,
+    upper: &BigUint,
+) -> crate::Result<DlpSolution> {
+    let p = normalize(ec, p)?;
+    let q = normalize(ec, q)?;
+    if upper < lower {
+        return Err(EcError::IncorrectParameters(format!(
+            "empty interval [{lower}, {upper}]"
+        )));
+    }
+    let width = upper - lower;
+    let mean = width.sqrt() / 2_u8 + BigUint::one();
+    // mean of 1, 2, ..., 2^(l - 1) is (2^l - 1) / l
+    let mut l = 1_u64;
+    while (BigUint::one() << l) < &mean * l {
+        l += 1;
+    }
+    let mut jumps = Vec::with_capacity(l as usize);
+    let mut jump = p.clone();
+    for _ in 0..l {
+        jumps.push(jump.clone());
+        jump = add(ec, &jump, &jump)?;
+    }
+    let tame_jumps = to_u64(
+        &(&mean << 2_u8),
+        "interval is too wide for Pollard's lambda",
+    )?;
+
+    let mut iterations = 0;
+    for attempt in 0..LAMBDA_ATTEMPTS {
+        // tame kangaroo
+        let mut tame = normalize(ec, &ec.proj_point_mul(&p.to_projective(), upper))?;
+        let mut tame_dist = BigUint::zero();
+        for _ in 0..tame_jumps {
+            let i = partition(&tame, l, attempt);
+            tame = add(ec, &tame, &jumps[i])?;
+            tame_dist += BigUint::one() << i;
+            iterations += 1;
+        }
+
+        // wild kangaroo, Q = k * P, k + wild_dist = upper + tame_dist at the trap
+        let mut wild = q.clone();
+        let mut wild_dist = BigUint::zero();
+        let limit = &width + &tame_dist;
+        while wild_dist <= limit {
+            if wild == tame {
+                let k = upper + &tame_dist - &wild_dist;
+                if check(ec, &p, &q, &k)? {
+                    return Ok(DlpSolution { k, iterations });
+                }
+                break;
+            }
+            let i = partition(&wild, l, attempt);
+            wild = add(ec, &wild, &jumps[i])?;
+            wild_dist += BigUint::one() << i;
+            iterations += 1;
+        }
+    }
+    not_found(&format!("k isn't found in [{lower}, {upper}]"))
+}
+
+/// **pohlig_hellman** -- reduces discrete logarithm in group of order N = ord(P) = prod(p_i^e_i)
+/// to e_i logarithms in subgroups of prime order p_i (digits of k mod p_i^e_i in base p_i),
+/// that are solved with `bsgs` or `pollard_rho`, and combines the results with CRT.
+/// Order of P is found with `EcInfo::point_order`, so n * h has to be factored
+pub fn pohlig_hellman(info: &EcInfo, p: &EcPointP, q: &EcPointP) -> crate::Result<DlpSolution> {
+    let ec = &info.ecurve;
+    let order = info.point_order(p)?;
+    if !ec.proj_point_mul(q, &order).is_inf() {
+        return not_found("ord(P) * Q != O, so Q isn't a multiple of P");
+    }
+
+    let mut iterations = 0;
+    let (mut k, mut modulus) = (BigUint::zero(), BigUint::one());
+    for (prime, e) in factorize(&order).factors {
+        // P0 = (N / p) * P has order p
+        let p0 = ec.proj_point_mul(p, &(&order / &prime));
+        let mut x = BigUint::zero();
+        let mut prime_power = BigUint::one();
+        for _ in 0..e {
+            // (N / p^(j + 1)) * (Q - x * P) = d * P0
+            let minus_xp = ec.proj_point_mul(p, &((&order - &x % &order) % &order));
+            let rest = ec.proj_point_add(q, &minus_xp);
+            let h = ec.proj_point_mul(&rest, &(&order / (&prime_power * &prime)));
+            let digit = match prime.bits() <= PH_BSGS_BITS {
+                true => bsgs(ec, &p0, &h, &prime)?,
+                false => pollard_rho(ec, &p0, &h, &prime)?,
+            };
+            iterations += digit.iterations;
+            x += digit.k * &prime_power;
+            prime_power *= &prime;
+        }
+
+        // k = k mod modulus, k = x mod p^e
+        let inv = inverse_mod(&(&modulus % &prime_power), &prime_power).unwrap();
+        let t = ((&x + &prime_power - &k % &prime_power) % &prime_power * inv) % &prime_power;
+        k += &modulus * t;
+        modulus *= prime_power;
+    }
+    match check(ec, &normalize(ec, p)?, &normalize(ec, q)?, &k)? {
+        true => Ok(DlpSolution { k, iterations }),
+        false => not_found("Q isn't a multiple of P"),
+    }
+}
+
+fn not_found<T>(msg: &str) -> crate::Result<T> {
+    Err(EcError::DiscreteLogNotFound(msg.to_string()))
+}
+
+fn to_u64(x: &BigUint, msg: &str) -> crate::Result<u64> {
+    x.to_u64()
+        .ok_or_else(|| EcError::IncorrectParameters(format!("{msg}: {x}")))
+}
+
+fn normalize(ec: &ECurve, p: &EcPointP) -> crate::Result<EcPointA> {
+    ec.take_by_module(p).to_affine(ec)
+}
+
+fn add(ec: &ECurve, a: &EcPointA, b: &EcPointA) -> crate::Result<EcPointA> {
+    normalize(
+        ec,
+        &ec.proj_point_add(&a.to_projective(), &b.to_projective()),
+    )
+}
+
+fn key(p: &EcPointA) -> (BigInt, BigInt, bool) {
+    (p.x.clone(), p.y.clone(), p.is_inf)
+}
+
+fn check(ec: &ECurve, p: &EcPointA, q: &EcPointA, k: &BigUint) -> crate::Result<bool> {
+    Ok(normalize(ec, &ec.proj_point_mul(&p.to_projective(), k))? == *q)
+}
+
+// index of the step, that depends only on x, salt changes the jump function
+fn partition(p: &EcPointA, count: u64, salt: u64) -> usize {
+    let x = p.x.iter_u64_digits().next().unwrap_or(0);
+    (x.wrapping_mul(0x9e37_79b9_7f4a_7c15).wrapping_add(salt) >> 32) as usize % count as usize
+}
+
+fn inverse_mod(a: &BigUint, m: &BigUint) -> Option<BigUint> {
+    crate::helpers::inverse(&BigInt::from(a.clone()), &BigInt::from(m.clone()))
+        .ok()
+        .and_then(|inv| inv.to_biguint())
+}
+
+// deterministic generator, so that results and iteration counts are reproducible
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: &BigUint) -> BigUint {
+        let digits: Vec<u64> = (0..n.iter_u64_digits().len() + 1)
+            .map(|_| self.next())
+            .collect();
+        BigUint::from_slice(
+            &digits
+                .iter()
+                .flat_map(|d| [*d as u32, (*d >> 32) as u32])
+                .collect::<Vec<_>>(),
+        ) % n
+    }
+}
@@ -0,0 +1,542 @@
+use crate::affine_point::EcPointA;
+use crate::field::BigPrimeField;
+use crate::helpers::{bigint_cswap, inverse, take_by_bigint_module};
+use crate::montgomery::MontgomeryCurve;
+use crate::projective_point::EcPointP;
+use crate::traits::{CurveModel, DomainParameters, Group};
+use crate::{ECurve, EcError, EcInfo};
+use num_bigint::{BigInt, BigUint};
+use num_traits::{Num, One, Zero};
+use std::fmt::{Debug, Display, Formatter};
+
+/// **EdwardsCurve** -- represents elliptic curve in twisted Edwards form
+/// points satisfy the following equation
+/// ax^2 + y^2 = 1 + dx^2y^2, where a, d != 0 and a != d mod q.
+/// If a is a square and d is a non-square mod q, then addition law is complete,
+/// i.e. it works for all pairs of points (including doubling and neutral point)
+#[derive(PartialOrd, PartialEq, Clone, Debug)]
+pub struct EdwardsCurve {
+    a: BigInt,
+    d: BigInt,
+    q: BigInt,
+}
+
+/// **EdPointE** -- point on twisted Edwards curve in extended coordinates (X : Y : Z : T),
+/// where x = X/Z, y = Y/Z and T = XY/Z
+#[derive(Clone, Eq, PartialEq, PartialOrd)]
+pub struct EdPointE {
+    pub x: BigInt,
+    pub y: BigInt,
+    pub z: BigInt,
+    pub t: BigInt,
+}
+
+/// **EdwardsEcInfo** -- EC domain parameters of twisted Edwards curve, the same as `EcInfo`
+/// for Weierstrass curves
+#[derive(PartialEq, Clone, Debug)]
+pub struct EdwardsEcInfo {
+    pub bp: EdPointE,
+    /// **n** -- order of base point
+    pub n: BigUint,
+    /// **h** -- cofactor, i.e. #E(F_q) = n * h
+    pub h: BigUint,
+    pub curve: EdwardsCurve,
+}
+
+/// **PreGeneratedEdwardsParams** -- curves from RFC 8032
+#[derive(Debug)]
+pub enum PreGeneratedEdwardsParams {
+    Edwards25519,
+    Edwards448,
+}
+
+impl EdPointE {
+    pub fn new(x: &BigInt, y: &BigInt, z: &BigInt, t: &BigInt) -> Self {
+        EdPointE {
+            x: x.clone(),
+            y: y.clone(),
+            z: z.clone(),
+            t: t.clone(),
+        }
+    }
+
+    /// **from_affine** -- (x, y) => (x : y : 1 : xy)
+    pub fn from_affine(x: &BigInt, y: &BigInt) -> Self {
+        EdPointE {
+            x: x.clone(),
+            y: y.clone(),
+            z: BigInt::one(),
+            t: x * y,
+        }
+    }
+
+    /// **neutral** -- (0 : 1 : 1 : 0), i.e. affine point (0, 1)
+    pub fn neutral() -> Self {
+        EdPointE {
+            x: BigInt::zero(),
+            y: BigInt::one(),
+            z: BigInt::one(),
+            t: BigInt::zero(),
+        }
+    }
+
+    pub fn negative(&self) -> EdPointE {
+        EdPointE {
+            x: -self.x.clone(),
+            y: self.y.clone(),
+            z: self.z.clone(),
+            t: -self.t.clone(),
+        }
+    }
+}
+
+impl Debug for EdPointE {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "x: {}, y: {} ,z: {} ,t: {}",
+            self.x, self.y, self.z, self.t
+        )
+    }
+}
+
+impl Display for EdPointE {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "x: {}, y: {} ,z: {} ,t: {}",
+            self.x, self.y, self.z, self.t
+        )
+    }
+}
+
+impl PreGeneratedEdwardsParams {
+    /// **base_point** -- base point (x, y) from RFC 8032, section 5.1 and 5.2
+    pub fn base_point(&self) -> EdPointE {
+        let (x, y) = match self {
+            PreGeneratedEdwardsParams::Edwards25519 => (
+                "15112221349535400772501151409588531511454012693041857206046113283949847762202",
+                "46316835694926478169428394003475163141307993866256225615783033603165251855960",
+            ),
+            PreGeneratedEdwardsParams::Edwards448 => (
+                "224580040295924300187604334099896036246789641632564134246125461686950415467406032909029192869357953282578032075146446173674602635247710",
+                "298819210078481492676017930443930673437544040154080242095928241372331506189835876003536878655418784733982303233503462500531545062832660",
+            ),
+        };
+        EdPointE::from_affine(
+            &BigInt::from_str_radix(x, 10).unwrap(),
+            &BigInt::from_str_radix(y, 10).unwrap(),
+        )
+    }
+
+    /// **order** -- order of prime subgroup generated by base point
+    pub fn order(&self) -> BigUint {
+        BigUint::from_str_radix(
+            match self {
+                PreGeneratedEdwardsParams::Edwards25519 => "7237005577332262213973186563042994240857116359379907606001950938285454250989",
+                PreGeneratedEdwardsParams::Edwards448 => "181709681073901722637330951972001133588410340171829515070372549795146003961539585716195755291692375963310293709091662304773755859649779",
+            },
+            10,
+        )
+        .unwrap()
+    }
+
+    pub fn cofactor(&self) -> BigUint {
+        match self {
+            PreGeneratedEdwardsParams::Edwards25519 => BigUint::from(8_u8),
+            PreGeneratedEdwardsParams::Edwards448 => BigUint::from(4_u8),
+        }
+    }
+}
+
+impl From<PreGeneratedEdwardsParams> for EdwardsCurve {
+    fn from(value: PreGeneratedEdwardsParams) -> Self {
+        EdwardsCurve::from(&value)
+    }
+}
+impl From<&PreGeneratedEdwardsParams> for EdwardsCurve {
+    fn from(value: &PreGeneratedEdwardsParams) -> Self {
+        match value {
+            // q = 2^255 - 19, d = -121665/121666
+            PreGeneratedEdwardsParams::Edwards25519 => EdwardsCurve::new(
+                &BigInt::from(-1),
+                &BigInt::from_str_radix(
+                    "37095705934669439343138083508754565189542113879843219016388785533085940283555",
+                    10,
+                )
+                .unwrap(),
+                &BigInt::from_str_radix(
+                    "57896044618658097711785492504343953926634992332820282019728792003956564819949",
+                    10,
+                )
+                .unwrap(),
+            )
+            .unwrap(),
+            // q = 2^448 - 2^224 - 1
+            PreGeneratedEdwardsParams::Edwards448 => EdwardsCurve::new(
+                &BigInt::one(),
+                &BigInt::from(-39081),
+                &BigInt::from_str_radix(
+                    "726838724295606890549323807888004534353641360687318060281490199180612328166730772686396383698676545930088884461843637361053498018365439",
+                    10,
+                )
+                .unwrap(),
+            )
+            .unwrap(),
+        }
+    }
+}
+
+impl From<PreGeneratedEdwardsParams> for EdwardsEcInfo {
+    fn from(value: PreGeneratedEdwardsParams) -> Self {
+        EdwardsEcInfo::from(&value)
+    }
+}
+impl From<&PreGeneratedEdwardsParams> for EdwardsEcInfo {
+    fn from(value: &PreGeneratedEdwardsParams) -> Self {
+        EdwardsEcInfo::new(
+            value.base_point(),
+            value.order(),
+            value.cofactor(),
+            EdwardsCurve::from(value),
+        )
+        .unwrap()
+    }
+}
+
+impl From<PreGeneratedEdwardsParams> for EcInfo {
+    fn from(value: PreGeneratedEdwardsParams) -> Self {
+        EcInfo::from(&value)
+    }
+}
+/// EC domain parameters of the Weierstrass model of twisted Edwards curve
+impl From<&PreGeneratedEdwardsParams> for EcInfo {
+    fn from(value: &PreGeneratedEdwardsParams) -> Self {
+        let curve = EdwardsCurve::from(value);
+        EcInfo::new(
+            curve
+                .to_weierstrass_proj_point(&value.base_point())
+                .unwrap(),
+            value.order(),
+            value.cofactor(),
+            curve.to_weierstrass().unwrap(),
+        )
+        .unwrap()
+    }
+}
+
+impl EdwardsEcInfo {
+    /// **new** -- creates EC domain parameters, checks that base point lies on the curve
+    /// and that it has order **n**, i.e. n * bp = O
+    pub fn new(bp: EdPointE, n: BigUint, h: BigUint, curve: EdwardsCurve) -> crate::Result<Self> {
+        if !curve.check_point(&bp) || curve.is_neutral(&bp) {
+            return Err(EcError::IncorrectBasePoint(format!(
+                "({bp}) doesn't lie on the curve"
+            )));
+        }
+        if !curve.is_neutral(&curve.point_mul(&bp, &n)) {
+            return Err(EcError::IncorrectBasePoint(format!(
+                "n * ({bp}) != O, n: {n}"
+            )));
+        }
+        Ok(EdwardsEcInfo { bp, n, h, curve })
+    }
+}
+
+impl EdwardsCurve {
+    pub fn new(a: &BigInt, d: &BigInt, q: &BigInt) -> crate::Result<Self> {
+        let (a, d) = (
+            take_by_bigint_module(&(a % q), q),
+            take_by_bigint_module(&(d % q), q),
+        );
+        if a.is_zero() || d.is_zero() || a == d {
+            return Err(EcError::IncorrectParameters(format!(
+                "a, d have to be nonzero and distinct, a: {a}, d: {d}, q: {q}"
+            )));
+        }
+        Ok(EdwardsCurve { a, d, q: q.clone() })
+    }
+
+    pub fn get_a(&self) -> BigInt {
+        self.a.clone()
+    }
+    pub fn get_d(&self) -> BigInt {
+        self.d.clone()
+    }
+    pub fn get_q(&self) -> BigInt {
+        self.q.clone()
+    }
+
+    // aX^2Z^2 + Y^2Z^2 = Z^4 + dX^2Y^2 and XY = TZ
+    pub fn check_point(&self, p: &EdPointE) -> bool {
+        let q = &self.q;
+        let (x2, y2, z2) = (&p.x * &p.x, &p.y * &p.y, &p.z * &p.z);
+        !p.z.is_zero()
+            && take_by_bigint_module(&((&self.a * &x2 * &z2 + &y2 * &z2) % q), q)
+                == take_by_bigint_module(&((&z2 * &z2 + &self.d * &x2 * &y2) % q), q)
+            && take_by_bigint_module(&((&p.x * &p.y) % q), q)
+                == take_by_bigint_module(&((&p.t * &p.z) % q), q)
+    }
+
+    pub fn is_neutral(&self, p: &EdPointE) -> bool {
+        take_by_bigint_module(&(&p.x % &self.q), &self.q).is_zero()
+            && take_by_bigint_module(&((&p.y - &p.z) % &self.q), &self.q).is_zero()
+    }
+
+    /// **to_affine** -- transforms (X : Y : Z : T) => (X/Z, Y/Z)
+    pub fn to_affine(&self, p: &EdPointE) -> crate::Result<(BigInt, BigInt)> {
+        let q = &self.q;
+        let inv = inverse(&take_by_bigint_module(&(&p.z % q), q), q)?;
+        Ok((
+            take_by_bigint_module(&((&p.x * &inv) % q), q),
+            take_by_bigint_module(&((&p.y * &inv) % q), q),
+        ))
+    }
+
+    /// **normalize** -- transforms extended point Z coordinate into 1
+    pub fn normalize(&self, p: &EdPointE) -> crate::Result<EdPointE> {
+        let (x, y) = self.to_affine(p)?;
+        Ok(EdPointE {
+            t: (&x * &y) % &self.q,
+            x,
+            y,
+            z: BigInt::one(),
+        })
+    }
+
+    /// **point_add** -- unified addition in extended coordinates (add-2008-hwcd),
+    /// the same formula is used for doubling and neutral point
+    pub fn point_add(&self, p1: &EdPointE, p2: &EdPointE) -> EdPointE {
+        let q = &self.q;
+        let a = (&p1.x * &p2.x) % q;
+        let b = (&p1.y * &p2.y) % q;
+        let c = (&p1.t * &self.d * &p2.t) % q;
+        let d = (&p1.z * &p2.z) % q;
+        let e = ((&p1.x + &p1.y) * (&p2.x + &p2.y) - &a - &b) % q;
+        let f = &d - &c;
+        let g = &d + &c;
+        let h = &b - &self.a * &a;
+        EdPointE {
+            x: take_by_bigint_module(&((&e * &f) % q), q),
+            y: take_by_bigint_module(&((&g * &h) % q), q),
+            z: take_by_bigint_module(&((&f * &g) % q), q),
+            t: take_by_bigint_module(&((&e * &h) % q), q),
+        }
+    }
+
+    /// **point_double** -- doubling in extended coordinates (dbl-2008-hwcd)
+    pub fn point_double(&self, p: &EdPointE) -> EdPointE {
+        let q = &self.q;
+        let a = (&p.x * &p.x) % q;
+        let b = (&p.y * &p.y) % q;
+        let c = (BigInt::from(2_u8) * &p.z * &p.z) % q;
+        let d = (&self.a * &a) % q;
+        let e = ((&p.x + &p.y) * (&p.x + &p.y) - &a - &b) % q;
+        let g = &d + &b;
+        let f = &g - &c;
+        let h = &d - &b;
+        EdPointE {
+            x: take_by_bigint_module(&((&e * &f) % q), q),
+            y: take_by_bigint_module(&((&g * &h) % q), q),
+            z: take_by_bigint_module(&((&f * &g) % q), q),
+            t: take_by_bigint_module(&((&e * &h) % q), q),
+        }
+    }
+
+    pub fn point_mul(&self, p: &EdPointE, k: &BigUint) -> EdPointE {
+        let mut r = EdPointE::neutral();
+        // from MSB to LSB
+        for i in (0..k.bits()).rev() {
+            r = self.point_double(&r);
+            if k.bit(i) {
+                r = self.point_add(&r, p);
+            }
+        }
+        r
+    }
+
+    /// **point_mul_ct** -- k * P for secret k with Montgomery ladder of bit length of `order`
+    /// steps, every step is one addition and one doubling, points are swapped with masks.
+    /// It relies on the complete addition law, i.e. a is a square and d is a non-square mod q
+    pub fn point_mul_ct(&self, p: &EdPointE, k: &BigUint, order: &BigUint) -> EdPointE {
+        let bits = order.bits();
+        // k as little-endian limbs padded to the number of steps
+        let mut k_limbs = (k % order).to_u64_digits();
+        k_limbs.resize(bits.div_ceil(64) as usize + 1, 0);
+        let k_bit = |i: u64| (k_limbs[(i / 64) as usize] >> (i % 64)) & 1;
+
+        let limbs = self.q.bits().div_ceil(64) as usize;
+        let (mut r0, mut r1, mut swap) = (EdPointE::neutral(), p.clone(), 0);
+        for i in (0..bits).rev() {
+            let bit = k_bit(i);
+            self.point_swap(&mut r0, &mut r1, swap ^ bit, limbs);
+            swap = bit;
+            r1 = self.point_add(&r0, &r1);
+            r0 = self.point_double(&r0);
+        }
+        self.point_swap(&mut r0, &mut r1, swap, limbs);
+        r0
+    }
+
+    // conditional swap of all four coordinates with masks
+    fn point_swap(&self, a: &mut EdPointE, b: &mut EdPointE, choice: u64, limbs: usize) {
+        let q = &self.q;
+        bigint_cswap(q, &mut a.x, &mut b.x, choice, limbs);
+        bigint_cswap(q, &mut a.y, &mut b.y, choice, limbs);
+        bigint_cswap(q, &mut a.z, &mut b.z, choice, limbs);
+        bigint_cswap(q, &mut a.t, &mut b.t, choice, limbs);
+    }
+
+    /// **to_montgomery** -- birationally equivalent curve in Montgomery form
+    /// A = 2(a + d)/(a - d), B = 4/(a - d)
+    pub fn to_montgomery(&self) -> crate::Result<MontgomeryCurve> {
+        let q = &self.q;
+        let a_d_inv = inverse(&take_by_bigint_module(&((&self.a - &self.d) % q), q), q)?;
+        MontgomeryCurve::new(
+            &((BigInt::from(2_u8) * (&self.a + &self.d) * &a_d_inv) % q),
+            &((BigInt::from(4_u8) * &a_d_inv) % q),
+            q,
+        )
+    }
+
+    pub fn to_weierstrass(&self) -> crate::Result<ECurve> {
+        self.to_montgomery()?.to_weierstrass()
+    }
+
+    /// **to_weierstrass_point** -- maps (x, y) -> (u, v) = ((1 + y)/(1 - y), u/x)
+    /// on Montgomery curve and then to the Weierstrass model of the curve.
+    /// Neutral point (0, 1) is mapped into O and (0, -1) into point of order 2 (u, v) = (0, 0)
+    pub fn to_weierstrass_point(&self, p: &EdPointE) -> crate::Result<EcPointA> {
+        let q = &self.q;
+        let montgomery = self.to_montgomery()?;
+        let (x, y) = self.to_affine(p)?;
+        let uv = if x.is_zero() {
+            if y.is_one() {
+                return Ok(EcPointA {
+                    x: Default::default(),
+                    y: Default::default(),
+                    is_inf: true,
+                });
+            }
+            EcPointA::new(&BigInt::zero(), &BigInt::zero())
+        } else {
+            let u = ((BigInt::one() + &y)
+                * inverse(&take_by_bigint_module(&((BigInt::one() - &y) % q), q), q)?)
+                % q;
+            let v = (&u * inverse(&x, q)?) % q;
+            EcPointA::new(&u, &v)
+        };
+        montgomery.to_weierstrass_point(&uv)
+    }
+
+    pub fn to_weierstrass_proj_point(&self, p: &EdPointE) -> crate::Result<EcPointP> {
+        Ok(self.to_weierstrass_point(p)?.to_projective())
+    }
+
+    /// **from_weierstrass_point** -- inverse map to `to_weierstrass_point`,
+    /// (u, v) -> (x, y) = (u/v, (u - 1)/(u + 1))
+    pub fn from_weierstrass_point(&self, p: &EcPointA) -> crate::Result<EdPointE> {
+        if p.is_inf() {
+            return Ok(EdPointE::neutral());
+        }
+        let q = &self.q;
+        let uv = self.to_montgomery()?.from_weierstrass_point(p)?;
+        let (u, v) = (uv.get_x(), uv.get_y());
+        if v.is_zero() {
+            return match u.is_zero() {
+                true => Ok(EdPointE::from_affine(&BigInt::zero(), &(q - BigInt::one()))),
+                false => Err(EcError::IncorrectParameters(format!(
+                    "point ({u}, 0) has no image on twisted Edwards curve"
+                ))),
+            };
+        }
+        let x = (&u * inverse(&v, q)?) % q;
+        let y = ((&u - BigInt::one())
+            * inverse(&take_by_bigint_module(&((&u + BigInt::one()) % q), q), q)?)
+            % q;
+        Ok(EdPointE::from_affine(
+            &take_by_bigint_module(&x, q),
+            &take_by_bigint_module(&y, q),
+        ))
+    }
+
+    pub fn from_weierstrass_proj_point(&self, p: &EcPointP) -> crate::Result<EdPointE> {
+        self.from_weierstrass_point(&p.to_affine(&self.to_weierstrass()?)?)
+    }
+}
+
+impl Group for EdwardsCurve {
+    type Point = EdPointE;
+
+    fn identity(&self) -> EdPointE {
+        EdPointE::neutral()
+    }
+    fn is_identity(&self, p: &EdPointE) -> bool {
+        self.is_neutral(p)
+    }
+    fn add(&self, a: &EdPointE, b: &EdPointE) -> EdPointE {
+        self.point_add(a, b)
+    }
+    fn neg(&self, a: &EdPointE) -> EdPointE {
+        let p = a.negative();
+        EdPointE {
+            x: take_by_bigint_module(&(&p.x % &self.q), &self.q),
+            t: take_by_bigint_module(&(&p.t % &self.q), &self.q),
+            ..p
+        }
+    }
+    fn normalize(&self, p: &EdPointE) -> crate::Result<EdPointE> {
+        EdwardsCurve::normalize(self, p)
+    }
+    fn double(&self, a: &EdPointE) -> EdPointE {
+        self.point_double(a)
+    }
+    fn mul(&self, a: &EdPointE, k: &BigUint) -> EdPointE {
+        self.point_mul(a, k)
+    }
+}
+
+impl CurveModel for EdwardsCurve {
+    type BaseField = BigPrimeField;
+
+    fn base_field(&self) -> BigPrimeField {
+        BigPrimeField::new(&self.q)
+    }
+    fn contains(&self, p: &EdPointE) -> bool {
+        self.check_point(p)
+    }
+    /// neutral point has affine coordinates (0, 1) on Edwards curve
+    fn to_affine(&self, p: &EdPointE) -> crate::Result<(BigInt, BigInt)> {
+        EdwardsCurve::to_affine(self, p)
+    }
+    /// compares coefficients a and d by module q
+    fn is_same_curve(&self, other: &EdwardsCurve) -> bool {
+        let q = &self.q;
+        let reduce = |x: &BigInt| take_by_bigint_module(&(x % q), q);
+        *q == other.q && reduce(&self.a) == reduce(&other.a) && reduce(&self.d) == reduce(&other.d)
+    }
+}
+
+impl DomainParameters for EdwardsEcInfo {
+    type Curve = EdwardsCurve;
+
+    fn curve(&self) -> &EdwardsCurve {
+        &self.curve
+    }
+    fn generator(&self) -> EdPointE {
+        self.bp.clone()
+    }
+    fn order(&self) -> BigUint {
+        self.n.clone()
+    }
+    fn cofactor(&self) -> BigUint {
+        self.h.clone()
+    }
+    fn base_point_mul(&self, k: &BigUint) -> EdPointE {
+        self.secret_mul(&self.bp, k)
+    }
+    /// constant-time ladder with bit length of n * h steps
+    fn secret_mul(&self, p: &EdPointE, k: &BigUint) -> EdPointE {
+        self.curve.point_mul_ct(p, k, &(&self.n * &self.h))
+    }
+}
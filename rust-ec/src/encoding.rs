@@ -0,0 +1,110 @@
+use crate::affine_point::EcPointA;
+use crate::helpers::take_by_bigint_module;
+use crate::{ECurve, EcError};
+use num_bigint::{BigInt, Sign};
+
+/// **PointFormat** -- forms of the octet string from SEC 1, section 2.3.3
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum PointFormat {
+    /// 0x04 || X || Y
+    Uncompressed,
+    /// 0x02 || X (y is even) or 0x03 || X (y is odd)
+    Compressed,
+    /// 0x06 || X || Y (y is even) or 0x07 || X || Y (y is odd), from ANSI X9.62
+    Hybrid,
+}
+
+/// **coordinate_len** -- length of every coordinate in bytes, i.e. ceil(log2(q) / 8)
+pub fn coordinate_len(ec: &ECurve) -> usize {
+    ec.q.bits().div_ceil(8) as usize
+}
+
+/// **encode_point** -- Elliptic-Curve-Point-to-Octet-String conversion (SEC 1, section 2.3.3),
+/// point at infinity is encoded as single 0x00 byte
+pub fn encode_point(ec: &ECurve, p: &EcPointA, format: PointFormat) -> Vec<u8> {
+    if p.is_inf {
+        return vec![0x00];
+    }
+    let len = coordinate_len(ec);
+    let x = take_by_bigint_module(&(&p.x % &ec.q), &ec.q);
+    let y = take_by_bigint_module(&(&p.y % &ec.q), &ec.q);
+    let parity = y.bit(0) as u8;
+
+    let mut bytes = Vec::with_capacity(1 + 2 * len);
+    bytes.push(match format {
+        PointFormat::Uncompressed => 0x04,
+        PointFormat::Compressed => 0x02 | parity,
+        PointFormat::Hybrid => 0x06 | parity,
+    });
+    bytes.extend(to_fixed_bytes(&x, len));
+    if format != PointFormat::Compressed {
+        bytes.extend(to_fixed_bytes(&y, len));
+    }
+    bytes
+}
+
+/// **decode_point** -- Octet-String-to-Elliptic-Curve-Point conversion (SEC 1, section 2.3.4),
+/// accepts all forms of [PointFormat] and 0x00 for point at infinity.
+/// Length of the string, range of coordinates and the curve equation are checked
+pub fn decode_point(ec: &ECurve, bytes: &[u8]) -> crate::Result<EcPointA> {
+    let len = coordinate_len(ec);
+    let err = |msg: &str| Err(EcError::IncorrectPointEncoding(msg.to_string()));
+    let (tag, body) = match bytes.split_first() {
+        Some(x) => x,
+        None => return err("empty octet string"),
+    };
+    let expected_len = match tag {
+        0x00 => 0,
+        0x02 | 0x03 => len,
+        0x04 | 0x06 | 0x07 => 2 * len,
+        _ => return err(&format!("unknown leading octet 0x{tag:02x}")),
+    };
+    if body.len() != expected_len {
+        return err(&format!(
+            "expected {} octets for leading octet 0x{tag:02x}, got {}",
+            expected_len + 1,
+            bytes.len()
+        ));
+    }
+    if *tag == 0x00 {
+        return Ok(EcPointA {
+            x: Default::default(),
+            y: Default::default(),
+            is_inf: true,
+        });
+    }
+
+    let x = BigInt::from_bytes_be(Sign::Plus, &body[..len]);
+    if x >= ec.q {
+        return err("x coordinate isn't less than q");
+    }
+    let y = match tag {
+        0x02 | 0x03 => match ec.lift_x(&x, tag & 1 == 1) {
+            Ok(p) => p.y,
+            Err(e) => return err(&e.to_string()),
+        },
+        _ => {
+            let y = BigInt::from_bytes_be(Sign::Plus, &body[len..]);
+            if y >= ec.q {
+                return err("y coordinate isn't less than q");
+            }
+            if *tag != 0x04 && y.bit(0) != (tag & 1 == 1) {
+                return err("parity of y doesn't match the leading octet");
+            }
+            y
+        }
+    };
+
+    let p = EcPointA::new(&x, &y);
+    match ec.check_affine_point(&p) {
+        true => Ok(p),
+        false => err("point doesn't lie on the curve"),
+    }
+}
+
+fn to_fixed_bytes(x: &BigInt, len: usize) -> Vec<u8> {
+    let bytes = x.to_bytes_be().1;
+    let mut res = vec![0_u8; len - bytes.len()];
+    res.extend(bytes);
+    res
+}
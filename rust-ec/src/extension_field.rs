@@ -0,0 +1,377 @@
+use crate::helpers::{inverse, take_by_bigint_module};
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use std::fmt::{Display, Formatter};
+
+/// **Fp2** -- element c0 + c1 * u of GF(p^2) = GF(p)[u] / (u^2 + 1)
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Fp2 {
+    pub c0: BigInt,
+    pub c1: BigInt,
+}
+
+/// **Fp6** -- element c0 + c1 * v + c2 * v^2 of GF(p^6) = GF(p^2)[v] / (v^3 - xi)
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Fp6 {
+    pub c0: Fp2,
+    pub c1: Fp2,
+    pub c2: Fp2,
+}
+
+/// **Fp12** -- element c0 + c1 * w of GF(p^12) = GF(p^6)[w] / (w^2 - v)
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct Fp12 {
+    pub c0: Fp6,
+    pub c1: Fp6,
+}
+
+impl Fp2 {
+    pub fn new(c0: &BigInt, c1: &BigInt) -> Self {
+        Fp2 {
+            c0: c0.clone(),
+            c1: c1.clone(),
+        }
+    }
+
+    pub fn zero() -> Self {
+        Fp2::new(&BigInt::zero(), &BigInt::zero())
+    }
+
+    pub fn one() -> Self {
+        Fp2::new(&BigInt::one(), &BigInt::zero())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+}
+
+impl Fp6 {
+    pub fn zero() -> Self {
+        Fp6 {
+            c0: Fp2::zero(),
+            c1: Fp2::zero(),
+            c2: Fp2::zero(),
+        }
+    }
+
+    pub fn one() -> Self {
+        Fp6 {
+            c0: Fp2::one(),
+            c1: Fp2::zero(),
+            c2: Fp2::zero(),
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero() && self.c2.is_zero()
+    }
+}
+
+impl Fp12 {
+    pub fn zero() -> Self {
+        Fp12 {
+            c0: Fp6::zero(),
+            c1: Fp6::zero(),
+        }
+    }
+
+    pub fn one() -> Self {
+        Fp12 {
+            c0: Fp6::one(),
+            c1: Fp6::zero(),
+        }
+    }
+
+    pub fn is_one(&self) -> bool {
+        *self == Fp12::one()
+    }
+
+    /// **from_powers_of_w** -- g_0 + g_1 * w + ... + g_5 * w^5, w^2 = v
+    pub fn from_powers_of_w(g: [Fp2; 6]) -> Self {
+        let [g0, g1, g2, g3, g4, g5] = g;
+        Fp12 {
+            c0: Fp6 {
+                c0: g0,
+                c1: g2,
+                c2: g4,
+            },
+            c1: Fp6 {
+                c0: g1,
+                c1: g3,
+                c2: g5,
+            },
+        }
+    }
+
+    /// **powers_of_w** -- coefficients g_i of the element as g_0 + g_1 * w + ... + g_5 * w^5
+    pub fn powers_of_w(&self) -> [Fp2; 6] {
+        [
+            self.c0.c0.clone(),
+            self.c1.c0.clone(),
+            self.c0.c1.clone(),
+            self.c1.c1.clone(),
+            self.c0.c2.clone(),
+            self.c1.c2.clone(),
+        ]
+    }
+}
+
+/// **ExtensionTower** -- arithmetic of the tower GF(p) < GF(p^2) < GF(p^6) < GF(p^12)
+/// used by pairings on BN and BLS12 curves, p = 3 mod 4 so u^2 = -1 is irreducible,
+/// xi has to be neither a square nor a cube in GF(p^2)
+#[derive(Clone, PartialEq, Debug)]
+pub struct ExtensionTower {
+    p: BigInt,
+    xi: Fp2,
+    /// **frobenius_coeffs** -- xi^(i(p - 1)/6) = w^(i(p - 1)), i = 0..5
+    frobenius_coeffs: Vec<Fp2>,
+}
+
+impl ExtensionTower {
+    pub fn new(p: &BigInt, xi: &Fp2) -> Self {
+        let mut tower = ExtensionTower {
+            p: p.clone(),
+            xi: xi.clone(),
+            frobenius_coeffs: vec![],
+        };
+        let gamma = tower.fp2_pow(xi, &((p - BigInt::one()) / 6));
+        let mut coeffs = vec![Fp2::one()];
+        for i in 1..6 {
+            coeffs.push(tower.fp2_mul(&coeffs[i - 1], &gamma));
+        }
+        tower.frobenius_coeffs = coeffs;
+        tower
+    }
+
+    pub fn get_p(&self) -> BigInt {
+        self.p.clone()
+    }
+    pub fn get_xi(&self) -> Fp2 {
+        self.xi.clone()
+    }
+    /// **get_frobenius_coeff** -- xi^(i(p - 1)/6)
+    pub fn get_frobenius_coeff(&self, i: usize) -> Fp2 {
+        self.frobenius_coeffs[i % 6].clone()
+    }
+
+    pub fn fp(&self, a: &BigInt) -> BigInt {
+        take_by_bigint_module(&(a % &self.p), &self.p)
+    }
+
+    pub fn fp2_add(&self, a: &Fp2, b: &Fp2) -> Fp2 {
+        Fp2 {
+            c0: self.fp(&(&a.c0 + &b.c0)),
+            c1: self.fp(&(&a.c1 + &b.c1)),
+        }
+    }
+
+    pub fn fp2_sub(&self, a: &Fp2, b: &Fp2) -> Fp2 {
+        Fp2 {
+            c0: self.fp(&(&a.c0 - &b.c0)),
+            c1: self.fp(&(&a.c1 - &b.c1)),
+        }
+    }
+
+    pub fn fp2_neg(&self, a: &Fp2) -> Fp2 {
+        self.fp2_sub(&Fp2::zero(), a)
+    }
+
+    /// **fp2_mul** -- (a0 + a1u)(b0 + b1u) = a0b0 - a1b1 + ((a0 + a1)(b0 + b1) - a0b0 - a1b1)u
+    pub fn fp2_mul(&self, a: &Fp2, b: &Fp2) -> Fp2 {
+        let t0 = &a.c0 * &b.c0;
+        let t1 = &a.c1 * &b.c1;
+        let t2 = (&a.c0 + &a.c1) * (&b.c0 + &b.c1);
+        Fp2 {
+            c0: self.fp(&(&t0 - &t1)),
+            c1: self.fp(&(t2 - t0 - t1)),
+        }
+    }
+
+    pub fn fp2_square(&self, a: &Fp2) -> Fp2 {
+        self.fp2_mul(a, a)
+    }
+
+    /// **fp2_mul_by_fp** -- multiplication by element of the base field
+    pub fn fp2_mul_by_fp(&self, a: &Fp2, k: &BigInt) -> Fp2 {
+        Fp2 {
+            c0: self.fp(&(&a.c0 * k)),
+            c1: self.fp(&(&a.c1 * k)),
+        }
+    }
+
+    /// **fp2_conjugate** -- a0 - a1u = a^p
+    pub fn fp2_conjugate(&self, a: &Fp2) -> Fp2 {
+        Fp2 {
+            c0: a.c0.clone(),
+            c1: self.fp(&-&a.c1),
+        }
+    }
+
+    /// **fp2_inverse** -- (a0 - a1u) / (a0^2 + a1^2)
+    pub fn fp2_inverse(&self, a: &Fp2) -> crate::Result<Fp2> {
+        let norm = self.fp(&(&a.c0 * &a.c0 + &a.c1 * &a.c1));
+        let norm_inv = inverse(&norm, &self.p)?;
+        Ok(self.fp2_mul_by_fp(&self.fp2_conjugate(a), &norm_inv))
+    }
+
+    pub fn fp2_pow(&self, a: &Fp2, e: &BigInt) -> Fp2 {
+        let mut r = Fp2::one();
+        for i in (0..e.bits()).rev() {
+            r = self.fp2_square(&r);
+            if e.bit(i) {
+                r = self.fp2_mul(&r, a);
+            }
+        }
+        r
+    }
+
+    pub fn fp6_add(&self, a: &Fp6, b: &Fp6) -> Fp6 {
+        Fp6 {
+            c0: self.fp2_add(&a.c0, &b.c0),
+            c1: self.fp2_add(&a.c1, &b.c1),
+            c2: self.fp2_add(&a.c2, &b.c2),
+        }
+    }
+
+    pub fn fp6_sub(&self, a: &Fp6, b: &Fp6) -> Fp6 {
+        Fp6 {
+            c0: self.fp2_sub(&a.c0, &b.c0),
+            c1: self.fp2_sub(&a.c1, &b.c1),
+            c2: self.fp2_sub(&a.c2, &b.c2),
+        }
+    }
+
+    pub fn fp6_neg(&self, a: &Fp6) -> Fp6 {
+        self.fp6_sub(&Fp6::zero(), a)
+    }
+
+    /// **fp6_mul** -- product of polynomials in v of degree 2, v^3 = xi
+    pub fn fp6_mul(&self, a: &Fp6, b: &Fp6) -> Fp6 {
+        let t0 = self.fp2_mul(&a.c0, &b.c0);
+        let t1 = self.fp2_mul(&a.c1, &b.c1);
+        let t2 = self.fp2_mul(&a.c2, &b.c2);
+        let a1b2 = self.fp2_mul(&a.c1, &b.c2);
+        let a2b1 = self.fp2_mul(&a.c2, &b.c1);
+        let a0b1 = self.fp2_mul(&a.c0, &b.c1);
+        let a1b0 = self.fp2_mul(&a.c1, &b.c0);
+        let a0b2 = self.fp2_mul(&a.c0, &b.c2);
+        let a2b0 = self.fp2_mul(&a.c2, &b.c0);
+        Fp6 {
+            c0: self.fp2_add(&t0, &self.fp2_mul(&self.fp2_add(&a1b2, &a2b1), &self.xi)),
+            c1: self.fp2_add(&self.fp2_add(&a0b1, &a1b0), &self.fp2_mul(&t2, &self.xi)),
+            c2: self.fp2_add(&self.fp2_add(&a0b2, &a2b0), &t1),
+        }
+    }
+
+    /// **fp6_mul_by_v** -- (c0 + c1v + c2v^2)v = xi * c2 + c0v + c1v^2
+    pub fn fp6_mul_by_v(&self, a: &Fp6) -> Fp6 {
+        Fp6 {
+            c0: self.fp2_mul(&a.c2, &self.xi),
+            c1: a.c0.clone(),
+            c2: a.c1.clone(),
+        }
+    }
+
+    pub fn fp6_inverse(&self, a: &Fp6) -> crate::Result<Fp6> {
+        // a^(-1) = (A + Bv + Cv^2) / F, where F = a0A + xi(a2B + a1C) lies in GF(p^2)
+        let a_ = self.fp2_sub(
+            &self.fp2_square(&a.c0),
+            &self.fp2_mul(&self.xi, &self.fp2_mul(&a.c1, &a.c2)),
+        );
+        let b_ = self.fp2_sub(
+            &self.fp2_mul(&self.xi, &self.fp2_square(&a.c2)),
+            &self.fp2_mul(&a.c0, &a.c1),
+        );
+        let c_ = self.fp2_sub(&self.fp2_square(&a.c1), &self.fp2_mul(&a.c0, &a.c2));
+        let f = self.fp2_add(
+            &self.fp2_mul(&a.c0, &a_),
+            &self.fp2_mul(
+                &self.xi,
+                &self.fp2_add(&self.fp2_mul(&a.c2, &b_), &self.fp2_mul(&a.c1, &c_)),
+            ),
+        );
+        let f_inv = self.fp2_inverse(&f)?;
+        Ok(Fp6 {
+            c0: self.fp2_mul(&a_, &f_inv),
+            c1: self.fp2_mul(&b_, &f_inv),
+            c2: self.fp2_mul(&c_, &f_inv),
+        })
+    }
+
+    /// **fp12_mul** -- (a0 + a1w)(b0 + b1w) = a0b0 + a1b1v + ((a0 + a1)(b0 + b1) - a0b0 - a1b1)w
+    pub fn fp12_mul(&self, a: &Fp12, b: &Fp12) -> Fp12 {
+        let t0 = self.fp6_mul(&a.c0, &b.c0);
+        let t1 = self.fp6_mul(&a.c1, &b.c1);
+        let t2 = self.fp6_mul(&self.fp6_add(&a.c0, &a.c1), &self.fp6_add(&b.c0, &b.c1));
+        Fp12 {
+            c0: self.fp6_add(&t0, &self.fp6_mul_by_v(&t1)),
+            c1: self.fp6_sub(&self.fp6_sub(&t2, &t0), &t1),
+        }
+    }
+
+    pub fn fp12_square(&self, a: &Fp12) -> Fp12 {
+        self.fp12_mul(a, a)
+    }
+
+    /// **fp12_conjugate** -- a0 - a1w = a^(p^6), for elements of norm 1 it equals a^(-1)
+    pub fn fp12_conjugate(&self, a: &Fp12) -> Fp12 {
+        Fp12 {
+            c0: a.c0.clone(),
+            c1: self.fp6_neg(&a.c1),
+        }
+    }
+
+    /// **fp12_inverse** -- (a0 - a1w) / (a0^2 - a1^2 v)
+    pub fn fp12_inverse(&self, a: &Fp12) -> crate::Result<Fp12> {
+        let norm = self.fp6_sub(
+            &self.fp6_mul(&a.c0, &a.c0),
+            &self.fp6_mul_by_v(&self.fp6_mul(&a.c1, &a.c1)),
+        );
+        let norm_inv = self.fp6_inverse(&norm)?;
+        Ok(Fp12 {
+            c0: self.fp6_mul(&a.c0, &norm_inv),
+            c1: self.fp6_neg(&self.fp6_mul(&a.c1, &norm_inv)),
+        })
+    }
+
+    /// **fp12_frobenius** -- a^(p^k): (g_i w^i)^p = conj(g_i) * w^(i(p - 1)) * w^i
+    pub fn fp12_frobenius(&self, a: &Fp12, k: usize) -> Fp12 {
+        let mut g = a.powers_of_w();
+        for _ in 0..k {
+            for (i, gi) in g.iter_mut().enumerate() {
+                *gi = self.fp2_mul(&self.fp2_conjugate(gi), &self.frobenius_coeffs[i]);
+            }
+        }
+        Fp12::from_powers_of_w(g)
+    }
+
+    pub fn fp12_pow(&self, a: &Fp12, e: &BigInt) -> Fp12 {
+        let mut r = Fp12::one();
+        for i in (0..e.bits()).rev() {
+            r = self.fp12_square(&r);
+            if e.bit(i) {
+                r = self.fp12_mul(&r, a);
+            }
+        }
+        r
+    }
+}
+
+impl Display for Fp2 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:x} + {:x} * u", self.c0, self.c1)
+    }
+}
+
+impl Display for Fp6 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}) + ({}) * v + ({}) * v^2", self.c0, self.c1, self.c2)
+    }
+}
+
+impl Display for Fp12 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}) + ({}) * w", self.c0, self.c1)
+    }
+}
@@ -0,0 +1,155 @@
+use crate::generation::is_probable_prime;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+/// **TRIAL_DIVISION_BOUND** -- all prime factors below this bound are found with trial division
+pub const TRIAL_DIVISION_BOUND: u32 = 1 << 16;
+/// **RHO_ITERATIONS** -- limit of iterations of Pollard's rho per composite number,
+/// factors up to ~2^32 are usually found within it
+pub const RHO_ITERATIONS: u64 = 1 << 16;
+/// **RHO_BATCH** -- number of |x - y| multiplied together before taking gcd
+const RHO_BATCH: u64 = 128;
+/// **RHO_ATTEMPTS** -- number of polynomials x^2 + c tried, if the cycle doesn't split n
+const RHO_ATTEMPTS: u32 = 4;
+
+/// **Factorization** -- n = prod(p_i^e_i) * cofactor, where p_i are (probable) primes
+/// in ascending order and cofactor is the composite part, that wasn't factored (1 if n is factored)
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Factorization {
+    pub factors: Vec<(BigUint, u32)>,
+    pub cofactor: BigUint,
+}
+
+impl Factorization {
+    /// **is_complete** -- true if n is fully factored
+    pub fn is_complete(&self) -> bool {
+        self.cofactor.is_one()
+    }
+
+    /// **largest_factor** -- the largest prime factor found, doesn't take cofactor into account
+    pub fn largest_factor(&self) -> Option<&BigUint> {
+        self.factors.last().map(|(p, _)| p)
+    }
+}
+
+/// **factorize** -- trial division up to `TRIAL_DIVISION_BOUND`, then Pollard's rho
+/// (Brent's variant) for the composite parts, primality is checked with `is_probable_prime`.
+/// Parts that aren't split within `RHO_ITERATIONS` are left in cofactor
+pub fn factorize(n: &BigUint) -> Factorization {
+    let mut factors = Vec::new();
+    let mut cofactor = BigUint::one();
+    if n.is_zero() {
+        return Factorization {
+            factors,
+            cofactor: n.clone(),
+        };
+    }
+
+    let mut rest = n.clone();
+    let mut d = 2_u32;
+    while d < TRIAL_DIVISION_BOUND && BigUint::from(d) * d <= rest {
+        let mut e = 0;
+        while (&rest % d).is_zero() {
+            rest /= d;
+            e += 1;
+        }
+        if e > 0 {
+            factors.push((BigUint::from(d), e));
+        }
+        d += 1 + (d & 1);
+    }
+
+    let mut composites = vec![rest];
+    while let Some(m) = composites.pop() {
+        if m.is_one() {
+            continue;
+        }
+        if is_probable_prime(&m) {
+            push_factor(&mut factors, m);
+            continue;
+        }
+        let root = m.sqrt();
+        if &root * &root == m {
+            composites.extend([root.clone(), root]);
+            continue;
+        }
+        match pollard_rho(&m) {
+            Some(d) => {
+                composites.push(&m / &d);
+                composites.push(d);
+            }
+            None => cofactor *= m,
+        }
+    }
+    factors.sort();
+    Factorization { factors, cofactor }
+}
+
+fn push_factor(factors: &mut Vec<(BigUint, u32)>, p: BigUint) {
+    match factors.iter_mut().find(|(q, _)| *q == p) {
+        Some((_, e)) => *e += 1,
+        None => factors.push((p, 1)),
+    }
+}
+
+fn pollard_rho(n: &BigUint) -> Option<BigUint> {
+    for c in 1..=RHO_ATTEMPTS {
+        match brent(n, c) {
+            Some(d) if d != *n => return Some(d),
+            Some(_) => continue,
+            None => return None,
+        }
+    }
+    None
+}
+
+// returns n, if the cycle is found without splitting n, and None, if iterations are exhausted
+fn brent(n: &BigUint, c: u32) -> Option<BigUint> {
+    let f = |x: &BigUint| (x * x + c) % n;
+    let diff = |a: &BigUint, b: &BigUint| if a > b { a - b } else { b - a };
+    let (mut y, mut r, mut prod) = (BigUint::from(2_u8), 1_u64, BigUint::one());
+    let mut ys = y.clone();
+    let mut g = BigUint::one();
+    let x = loop {
+        if r > RHO_ITERATIONS {
+            return None;
+        }
+        let x = y.clone();
+        for _ in 0..r {
+            y = f(&y);
+        }
+        let mut k = 0;
+        while k < r && g.is_one() {
+            ys = y.clone();
+            for _ in 0..RHO_BATCH.min(r - k) {
+                y = f(&y);
+                prod = (prod * diff(&x, &y)) % n;
+            }
+            g = gcd(&prod, n);
+            k += RHO_BATCH;
+        }
+        r <<= 1;
+        if !g.is_one() {
+            break x;
+        }
+    };
+    if g == *n {
+        // the batch has overshot, steps are repeated one by one
+        loop {
+            ys = f(&ys);
+            g = gcd(&diff(&x, &ys), n);
+            if !g.is_one() {
+                break;
+            }
+        }
+    }
+    Some(g)
+}
+
+fn gcd(a: &BigUint, b: &BigUint) -> BigUint {
+    let (mut a, mut b) = (a.clone(), b.clone());
+    while !b.is_zero() {
+        (a, b) = (b.clone(), a % b);
+    }
+    a
+}
@@ -0,0 +1,406 @@
+use crate::helpers::{inverse, take_by_bigint_module};
+use crate::traits::Field;
+use crate::EcError;
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::{One, Zero};
+use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// **PrimeField** -- parameters of GF(p) for elements stored as N 64-bit limbs (little-endian)
+/// in Montgomery form a * R mod p, R = 2^(64N). Multiplication is done with CIOS method,
+/// so no heap allocations and no divisions are needed
+#[derive(Clone, PartialEq, Eq)]
+pub struct PrimeField<const N: usize> {
+    p: [u64; N],
+    /// **p_inv** -- -p^{-1} mod 2^64
+    p_inv: u64,
+    /// **r** -- R mod p, i.e. 1 in Montgomery form
+    r: [u64; N],
+    /// **r2** -- R^2 mod p, is used to move numbers into Montgomery form
+    r2: [u64; N],
+    /// **p_minus_2** -- exponent for inversion with Fermat's little theorem
+    p_minus_2: [u64; N],
+}
+
+/// **FieldElement** -- element of GF(p) in Montgomery form, bound to its field
+#[derive(Clone, Copy)]
+pub struct FieldElement<'a, const N: usize> {
+    limbs: [u64; N],
+    field: &'a PrimeField<N>,
+}
+
+// a + b * c + carry
+#[inline(always)]
+fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = (a as u128) + (b as u128) * (c as u128) + (carry as u128);
+    (t as u64, (t >> 64) as u64)
+}
+
+// a + b + carry
+#[inline(always)]
+fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = (a as u128) + (b as u128) + (carry as u128);
+    (t as u64, (t >> 64) as u64)
+}
+
+// a - b - borrow, borrow is 0 or 1
+#[inline(always)]
+fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let t = (a as u128).wrapping_sub((b as u128) + (borrow as u128));
+    (t as u64, (t >> 127) as u64)
+}
+
+fn sub_limbs<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], u64) {
+    let mut r = [0_u64; N];
+    let mut borrow = 0;
+    for i in 0..N {
+        (r[i], borrow) = sbb(a[i], b[i], borrow);
+    }
+    (r, borrow)
+}
+
+fn to_limbs<const N: usize>(x: &BigInt) -> [u64; N] {
+    let mut r = [0_u64; N];
+    for (limb, digit) in r.iter_mut().zip(x.magnitude().iter_u64_digits()) {
+        *limb = digit;
+    }
+    r
+}
+
+fn from_limbs<const N: usize>(x: &[u64; N]) -> BigInt {
+    let bytes: Vec<u8> = x.iter().flat_map(|limb| limb.to_le_bytes()).collect();
+    BigInt::from_bytes_le(Sign::Plus, &bytes)
+}
+
+impl<const N: usize> PrimeField<N> {
+    /// **new** -- modulus has to be odd prime, that fits into N limbs
+    pub fn new(p: &BigInt) -> crate::Result<Self> {
+        if p.sign() != Sign::Plus || p.bits() > 64 * N as u64 || !p.bit(0) || p.is_one() {
+            return Err(EcError::IncorrectParameters(format!(
+                "modulus {p} has to be odd and fit into {N} limbs"
+            )));
+        }
+        let limbs = to_limbs::<N>(p);
+        // Newton iteration for inverse mod 2^64, every step doubles number of correct bits
+        let mut inv = 1_u64;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2_u64.wrapping_sub(limbs[0].wrapping_mul(inv)));
+        }
+        Ok(PrimeField {
+            p: limbs,
+            p_inv: inv.wrapping_neg(),
+            r: to_limbs(&((BigInt::one() << (64 * N)) % p)),
+            r2: to_limbs(&((BigInt::one() << (128 * N)) % p)),
+            p_minus_2: to_limbs(&(p - BigInt::from(2_u8))),
+        })
+    }
+
+    pub fn modulus(&self) -> BigInt {
+        from_limbs(&self.p)
+    }
+
+    pub fn zero(&self) -> FieldElement<'_, N> {
+        FieldElement {
+            limbs: [0; N],
+            field: self,
+        }
+    }
+
+    pub fn one(&self) -> FieldElement<'_, N> {
+        FieldElement {
+            limbs: self.r,
+            field: self,
+        }
+    }
+
+    /// **element** -- takes x by module p and moves it into Montgomery form
+    pub fn element(&self, x: &BigInt) -> FieldElement<'_, N> {
+        let p = self.modulus();
+        let x = match x.sign() {
+            Sign::Minus => (&p - (-x) % &p) % &p,
+            _ => x % &p,
+        };
+        FieldElement {
+            limbs: self.mont_mul(&to_limbs(&x), &self.r2),
+            field: self,
+        }
+    }
+
+    pub fn from_u64(&self, x: u64) -> FieldElement<'_, N> {
+        self.element(&BigInt::from(x))
+    }
+
+    /// **small_combination** -- sum c_i x_i for |c_i| < 2^32, positive and negative products
+    /// are accumulated in N + 1 limbs and reduced only once, so it's much faster than
+    /// multiplications by c_i as field elements
+    pub(crate) fn small_combination<'a>(
+        &'a self,
+        terms: impl Iterator<Item = (i64, FieldElement<'a, N>)>,
+    ) -> FieldElement<'a, N> {
+        let mut acc = [([0_u64; N], 0_u64); 2];
+        for (c, x) in terms {
+            let (sum, high) = &mut acc[(c < 0) as usize];
+            let mut carry = 0;
+            for (limb, x) in sum.iter_mut().zip(x.limbs.iter()) {
+                (*limb, carry) = mac(*limb, *x, c.unsigned_abs(), carry);
+            }
+            *high += carry;
+        }
+        // sum + high * R = sum * R * R^{-1} + high * R^2 * R^{-1}
+        let [pos, neg] = acc.map(|(sum, high)| {
+            let mut h = [0_u64; N];
+            h[0] = high;
+            FieldElement {
+                limbs: self.mont_mul(&sum, &self.r),
+                field: self,
+            } + FieldElement {
+                limbs: self.mont_mul(&h, &self.r2),
+                field: self,
+            }
+        });
+        pos - neg
+    }
+
+    // a * b * R^{-1} mod p
+    fn mont_mul(&self, a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+        let mut t = [0_u64; N];
+        let mut t_n = 0;
+        for b_i in b.iter() {
+            // t += a * b_i
+            let mut carry = 0;
+            for j in 0..N {
+                (t[j], carry) = mac(t[j], a[j], *b_i, carry);
+            }
+            let t_n1;
+            (t_n, t_n1) = adc(t_n, carry, 0);
+
+            // t = (t + m * p) / 2^64, where m is chosen so that the lowest limb becomes zero
+            let m = t[0].wrapping_mul(self.p_inv);
+            let (_, mut carry) = mac(t[0], m, self.p[0], 0);
+            for j in 1..N {
+                (t[j - 1], carry) = mac(t[j], m, self.p[j], carry);
+            }
+            let c;
+            (t[N - 1], c) = adc(t_n, carry, 0);
+            t_n = t_n1 + c;
+        }
+        self.reduce_once(&t, t_n)
+    }
+
+    // x is a difference, p is added to it only if there was a borrow (overflow of the addition
+    // compensates it), p is masked instead of branching on the borrow
+    fn add_p_on_borrow(&self, x: &[u64; N], borrow: u64) -> [u64; N] {
+        let mask = 0_u64.wrapping_sub(borrow & 1);
+        let mut r = [0_u64; N];
+        let mut carry = 0;
+        for (i, limb) in r.iter_mut().enumerate() {
+            (*limb, carry) = adc(x[i], self.p[i] & mask, carry);
+        }
+        r
+    }
+
+    // x < 2p, so at most one subtraction is needed
+    fn reduce_once(&self, x: &[u64; N], carry: u64) -> [u64; N] {
+        let (mut d, borrow) = sub_limbs(x, &self.p);
+        // x - p is taken if there was a carry or no borrow, the choice is done with a mask
+        let mask = 0_u64.wrapping_sub((carry | (borrow ^ 1)) & 1);
+        for i in 0..N {
+            d[i] = (d[i] & mask) | (x[i] & !mask);
+        }
+        d
+    }
+}
+
+impl<const N: usize> Debug for PrimeField<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GF({})", self.modulus())
+    }
+}
+
+impl<'a, const N: usize> FieldElement<'a, N> {
+    pub fn field(&self) -> &'a PrimeField<N> {
+        self.field
+    }
+
+    /// **from_montgomery_limbs** -- element, that is already in Montgomery form
+    pub(crate) fn from_montgomery_limbs(field: &'a PrimeField<N>, limbs: [u64; N]) -> Self {
+        FieldElement { limbs, field }
+    }
+
+    pub(crate) fn montgomery_limbs(&self) -> [u64; N] {
+        self.limbs
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.iter().all(|x| *x == 0)
+    }
+
+    /// **conditional_assign** -- sets self to other if choice == 1, without branches
+    pub fn conditional_assign(&mut self, other: &Self, choice: u64) {
+        let mask = 0_u64.wrapping_sub(choice & 1);
+        for i in 0..N {
+            self.limbs[i] ^= mask & (self.limbs[i] ^ other.limbs[i]);
+        }
+    }
+
+    /// **conditional_swap** -- swaps a and b if choice == 1 and does nothing if choice == 0,
+    /// without branches depending on choice
+    pub fn conditional_swap(a: &mut Self, b: &mut Self, choice: u64) {
+        let mask = 0_u64.wrapping_sub(choice & 1);
+        for i in 0..N {
+            let t = mask & (a.limbs[i] ^ b.limbs[i]);
+            a.limbs[i] ^= t;
+            b.limbs[i] ^= t;
+        }
+    }
+
+    pub fn square(self) -> Self {
+        self * self
+    }
+
+    /// **pow** -- exponentiation by little-endian limbs of exponent (from MSB to LSB)
+    pub fn pow(self, e: &[u64]) -> Self {
+        let mut r = self.field.one();
+        for limb in e.iter().rev() {
+            for i in (0..64).rev() {
+                r = r.square();
+                if (limb >> i) & 1 == 1 {
+                    r = r * self;
+                }
+            }
+        }
+        r
+    }
+
+    /// **inverse** -- a^{p-2} = a^{-1} mod p, zero is mapped to zero
+    pub fn inverse(self) -> Self {
+        self.pow(&self.field.p_minus_2)
+    }
+
+    /// **to_bigint** -- moves element out of Montgomery form, result is in [0, p)
+    pub fn to_bigint(&self) -> BigInt {
+        let mut one = [0_u64; N];
+        one[0] = 1;
+        from_limbs(&self.field.mont_mul(&self.limbs, &one))
+    }
+}
+
+impl<'a, const N: usize> Add for FieldElement<'a, N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let mut r = [0_u64; N];
+        let mut carry = 0;
+        for (i, limb) in r.iter_mut().enumerate() {
+            (*limb, carry) = adc(self.limbs[i], rhs.limbs[i], carry);
+        }
+        FieldElement {
+            limbs: self.field.reduce_once(&r, carry),
+            field: self.field,
+        }
+    }
+}
+
+impl<'a, const N: usize> Sub for FieldElement<'a, N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        let (d, borrow) = sub_limbs(&self.limbs, &rhs.limbs);
+        FieldElement {
+            limbs: self.field.add_p_on_borrow(&d, borrow),
+            field: self.field,
+        }
+    }
+}
+
+impl<'a, const N: usize> Mul for FieldElement<'a, N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        FieldElement {
+            limbs: self.field.mont_mul(&self.limbs, &rhs.limbs),
+            field: self.field,
+        }
+    }
+}
+
+impl<'a, const N: usize> Neg for FieldElement<'a, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.field.zero() - self
+    }
+}
+
+// elements of the same field usually share the reference, so the moduli are compared
+// only for different fields
+impl<'a, const N: usize> PartialEq for FieldElement<'a, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.limbs == other.limbs
+            && (std::ptr::eq(self.field, other.field) || self.field.p == other.field.p)
+    }
+}
+
+impl<'a, const N: usize> Eq for FieldElement<'a, N> {}
+
+impl<'a, const N: usize> Debug for FieldElement<'a, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_bigint())
+    }
+}
+
+impl<'a, const N: usize> Display for FieldElement<'a, N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_bigint())
+    }
+}
+
+/// **BigPrimeField** -- GF(p) for elements stored as BigInt in [0, p - 1], the same arithmetic
+/// `ECurve` uses, size of the modulus isn't limited
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BigPrimeField {
+    p: BigInt,
+}
+
+impl BigPrimeField {
+    pub fn new(p: &BigInt) -> Self {
+        BigPrimeField { p: p.clone() }
+    }
+
+    pub fn modulus(&self) -> BigInt {
+        self.p.clone()
+    }
+
+    pub fn element(&self, x: &BigInt) -> BigInt {
+        take_by_bigint_module(&(x % &self.p), &self.p)
+    }
+}
+
+impl Field for BigPrimeField {
+    type Element = BigInt;
+
+    fn zero(&self) -> BigInt {
+        BigInt::zero()
+    }
+    fn one(&self) -> BigInt {
+        BigInt::one()
+    }
+    fn add(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        self.element(&(a + b))
+    }
+    fn sub(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        self.element(&(a - b))
+    }
+    fn mul(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        self.element(&(a * b))
+    }
+    fn inverse(&self, a: &BigInt) -> crate::Result<BigInt> {
+        inverse(&self.element(a), &self.p)
+    }
+    fn to_integer(&self, a: &BigInt) -> BigUint {
+        self.element(a).to_biguint().unwrap()
+    }
+    fn bits(&self) -> u64 {
+        self.p.bits()
+    }
+}
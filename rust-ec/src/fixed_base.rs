@@ -0,0 +1,179 @@
+use crate::field::{FieldElement, PrimeField};
+use crate::helpers::{field_complete_add, field_jacobian_add, with_limbs, FieldPoint};
+use crate::projective_point::EcPointP;
+use crate::{ECurve, EcInfo};
+use num_bigint::BigUint;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+use std::sync::{Arc, OnceLock};
+
+/// **WINDOW** -- width of the scalar window in bits (radix 16)
+const WINDOW: u64 = 4;
+/// **ENTRIES** -- number of nonzero digits in every window
+const ENTRIES: usize = (1 << WINDOW) - 1;
+
+/// **FixedBaseTable** -- precomputed points j * 16^i * G (j = 1..15) for every 4-bit window i
+/// of the scalar, so k * G = sum(T[i][k_i]) is computed without doublings.
+/// Points are stored in affine form as fixed-width field elements in Montgomery form
+pub struct FixedBaseTable {
+    bp: EcPointP,
+    ecurve: ECurve,
+    windows: u64,
+    /// (X, Y, Z) limbs of every entry, Z is 1 or 0 (for O, that can happen on small curves)
+    points: Vec<u64>,
+}
+
+impl FixedBaseTable {
+    /// **new** -- builds table for scalars up to bit length of n * h,
+    /// returns None if modulus doesn't fit into fixed-width field elements
+    pub fn new(ec_info: &EcInfo) -> Option<Self> {
+        let windows = (&ec_info.n * &ec_info.h).bits().div_ceil(WINDOW);
+        let points = with_limbs!(ec_info.ecurve.q, build_table(ec_info, windows))?;
+        Some(FixedBaseTable {
+            bp: ec_info.bp.clone(),
+            ecurve: ec_info.ecurve.clone(),
+            windows,
+            points,
+        })
+    }
+
+    /// **mul** -- computes k * G in constant time: every window does the same complete addition
+    /// and the entry is selected by scanning the whole row with conditional assignments.
+    /// Returns None if table was built for another base point or k is too long
+    pub fn mul(&self, ec_info: &EcInfo, k: &BigUint) -> Option<EcPointP> {
+        if self.bp != ec_info.bp
+            || !self.ecurve.is_same_curve(&ec_info.ecurve)
+            || k.bits() > self.windows * WINDOW
+        {
+            return None;
+        }
+        with_limbs!(self.ecurve.q, table_mul(self, k))
+    }
+}
+
+fn build_table<const N: usize>(ec_info: &EcInfo, windows: u64) -> Option<Vec<u64>> {
+    let ecurve = &ec_info.ecurve;
+    let field = PrimeField::<N>::new(&ecurve.q).ok()?;
+    let coef_a = field.element(&ecurve.a);
+    let a_minus_3 = ecurve.is_a_minus_3();
+
+    // Jacobian points j * 16^i * G
+    let bp = &ec_info.bp;
+    let z = field.element(&bp.z);
+    let mut base = (
+        field.element(&bp.x) * z,
+        field.element(&bp.y) * z.square(),
+        z,
+    );
+    let mut jacobian: Vec<FieldPoint<'_, N>> = Vec::with_capacity(windows as usize * ENTRIES);
+    for _ in 0..windows {
+        let mut p = base;
+        for _ in 0..ENTRIES {
+            jacobian.push(p);
+            p = field_jacobian_add(coef_a, a_minus_3, &p, &base);
+        }
+        base = p;
+    }
+
+    // Montgomery's trick: one inversion for all Z coordinates, O entries are skipped
+    let mut prefix = Vec::with_capacity(jacobian.len());
+    let mut acc = field.one();
+    for p in jacobian.iter() {
+        prefix.push(acc);
+        if !p.2.is_zero() {
+            acc = acc * p.2;
+        }
+    }
+    let mut inv = acc.inverse();
+    let mut affine = vec![(field.zero(), field.one(), field.zero()); jacobian.len()];
+    for (i, p) in jacobian.iter().enumerate().rev() {
+        if p.2.is_zero() {
+            continue;
+        }
+        let z_inv = inv * prefix[i];
+        inv = inv * p.2;
+        let z_inv2 = z_inv.square();
+        affine[i] = (p.0 * z_inv2, p.1 * z_inv2 * z_inv, field.one());
+    }
+
+    Some(
+        affine
+            .iter()
+            .flat_map(|p| [p.0, p.1, p.2])
+            .flat_map(|x| x.montgomery_limbs())
+            .collect(),
+    )
+}
+
+fn table_mul<const N: usize>(table: &FixedBaseTable, k: &BigUint) -> Option<EcPointP> {
+    let ecurve = &table.ecurve;
+    let field = PrimeField::<N>::new(&ecurve.q).ok()?;
+    let coef_a = field.element(&ecurve.a);
+    let coef_b = field.element(&ecurve.b);
+    let a_minus_3 = ecurve.is_a_minus_3();
+    let element = |offset: usize| {
+        FieldElement::from_montgomery_limbs(
+            &field,
+            table.points[offset..offset + N].try_into().unwrap(),
+        )
+    };
+
+    let mut k_limbs = k.to_u64_digits();
+    k_limbs.resize((table.windows * WINDOW).div_ceil(64) as usize, 0);
+    let neutral = (field.zero(), field.one(), field.zero());
+    let mut r = neutral;
+    for i in 0..table.windows {
+        let digit = (k_limbs[(i * WINDOW / 64) as usize] >> (i * WINDOW % 64)) & ENTRIES as u64;
+        let mut s = neutral;
+        for j in 0..ENTRIES {
+            let offset = (i as usize * ENTRIES + j) * 3 * N;
+            // 1 if digit == j + 1, 0 otherwise
+            let choice = ((digit ^ (j as u64 + 1)).wrapping_sub(1) >> 63) & 1;
+            s.0.conditional_assign(&element(offset), choice);
+            s.1.conditional_assign(&element(offset + N), choice);
+            s.2.conditional_assign(&element(offset + 2 * N), choice);
+        }
+        r = field_complete_add(coef_a, coef_b, a_minus_3, &r, &s);
+    }
+
+    if r.2.is_zero() {
+        return Some(EcPointP::neutral());
+    }
+    Some(EcPointP {
+        x: r.0.to_bigint(),
+        y: r.1.to_bigint(),
+        z: r.2.to_bigint(),
+    })
+}
+
+/// **FixedBaseCache** -- lazily built table, that is shared between clones of EcInfo,
+/// it doesn't take part in comparison of domain parameters
+#[derive(Clone, Default)]
+pub(crate) struct FixedBaseCache(Arc<OnceLock<Option<FixedBaseTable>>>);
+
+impl FixedBaseCache {
+    pub(crate) fn get_or_build(&self, ec_info: &EcInfo) -> Option<&FixedBaseTable> {
+        self.0.get_or_init(|| FixedBaseTable::new(ec_info)).as_ref()
+    }
+}
+
+impl PartialEq for FixedBaseCache {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl PartialOrd for FixedBaseCache {
+    fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+}
+
+impl Debug for FixedBaseCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.0.get() {
+            Some(Some(table)) => write!(f, "FixedBaseTable({} windows)", table.windows),
+            _ => write!(f, "FixedBaseTable(not built)"),
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use crate::field::PrimeField;
+use crate::helpers::{inverse, take_by_bigint_module, with_limbs};
+use crate::modular_polynomial::ModularPolynomials;
+use crate::point_counting::{sea_in_field, trace_mod_l, BSGS_BITS, NAIVE_BITS};
+use crate::projective_point::EcPointP;
+use crate::sqrt::sqrt_mod;
+use crate::{ECurve, EcError, EcInfo, Params};
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::{One, Zero};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+
+/// **SIEVE_PRIMES** -- #E mod l is found with Schoof's step for these l before counting points,
+/// so most of curves with composite order are rejected without full point counting
+const SIEVE_PRIMES: [u64; 4] = [2, 3, 5, 7];
+/// **MILLER_RABIN_ROUNDS** -- number of pseudo-random bases in `is_probable_prime`
+const MILLER_RABIN_ROUNDS: u32 = 32;
+
+/// **SeedHash** -- hash function, that expands the seed into coefficients of the curve
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SeedHash {
+    /// ANSI X9.62 and FIPS 186-4, used for P-192, P-224, P-256, P-384 and P-521
+    Sha1,
+    /// the same procedure with 256-bit output (ANSI X9.62-2005)
+    Sha256,
+}
+
+impl SeedHash {
+    fn digest(&self, data: &[u8]) -> Vec<u8> {
+        match self {
+            SeedHash::Sha1 => Sha1::digest(data).to_vec(),
+            SeedHash::Sha256 => Sha256::digest(data).to_vec(),
+        }
+    }
+
+    fn output_bits(&self) -> u64 {
+        match self {
+            SeedHash::Sha1 => 160,
+            SeedHash::Sha256 => 256,
+        }
+    }
+}
+
+/// **SeededCurve** -- verifiably random curve of prime order: domain parameters (h = 1)
+/// and the seed, from which both coefficient b and base point are derived
+#[derive(Clone, Debug)]
+pub struct SeededCurve {
+    pub info: EcInfo,
+    pub seed: Vec<u8>,
+    pub hash: SeedHash,
+}
+
+impl SeededCurve {
+    /// **verify** -- checks the whole chain without point counting: curve and base point
+    /// are the ones derived from the seed, n is prime, n * bp = O and n is the only multiple
+    /// of n in Hasse interval, i.e. #E = n
+    pub fn verify(&self) -> bool {
+        let ec = &self.info.ecurve;
+        let q = ec.q.to_biguint().unwrap();
+        let n = &self.info.n;
+        let derived = match curve_from_seed(&ec.q, &self.seed, self.hash) {
+            Ok(derived) => derived,
+            Err(_) => return false,
+        };
+        // (q + 1 - n)^2 <= 4q and 2n - q - 1 > 2sqrt(q)
+        let in_hasse = |m: &BigUint| {
+            let t = BigInt::from(&q + BigUint::one()) - BigInt::from(m.clone());
+            &t * &t <= BigInt::from(&q << 2_u8)
+        };
+        let double_n = BigInt::from(n << 1_u8) - BigInt::from(&q + BigUint::one());
+        self.info.h.is_one()
+            && derived.is_same_curve(ec)
+            && ec.take_by_module(&self.info.bp)
+                == ec.take_by_module(&base_point(ec, &self.seed, self.hash))
+            && is_probable_prime(n)
+            && ec.proj_point_mul(&self.info.bp, n).is_inf()
+            && in_hasse(n)
+            && double_n.sign() == Sign::Plus
+            && &double_n * &double_n > BigInt::from(&q << 2_u8)
+    }
+}
+
+/// **seed_to_c** -- integer c, that is derived from the seed as in ANSI X9.62, A.3.3.1
+/// (FIPS 186-4, D.6): W0 || SHA(seed + 1) || ... || SHA(seed + s), where W0 is the rightmost
+/// bits of SHA(seed) with cleared leftmost bit and the length of c equals to the length of q
+pub fn seed_to_c(q: &BigInt, seed: &[u8], hash: SeedHash) -> crate::Result<BigInt> {
+    let v = hash.output_bits();
+    if (seed.len() as u64) * 8 < v {
+        return Err(EcError::IncorrectParameters(format!(
+            "seed has to be at least {v} bits long, got {} bits",
+            seed.len() * 8
+        )));
+    }
+    let t = q.bits();
+    let s = (t - 1) / v;
+    let h = t - s * v;
+
+    let mut w = BigInt::from_bytes_be(Sign::Plus, &hash.digest(seed));
+    w &= (BigInt::one() << (h - 1)) - BigInt::one();
+    let mut seed_i = seed.to_vec();
+    for _ in 0..s {
+        seed_i = next_seed(&seed_i);
+        w <<= v;
+        w |= BigInt::from_bytes_be(Sign::Plus, &hash.digest(&seed_i));
+    }
+    Ok(w)
+}
+
+/// **curve_from_seed** -- curve y^2 = x^3 - 3x + b over GF(q), where c * b^2 = -27 mod q
+/// for c from `seed_to_c` (a^3 / b^2 = c as in ANSI X9.62). From the two roots the even b is taken.
+/// Fails if c gives singular curve or -27 / c is a quadratic non-residue
+pub fn curve_from_seed(q: &BigInt, seed: &[u8], hash: SeedHash) -> crate::Result<ECurve> {
+    let c = seed_to_c(q, seed, hash)? % q;
+    let err = |msg: &str| Err(EcError::IncorrectParameters(format!("c = {c}: {msg}")));
+    if c.is_zero() || ((BigInt::from(4_u8) * &c + BigInt::from(27_u8)) % q).is_zero() {
+        return err("curve is singular");
+    }
+    let b2 = BigInt::from(-27) * inverse(&c, q)?;
+    let b = match sqrt_mod(&b2, q) {
+        Some(b) => b,
+        None => return err("-27 / c is a quadratic non-residue"),
+    };
+    let b = match b.bit(0) {
+        true => q - b,
+        false => b,
+    };
+    ECurve::new(Params {
+        a: BigInt::from(-3),
+        b,
+        q: q.clone(),
+    })
+}
+
+/// **verify_seed** -- checks that coefficients of the curve are generated from the seed,
+/// i.e. c * b^2 = a^3 mod q. Works for curves with published seeds, e.g. P-256 with SHA-1
+pub fn verify_seed(ec: &ECurve, seed: &[u8], hash: SeedHash) -> bool {
+    let q = &ec.q;
+    match seed_to_c(q, seed, hash) {
+        Ok(c) => {
+            let lhs = take_by_bigint_module(&((&c * &ec.b * &ec.b) % q), q);
+            let rhs = take_by_bigint_module(&((&ec.a * &ec.a * &ec.a) % q), q);
+            !(&c % q).is_zero() && lhs == rhs
+        }
+        Err(_) => false,
+    }
+}
+
+/// **base_point** -- the first point with even y and x = SHA(seed || i) mod q, i = 0, 1, ...
+/// (i is 4-byte big-endian counter). For curves of prime order every such point is a generator
+pub fn base_point(ec: &ECurve, seed: &[u8], hash: SeedHash) -> EcPointP {
+    let mut i = 0_u32;
+    loop {
+        let mut data = seed.to_vec();
+        data.extend(i.to_be_bytes());
+        let x = BigInt::from_bytes_be(Sign::Plus, &hash.digest(&data)) % &ec.q;
+        if let Ok(p) = ec.lift_x(&x, false) {
+            return EcPointP::from_affine(&p);
+        }
+        i += 1;
+    }
+}
+
+/// **generate_curve** -- searches for curve of prime order over GF(q) starting from the seed,
+/// each next seed is the previous one plus 1 (as big-endian integer of the same length).
+/// Most of curves are rejected by #E mod small l, orders of the rest are found with SEA and
+/// modular polynomials are computed once for all seeds, so a curve over 224-bit field takes
+/// about 20 seconds of point counting in release build and a search usually takes minutes
+pub fn generate_curve(
+    q: &BigInt,
+    seed: &[u8],
+    hash: SeedHash,
+    max_seeds: usize,
+) -> crate::Result<SeededCurve> {
+    with_limbs!(q, field_generate_curve(q, seed, hash, max_seeds)).unwrap_or_else(|| {
+        Err(EcError::IncorrectParameters(format!(
+            "modulus {q} doesn't fit into fixed-width field elements"
+        )))
+    })
+}
+
+fn field_generate_curve<const N: usize>(
+    q: &BigInt,
+    seed: &[u8],
+    hash: SeedHash,
+    max_seeds: usize,
+) -> Option<crate::Result<SeededCurve>> {
+    let field = PrimeField::<N>::new(q).ok()?;
+    let mut polys = ModularPolynomials::new(&field);
+    Some(search_curve(q, seed, hash, max_seeds, &mut polys))
+}
+
+fn search_curve<const N: usize>(
+    q: &BigInt,
+    seed: &[u8],
+    hash: SeedHash,
+    max_seeds: usize,
+    polys: &mut ModularPolynomials<'_, N>,
+) -> crate::Result<SeededCurve> {
+    let mut seed = seed.to_vec();
+    for _ in 0..max_seeds {
+        if let Ok(ec) = curve_from_seed(q, &seed, hash) {
+            if let Some(n) = prime_order(&ec, polys)? {
+                let bp = base_point(&ec, &seed, hash);
+                let info = EcInfo::new(bp, n, BigUint::one(), ec)?;
+                return Ok(SeededCurve { info, seed, hash });
+            }
+        }
+        seed = next_seed(&seed);
+    }
+    Err(EcError::IncorrectParameters(format!(
+        "no curve of prime order is found among {max_seeds} seeds"
+    )))
+}
+
+/// **is_probable_prime** -- trial division by small primes and Miller-Rabin test with base 2
+/// and bases derived from SHA-256(n || i), so they can't be chosen in advance for specific n
+pub fn is_probable_prime(n: &BigUint) -> bool {
+    let small = [2_u32, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    for p in small {
+        if (n % p).is_zero() {
+            return *n == BigUint::from(p);
+        }
+    }
+    if *n < BigUint::from(41_u32 * 41) {
+        return *n > BigUint::one();
+    }
+
+    let n_minus_1 = n - BigUint::one();
+    let e = n_minus_1.trailing_zeros().unwrap();
+    let d = &n_minus_1 >> e;
+    let is_witness = |a: &BigUint| {
+        let mut x = a.modpow(&d, n);
+        if x.is_one() || x == n_minus_1 {
+            return false;
+        }
+        for _ in 1..e {
+            x = (&x * &x) % n;
+            if x == n_minus_1 {
+                return false;
+            }
+        }
+        true
+    };
+    if is_witness(&BigUint::from(2_u8)) {
+        return false;
+    }
+    (0..MILLER_RABIN_ROUNDS).all(|i| {
+        let mut data = n.to_bytes_be();
+        data.extend(i.to_be_bytes());
+        let a = BigUint::from_bytes_be(&Sha256::digest(&data)) % (n - 3_u8) + 2_u8;
+        !is_witness(&a)
+    })
+}
+
+// #E if it is prime and != q (anomalous curves are broken by Smart's attack)
+fn prime_order<const N: usize>(
+    ec: &ECurve,
+    polys: &mut ModularPolynomials<'_, N>,
+) -> crate::Result<Option<BigUint>> {
+    let q = ec.q.to_biguint().unwrap();
+    if q.bits() > NAIVE_BITS {
+        for l in SIEVE_PRIMES {
+            // #E = q + 1 - t mod l
+            if (&q + 1_u8 + l - trace_mod_l(ec, l)?) % l == BigUint::zero() {
+                return Ok(None);
+            }
+        }
+    }
+    let n = match q.bits() > BSGS_BITS {
+        true => sea_in_field(ec, polys)?,
+        false => ec.order()?,
+    };
+    match n != q && is_probable_prime(&n) {
+        true => Ok(Some(n)),
+        false => Ok(None),
+    }
+}
+
+fn next_seed(seed: &[u8]) -> Vec<u8> {
+    let mut next = seed.to_vec();
+    for byte in next.iter_mut().rev() {
+        let (b, overflow) = byte.overflowing_add(1);
+        *byte = b;
+        if !overflow {
+            break;
+        }
+    }
+    next
+}
@@ -0,0 +1,87 @@
+use crate::helpers::{projective_add, projective_double, take_by_bigint_module};
+use crate::projective_point::EcPointP;
+use crate::ECurve;
+use num_bigint::{BigInt, BigUint, Sign};
+
+/// **GlvEndomorphism** -- efficiently computable endomorphism φ(x, y) = (βx, y) of EC with a = 0
+/// and q ≡ 1 mod 3. On points of order n it acts as multiplication by λ, i.e. φ(P) = λP,
+/// that allows to split scalar k into two half-length scalars: kP = k1*P + k2*φ(P)
+/// (Gallant–Lambert–Vanstone method)
+#[derive(PartialOrd, PartialEq, Clone, Debug)]
+pub struct GlvEndomorphism {
+    /// **beta** -- nontrivial cube root of unity mod q
+    pub beta: BigInt,
+    /// **lambda** -- nontrivial cube root of unity mod n, such that φ(P) = λP
+    pub lambda: BigInt,
+    /// **n** -- order of the subgroup where φ acts as multiplication by λ
+    pub n: BigInt,
+    /// **(a1, b1), (a2, b2)** -- short basis of the lattice {(x, y) : x + yλ ≡ 0 mod n}
+    pub a1: BigInt,
+    pub b1: BigInt,
+    pub a2: BigInt,
+    pub b2: BigInt,
+}
+
+impl GlvEndomorphism {
+    /// **apply** -- computes φ(X : Y : Z) = (βX : Y : Z)
+    pub fn apply(&self, ec_curve: &ECurve, p: &EcPointP) -> EcPointP {
+        if p.is_inf() {
+            return EcPointP::neutral();
+        }
+        EcPointP {
+            x: take_by_bigint_module(&((&p.x * &self.beta) % &ec_curve.q), &ec_curve.q),
+            y: p.y.clone(),
+            z: p.z.clone(),
+        }
+    }
+
+    /// **decompose** -- splits k into (k1, k2), such that k ≡ k1 + k2λ mod n
+    /// and |k1|, |k2| are approximately √n
+    /// (Algorithm 3.74 from "Guide to Elliptic Curve Cryptography")
+    pub fn decompose(&self, k: &BigUint) -> (BigInt, BigInt) {
+        let k = BigInt::from(k.clone()) % &self.n;
+        // b2 > 0 and b1 < 0, so both numerators are non-negative and division rounds properly
+        let round_div =
+            |x: BigInt| (BigInt::from(2_u8) * x + &self.n) / (BigInt::from(2_u8) * &self.n);
+        let c1 = round_div(&self.b2 * &k);
+        let c2 = round_div(-&self.b1 * &k);
+        let k1 = &k - &c1 * &self.a1 - &c2 * &self.a2;
+        let k2 = -&c1 * &self.b1 - &c2 * &self.b2;
+        (k1, k2)
+    }
+}
+
+/// **glv_mul** -- computes kP as k1*P + k2*φ(P) with simultaneous (Shamir's) double-and-add,
+/// so the number of doublings is halved in comparison with `projective_mul`
+pub(crate) fn glv_mul(
+    ec_curve: &ECurve,
+    glv: &GlvEndomorphism,
+    a: &EcPointP,
+    k: &BigUint,
+) -> EcPointP {
+    let (k1, k2) = glv.decompose(k);
+    // negative coordinates are taken by module, otherwise `projective_add` could miss doubling case
+    let p1 = ec_curve.take_by_module(&match k1.sign() {
+        Sign::Minus => a.negative(),
+        _ => a.clone(),
+    });
+    let p2 = ec_curve.take_by_module(&match k2.sign() {
+        Sign::Minus => glv.apply(ec_curve, a).negative(),
+        _ => glv.apply(ec_curve, a),
+    });
+    let p12 = projective_add(ec_curve, &p1, &p2);
+    let (k1, k2) = (k1.magnitude(), k2.magnitude());
+
+    let mut r = EcPointP::neutral();
+    // from MSB to LSB
+    for i in (0..k1.bits().max(k2.bits())).rev() {
+        r = projective_double(ec_curve, &r);
+        match (k1.bit(i), k2.bit(i)) {
+            (true, true) => r = projective_add(ec_curve, &r, &p12),
+            (true, false) => r = projective_add(ec_curve, &r, &p1),
+            (false, true) => r = projective_add(ec_curve, &r, &p2),
+            (false, false) => {}
+        }
+    }
+    r
+}
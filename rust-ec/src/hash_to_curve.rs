@@ -0,0 +1,517 @@
+use crate::affine_point::EcPointA;
+use crate::helpers::take_by_bigint_module;
+use crate::montgomery::{MontgomeryCurve, PreGeneratedMontgomeryParams};
+use crate::sqrt::{jacobi_symbol, sqrt_mod};
+use crate::{ECurve, EcError, EcInfo, PreGeneratedParams};
+use num_bigint::{BigInt, Sign};
+use num_traits::{Num, One, Zero};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::{Shake128, Shake256};
+
+/// **OVERSIZE_DST_PREFIX** -- DST longer than 255 bytes is replaced with H(prefix || DST)
+/// (RFC 9380, section 5.3.3)
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// **XmdHash** -- Merkle-Damgard hash function for `expand_message_xmd`
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum XmdHash {
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl XmdHash {
+    fn digest(&self, data: &[u8]) -> Vec<u8> {
+        match self {
+            XmdHash::Sha256 => Sha256::digest(data).to_vec(),
+            XmdHash::Sha384 => Sha384::digest(data).to_vec(),
+            XmdHash::Sha512 => Sha512::digest(data).to_vec(),
+        }
+    }
+
+    // b_in_bytes
+    fn output_bytes(&self) -> usize {
+        match self {
+            XmdHash::Sha256 => 32,
+            XmdHash::Sha384 => 48,
+            XmdHash::Sha512 => 64,
+        }
+    }
+
+    // s_in_bytes, size of the input block
+    fn block_bytes(&self) -> usize {
+        match self {
+            XmdHash::Sha256 => 64,
+            XmdHash::Sha384 | XmdHash::Sha512 => 128,
+        }
+    }
+}
+
+/// **XofHash** -- extendable-output function for `expand_message_xof`
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum XofHash {
+    Shake128,
+    Shake256,
+}
+
+impl XofHash {
+    fn digest(&self, data: &[u8], len: usize) -> Vec<u8> {
+        let mut out = vec![0_u8; len];
+        match self {
+            XofHash::Shake128 => {
+                let mut h = Shake128::default();
+                h.update(data);
+                h.finalize_xof().read(&mut out);
+            }
+            XofHash::Shake256 => {
+                let mut h = Shake256::default();
+                h.update(data);
+                h.finalize_xof().read(&mut out);
+            }
+        }
+        out
+    }
+
+    // target security level k in bits
+    fn security_bits(&self) -> usize {
+        match self {
+            XofHash::Shake128 => 128,
+            XofHash::Shake256 => 256,
+        }
+    }
+}
+
+/// **Expander** -- the way uniform bytes are derived from the message (RFC 9380, section 5.3)
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Expander {
+    Xmd(XmdHash),
+    Xof(XofHash),
+}
+
+impl Expander {
+    /// **expand_message** -- `expand_message_xmd` or `expand_message_xof` with the chosen hash
+    pub fn expand_message(
+        &self,
+        msg: &[u8],
+        dst: &[u8],
+        len_in_bytes: usize,
+    ) -> crate::Result<Vec<u8>> {
+        match self {
+            Expander::Xmd(hash) => expand_message_xmd(*hash, msg, dst, len_in_bytes),
+            Expander::Xof(hash) => expand_message_xof(*hash, msg, dst, len_in_bytes),
+        }
+    }
+}
+
+/// **expand_message_xmd** -- RFC 9380, section 5.3.1: b_0 = H(Z_pad || msg || l_i_b_str || 0 || DST'),
+/// b_1 = H(b_0 || 1 || DST'), b_i = H((b_0 xor b_(i-1)) || i || DST'), output is the first
+/// len_in_bytes of b_1 || ... || b_ell. Fails if ell > 255 or len_in_bytes > 65535
+pub fn expand_message_xmd(
+    hash: XmdHash,
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> crate::Result<Vec<u8>> {
+    let b_in_bytes = hash.output_bytes();
+    let ell = len_in_bytes.div_ceil(b_in_bytes);
+    if ell > 255 || len_in_bytes > 65535 {
+        return Err(EcError::IncorrectParameters(format!(
+            "expand_message_xmd can't output {len_in_bytes} bytes"
+        )));
+    }
+    let dst_prime = match dst.len() > 255 {
+        true => with_length(&hash.digest(&[OVERSIZE_DST_PREFIX, dst].concat())),
+        false => with_length(dst),
+    };
+
+    let mut msg_prime = vec![0_u8; hash.block_bytes()];
+    msg_prime.extend(msg);
+    msg_prime.extend((len_in_bytes as u16).to_be_bytes());
+    msg_prime.push(0);
+    msg_prime.extend(&dst_prime);
+    let b_0 = hash.digest(&msg_prime);
+
+    let mut b_i = hash.digest(&[&b_0[..], &[1], &dst_prime].concat());
+    let mut uniform_bytes = b_i.clone();
+    for i in 2..=ell {
+        let mut data: Vec<u8> = b_0.iter().zip(&b_i).map(|(x, y)| x ^ y).collect();
+        data.push(i as u8);
+        data.extend(&dst_prime);
+        b_i = hash.digest(&data);
+        uniform_bytes.extend(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    Ok(uniform_bytes)
+}
+
+/// **expand_message_xof** -- RFC 9380, section 5.3.2: H(msg || l_i_b_str || DST', len_in_bytes),
+/// oversized DST is hashed into ceil(2k / 8) bytes for security level k of the XOF
+pub fn expand_message_xof(
+    hash: XofHash,
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> crate::Result<Vec<u8>> {
+    if len_in_bytes > 65535 {
+        return Err(EcError::IncorrectParameters(format!(
+            "expand_message_xof can't output {len_in_bytes} bytes"
+        )));
+    }
+    let dst_prime = match dst.len() > 255 {
+        true => with_length(&hash.digest(
+            &[OVERSIZE_DST_PREFIX, dst].concat(),
+            (2 * hash.security_bits()).div_ceil(8),
+        )),
+        false => with_length(dst),
+    };
+    let mut msg_prime = msg.to_vec();
+    msg_prime.extend((len_in_bytes as u16).to_be_bytes());
+    msg_prime.extend(&dst_prime);
+    Ok(hash.digest(&msg_prime, len_in_bytes))
+}
+
+/// **hash_to_field** -- RFC 9380, section 5.2 for prime field GF(q): `count` elements,
+/// each one is OS2IP of l expanded bytes taken by module q, l = ceil((ceil(log2(q)) + k) / 8)
+pub fn hash_to_field(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+    q: &BigInt,
+    l: usize,
+    expander: Expander,
+) -> crate::Result<Vec<BigInt>> {
+    let uniform_bytes = expander.expand_message(msg, dst, count * l)?;
+    Ok(uniform_bytes
+        .chunks(l)
+        .map(|tv| BigInt::from_bytes_be(Sign::Plus, tv) % q)
+        .collect())
+}
+
+/// **IsogenyMap** -- rational map (x', y') -> (x_num(x') / x_den(x'), y' * y_num(x') / y_den(x')),
+/// coefficients of polynomials are listed from x'^0 up (RFC 9380, appendix E)
+#[derive(Clone, Debug, PartialEq)]
+pub struct IsogenyMap {
+    pub x_num: Vec<BigInt>,
+    pub x_den: Vec<BigInt>,
+    pub y_num: Vec<BigInt>,
+    pub y_den: Vec<BigInt>,
+}
+
+impl IsogenyMap {
+    /// **map** -- image of the point on E' on the target curve, point at infinity
+    /// is returned if a denominator is zero
+    pub fn map(&self, p: &EcPointA, q: &BigInt) -> EcPointA {
+        if p.is_inf() {
+            return p.clone();
+        }
+        let eval = |poly: &[BigInt]| {
+            poly.iter()
+                .rev()
+                .fold(BigInt::zero(), |acc, k| (acc * &p.x + k) % q)
+        };
+        let (x_den, y_den) = (eval(&self.x_den), eval(&self.y_den));
+        if x_den.is_zero() || y_den.is_zero() {
+            return infinity();
+        }
+        EcPointA::new(
+            &field(&(eval(&self.x_num) * inv0(&x_den, q)), q),
+            &field(&(&p.y * eval(&self.y_num) * inv0(&y_den, q)), q),
+        )
+    }
+}
+
+/// **MapToCurve** -- deterministic map from field element to point of the curve
+#[derive(Clone, Debug)]
+pub enum MapToCurve {
+    /// simplified Shallue-van de Woestijne-Ulas method for curves with ab != 0
+    /// (RFC 9380, section 6.6.2), Z is a non-square, Z != -1 and g(b / Za) is a square
+    Sswu { z: BigInt },
+    /// simplified SWU onto isogenous curve E' with a'b' != 0, then isogeny map to the curve,
+    /// used for curves with a = 0 (RFC 9380, section 6.6.3)
+    SswuIsogeny {
+        iso_curve: Box<ECurve>,
+        z: BigInt,
+        isogeny: IsogenyMap,
+    },
+    /// Elligator 2 for Montgomery curve Bv^2 = u^3 + Au^2 + u (RFC 9380, section 6.7.1),
+    /// Z is a non-square, the point is moved to Weierstrass model of the curve afterwards
+    Elligator2 { curve: MontgomeryCurve, z: BigInt },
+}
+
+/// **PreGeneratedSuite** -- hash-to-curve suites from RFC 9380, section 8
+#[derive(Copy, Clone, Debug)]
+pub enum PreGeneratedSuite {
+    /// P256_XMD:SHA-256_SSWU_RO_ and P256_XMD:SHA-256_SSWU_NU_
+    P256XmdSha256Sswu,
+    /// P384_XMD:SHA-384_SSWU_RO_ and P384_XMD:SHA-384_SSWU_NU_
+    P384XmdSha384Sswu,
+    /// P521_XMD:SHA-512_SSWU_RO_ and P521_XMD:SHA-512_SSWU_NU_
+    P521XmdSha512Sswu,
+    /// secp256k1_XMD:SHA-256_SSWU_RO_ and secp256k1_XMD:SHA-256_SSWU_NU_
+    Secp256k1XmdSha256Sswu,
+    /// curve25519_XMD:SHA-512_ELL2_RO_ and curve25519_XMD:SHA-512_ELL2_NU_,
+    /// points are returned in Weierstrass model (`PreGeneratedParams::Curve25519`)
+    Curve25519XmdSha512Ell2,
+}
+
+impl PreGeneratedSuite {
+    /// **suite_id** -- identifier of the suite, random oracle (RO) or nonuniform (NU) encoding
+    pub fn suite_id(&self, random_oracle: bool) -> String {
+        let id = match self {
+            PreGeneratedSuite::P256XmdSha256Sswu => "P256_XMD:SHA-256_SSWU_",
+            PreGeneratedSuite::P384XmdSha384Sswu => "P384_XMD:SHA-384_SSWU_",
+            PreGeneratedSuite::P521XmdSha512Sswu => "P521_XMD:SHA-512_SSWU_",
+            PreGeneratedSuite::Secp256k1XmdSha256Sswu => "secp256k1_XMD:SHA-256_SSWU_",
+            PreGeneratedSuite::Curve25519XmdSha512Ell2 => "curve25519_XMD:SHA-512_ELL2_",
+        };
+        match random_oracle {
+            true => format!("{id}RO_"),
+            false => format!("{id}NU_"),
+        }
+    }
+}
+
+/// **HashToCurveSuite** -- domain parameters of the target curve, expander,
+/// length l of field element in bytes and the mapping
+#[derive(Clone, Debug)]
+pub struct HashToCurveSuite {
+    pub info: EcInfo,
+    pub expander: Expander,
+    pub l: usize,
+    pub map: MapToCurve,
+}
+
+impl From<PreGeneratedSuite> for HashToCurveSuite {
+    fn from(value: PreGeneratedSuite) -> Self {
+        HashToCurveSuite::from(&value)
+    }
+}
+impl From<&PreGeneratedSuite> for HashToCurveSuite {
+    fn from(value: &PreGeneratedSuite) -> Self {
+        let sswu = |params, hash, l, z: i32| HashToCurveSuite {
+            info: EcInfo::from(params),
+            expander: Expander::Xmd(hash),
+            l,
+            map: MapToCurve::Sswu { z: BigInt::from(z) },
+        };
+        match value {
+            PreGeneratedSuite::P256XmdSha256Sswu => {
+                sswu(PreGeneratedParams::P256, XmdHash::Sha256, 48, -10)
+            }
+            PreGeneratedSuite::P384XmdSha384Sswu => {
+                sswu(PreGeneratedParams::P384, XmdHash::Sha384, 72, -12)
+            }
+            PreGeneratedSuite::P521XmdSha512Sswu => {
+                sswu(PreGeneratedParams::P521, XmdHash::Sha512, 98, -4)
+            }
+            PreGeneratedSuite::Secp256k1XmdSha256Sswu => {
+                let info = EcInfo::from(PreGeneratedParams::Secp256k1);
+                let iso_curve = ECurve::new(crate::Params {
+                    a: parse_hex(
+                        "3f8731abdd661adca08a5558f0f5d272e953d363cb6f0e5d405447c01a444533",
+                    ),
+                    b: BigInt::from(1771),
+                    q: info.ecurve.q.clone(),
+                })
+                .unwrap();
+                let coefficients = |k: &[&str]| k.iter().map(|k| parse_hex(k)).collect();
+                // RFC 9380, appendix E.1
+                let isogeny = IsogenyMap {
+                    x_num: coefficients(&[
+                        "8e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38daaaaa8c7",
+                        "07d3d4c80bc321d5b9f315cea7fd44c5d595d2fc0bf63b92dfff1044f17c6581",
+                        "534c328d23f234e6e2a413deca25caece4506144037c40314ecbd0b53d9dd262",
+                        "8e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38e38daaaaa88c",
+                    ]),
+                    x_den: coefficients(&[
+                        "d35771193d94918a9ca34ccbb7b640dd86cd409542f8487d9fe6b745781eb49b",
+                        "edadc6f64383dc1df7c4b2d51b54225406d36b641f5e41bbc52a56612a8c6d14",
+                        "1",
+                    ]),
+                    y_num: coefficients(&[
+                        "4bda12f684bda12f684bda12f684bda12f684bda12f684bda12f684b8e38e23c",
+                        "c75e0c32d5cb7c0fa9d0a54b12a0a6d5647ab046d686da6fdffc90fc201d71a3",
+                        "29a6194691f91a73715209ef6512e576722830a201be2018a765e85a9ecee931",
+                        "2f684bda12f684bda12f684bda12f684bda12f684bda12f684bda12f38e38d84",
+                    ]),
+                    y_den: coefficients(&[
+                        "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffff93b",
+                        "7a06534bb8bdb49fd5e9e6632722c2989467c1bfc8e8d978dfb425d2685c2573",
+                        "6484aa716545ca2cf3a70c3fa8fe337e0a3d21162f0d6299a7bf8192bfd2a76f",
+                        "1",
+                    ]),
+                };
+                HashToCurveSuite {
+                    info,
+                    expander: Expander::Xmd(XmdHash::Sha256),
+                    l: 48,
+                    map: MapToCurve::SswuIsogeny {
+                        iso_curve: Box::new(iso_curve),
+                        z: BigInt::from(-11),
+                        isogeny,
+                    },
+                }
+            }
+            PreGeneratedSuite::Curve25519XmdSha512Ell2 => HashToCurveSuite {
+                info: EcInfo::from(PreGeneratedParams::Curve25519),
+                expander: Expander::Xmd(XmdHash::Sha512),
+                l: 48,
+                map: MapToCurve::Elligator2 {
+                    curve: MontgomeryCurve::from(PreGeneratedMontgomeryParams::Curve25519),
+                    z: BigInt::from(2),
+                },
+            },
+        }
+    }
+}
+
+impl HashToCurveSuite {
+    /// **hash_to_curve** -- random oracle encoding (_RO_ suites): u_0, u_1 = hash_to_field(msg, 2),
+    /// P = h * (map_to_curve(u_0) + map_to_curve(u_1))
+    pub fn hash_to_curve(&self, msg: &[u8], dst: &[u8]) -> crate::Result<EcPointA> {
+        let ec = &self.info.ecurve;
+        let u = hash_to_field(msg, dst, 2, &ec.q, self.l, self.expander)?;
+        let q0 = self.map_to_curve(&u[0])?.to_projective();
+        let q1 = self.map_to_curve(&u[1])?.to_projective();
+        let p = self.info.clear_cofactor(&ec.proj_point_add(&q0, &q1));
+        ec.take_by_module(&p).to_affine(ec)
+    }
+
+    /// **encode_to_curve** -- nonuniform encoding (_NU_ suites): P = h * map_to_curve(u),
+    /// where u = hash_to_field(msg, 1). Only a fraction of points can be its output
+    pub fn encode_to_curve(&self, msg: &[u8], dst: &[u8]) -> crate::Result<EcPointA> {
+        let ec = &self.info.ecurve;
+        let u = hash_to_field(msg, dst, 1, &ec.q, self.l, self.expander)?;
+        let p = self
+            .info
+            .clear_cofactor(&self.map_to_curve(&u[0])?.to_projective());
+        ec.take_by_module(&p).to_affine(ec)
+    }
+
+    /// **map_to_curve** -- point of the curve for field element u, cofactor isn't cleared
+    pub fn map_to_curve(&self, u: &BigInt) -> crate::Result<EcPointA> {
+        let ec = &self.info.ecurve;
+        match &self.map {
+            MapToCurve::Sswu { z } => sswu(ec, z, u),
+            MapToCurve::SswuIsogeny {
+                iso_curve,
+                z,
+                isogeny,
+            } => Ok(isogeny.map(&sswu(iso_curve, z, u)?, &ec.q)),
+            MapToCurve::Elligator2 { curve, z } => {
+                curve.to_weierstrass_point(&elligator2(curve, z, u)?)
+            }
+        }
+    }
+}
+
+/// **sswu** -- simplified SWU map of u to the curve y^2 = x^3 + ax + b with ab != 0
+/// (RFC 9380, section 6.6.2), sign of y equals to the sign of u
+pub fn sswu(ec: &ECurve, z: &BigInt, u: &BigInt) -> crate::Result<EcPointA> {
+    let q = &ec.q;
+    let (a, b) = (&ec.a, &ec.b);
+    if a.is_zero() || b.is_zero() {
+        return Err(EcError::IncorrectParameters(
+            "simplified SWU requires ab != 0".to_string(),
+        ));
+    }
+    let g = |x: &BigInt| field(&(x * x * x + a * x + b), q);
+    let z_u2 = field(&(z * u * u), q);
+    // tv1 = 1 / (Z^2 * u^4 + Z * u^2)
+    let tv1 = inv0(&(&z_u2 * &z_u2 + &z_u2), q);
+    let x1 = match tv1.is_zero() {
+        true => field(&(b * inv0(&(z * a), q)), q),
+        false => field(&(-b * inv0(a, q) * (BigInt::one() + tv1)), q),
+    };
+    let gx1 = g(&x1);
+    let (x, y) = match is_square(&gx1, q) {
+        true => (x1, sqrt_mod(&gx1, q).unwrap()),
+        false => {
+            let x2 = field(&(z_u2 * x1), q);
+            let gx2 = g(&x2);
+            let y = sqrt_mod(&gx2, q).ok_or_else(|| {
+                EcError::IncorrectParameters(format!("Z = {z} isn't suitable for SSWU map"))
+            })?;
+            (x2, y)
+        }
+    };
+    let y = match sgn0(u, q) == sgn0(&y, q) {
+        true => y,
+        false => field(&-y, q),
+    };
+    Ok(EcPointA::new(&x, &y))
+}
+
+/// **elligator2** -- Elligator 2 map of u to Montgomery curve Bv^2 = u^3 + Au^2 + u
+/// (RFC 9380, section 6.7.1), returns point (s, t) of the Montgomery curve
+pub fn elligator2(curve: &MontgomeryCurve, z: &BigInt, u: &BigInt) -> crate::Result<EcPointA> {
+    let q = &curve.get_q();
+    let k_inv = inv0(&curve.get_b(), q);
+    // y^2 = x^3 + J/K x^2 + x/K^2 for x = s / K, y = t / K
+    let j_k = field(&(curve.get_a() * &k_inv), q);
+    let k2_inv = field(&(&k_inv * &k_inv), q);
+    let g = |x: &BigInt| field(&((x + &j_k) * x * x + x * &k2_inv), q);
+
+    let x1 = field(&(-&j_k * inv0(&(BigInt::one() + z * u * u), q)), q);
+    let x1 = match x1.is_zero() {
+        true => field(&-&j_k, q),
+        false => x1,
+    };
+    let gx1 = g(&x1);
+    let (x, y, odd) = match is_square(&gx1, q) {
+        true => (x1, sqrt_mod(&gx1, q).unwrap(), true),
+        false => {
+            let x2 = field(&(-&x1 - &j_k), q);
+            let gx2 = g(&x2);
+            let y = sqrt_mod(&gx2, q).ok_or_else(|| {
+                EcError::IncorrectParameters(format!("Z = {z} isn't suitable for Elligator 2"))
+            })?;
+            (x2, y, false)
+        }
+    };
+    let y = match sgn0(&y, q) == odd {
+        true => y,
+        false => field(&-y, q),
+    };
+    let k = curve.get_b();
+    Ok(EcPointA::new(&field(&(x * &k), q), &field(&(y * k), q)))
+}
+
+// sgn0 for prime field, i.e. parity of x mod q
+fn sgn0(x: &BigInt, q: &BigInt) -> bool {
+    field(x, q).bit(0)
+}
+
+// 0 is a square too
+fn is_square(x: &BigInt, q: &BigInt) -> bool {
+    jacobi_symbol(x, q) != Some(-1)
+}
+
+// x^(q - 2), i.e. 1 / x and 0 for x = 0
+fn inv0(x: &BigInt, q: &BigInt) -> BigInt {
+    field(x, q).modpow(&(q - BigInt::from(2_u8)), q)
+}
+
+fn field(x: &BigInt, q: &BigInt) -> BigInt {
+    take_by_bigint_module(&(x % q), q)
+}
+
+fn infinity() -> EcPointA {
+    EcPointA {
+        x: BigInt::zero(),
+        y: BigInt::zero(),
+        is_inf: true,
+    }
+}
+
+// length of the DST is appended as a single byte
+fn with_length(dst: &[u8]) -> Vec<u8> {
+    let mut dst_prime = dst.to_vec();
+    dst_prime.push(dst.len() as u8);
+    dst_prime
+}
+
+fn parse_hex(x: &str) -> BigInt {
+    BigInt::from_str_radix(x, 16).unwrap()
+}
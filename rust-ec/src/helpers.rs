@@ -1,6 +1,8 @@
 use crate::affine_point::EcPointA;
+use crate::field::{FieldElement, PrimeField};
+use crate::jacobian_point::EcPointJ;
 use crate::projective_point::EcPointP;
-use crate::{ECurve, EcError};
+use crate::{CoordinateSystem, ECurve, EcError};
 use num_bigint::{BigInt, BigUint, ToBigInt};
 use num_traits::{One, Zero};
 
@@ -24,6 +26,54 @@ pub fn projective_to_affine(ec: &ECurve, a: &EcPointP) -> crate::Result<EcPointA
     }
 }
 
+/// **batch_projective_to_affine** -- transforms all points with a single inversion
+/// (Montgomery's trick): z_1 * ... * z_i prefix products take n - 1 multiplications,
+/// and 2(n - 1) more are needed to get every z_i^{-1} from (z_1 * ... * z_n)^{-1}.
+/// Points at infinity (Z = 0 mod q) are skipped and returned as infinity
+pub fn batch_projective_to_affine(
+    ec: &ECurve,
+    points: &[EcPointP],
+) -> crate::Result<Vec<EcPointA>> {
+    let q = &ec.q;
+    let z: Vec<BigInt> = points
+        .iter()
+        .map(|p| take_by_bigint_module(&(&p.z % q), q))
+        .collect();
+
+    // prefix[i] = product of nonzero z_j, j < i
+    let mut prefix = Vec::with_capacity(points.len());
+    let mut acc = BigInt::one();
+    for z_i in z.iter() {
+        prefix.push(acc.clone());
+        if !z_i.is_zero() {
+            acc = (&acc * z_i) % q;
+        }
+    }
+    let mut inv = inverse(&acc, q)?;
+
+    let mut result = vec![
+        EcPointA {
+            x: Default::default(),
+            y: Default::default(),
+            is_inf: true,
+        };
+        points.len()
+    ];
+    for i in (0..points.len()).rev() {
+        if z[i].is_zero() {
+            continue;
+        }
+        let z_inv = (&inv * &prefix[i]) % q;
+        inv = (&inv * &z[i]) % q;
+        result[i] = EcPointA {
+            x: take_by_bigint_module(&((&points[i].x * &z_inv) % q), q),
+            y: take_by_bigint_module(&((&points[i].y * &z_inv) % q), q),
+            is_inf: false,
+        };
+    }
+    Ok(result)
+}
+
 // https://math.stackexchange.com/questions/1737883/convert-affine-coordinates-to-projective-coordinates
 // Y^{2}Z = X^{3} + aXZ^{2} + bZ^3
 pub fn affine_to_projective(a: &EcPointA) -> EcPointP {
@@ -156,3 +206,713 @@ pub(crate) fn projective_mul(ec_curve: &ECurve, a: &EcPointP, k: &BigUint) -> Ec
     }
     r
 }
+
+/// **jacobian_to_affine** -- transforms (X, Y, Z) => (X*(Z^{-2} mod q), Y*(Z^{-3} mod q))
+pub fn jacobian_to_affine(ec: &ECurve, a: &EcPointJ) -> crate::Result<EcPointA> {
+    if a.is_inf() {
+        return Ok(EcPointA {
+            x: Default::default(),
+            y: Default::default(),
+            is_inf: true,
+        });
+    }
+    let inv = inverse(&take_by_bigint_module(&(&a.z % &ec.q), &ec.q), &ec.q)?;
+    let inv2 = (&inv * &inv) % &ec.q;
+    Ok(EcPointA {
+        x: take_by_bigint_module(&((&a.x * &inv2) % &ec.q), &ec.q),
+        y: take_by_bigint_module(&((&a.y * &inv2 * &inv) % &ec.q), &ec.q),
+        is_inf: false,
+    })
+}
+
+pub fn affine_to_jacobian(a: &EcPointA) -> EcPointJ {
+    if a.is_inf {
+        return EcPointJ::neutral();
+    }
+    EcPointJ {
+        x: a.x.clone(),
+        y: a.y.clone(),
+        z: BigInt::one(),
+    }
+}
+
+/// **jacobian_double** -- doubling in Jacobian coordinates,
+/// dbl-2001-b for a = -3 (3(X - Z^2)(X + Z^2) = 3X^2 - 3Z^4) and dbl-2007-bl otherwise
+/// https://hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-3.html
+pub(crate) fn jacobian_double(ec_curve: &ECurve, a: &EcPointJ) -> EcPointJ {
+    let q = &ec_curve.q;
+    if a.is_inf() || take_by_bigint_module(&(&a.y % q), q).is_zero() {
+        return EcPointJ::neutral();
+    }
+    let (x3, y3, z3) = if ec_curve.is_a_minus_3() {
+        let delta = (&a.z * &a.z) % q;
+        let gamma = (&a.y * &a.y) % q;
+        let beta = (&a.x * &gamma) % q;
+        let alpha = (BigInt::from(3_u8) * (&a.x - &delta) * (&a.x + &delta)) % q;
+        let x3 = (&alpha * &alpha - BigInt::from(8_u8) * &beta) % q;
+        let z3 = ((&a.y + &a.z) * (&a.y + &a.z) - &gamma - &delta) % q;
+        let y3 = (&alpha * (BigInt::from(4_u8) * &beta - &x3)
+            - BigInt::from(8_u8) * &gamma * &gamma)
+            % q;
+        (x3, y3, z3)
+    } else {
+        let xx = (&a.x * &a.x) % q;
+        let yy = (&a.y * &a.y) % q;
+        let yyyy = (&yy * &yy) % q;
+        let zz = (&a.z * &a.z) % q;
+        let s = (BigInt::from(2_u8) * ((&a.x + &yy) * (&a.x + &yy) - &xx - &yyyy)) % q;
+        let m = (BigInt::from(3_u8) * &xx + &ec_curve.a * &zz * &zz) % q;
+        let x3 = (&m * &m - BigInt::from(2_u8) * &s) % q;
+        let y3 = (&m * (&s - &x3) - BigInt::from(8_u8) * &yyyy) % q;
+        let z3 = ((&a.y + &a.z) * (&a.y + &a.z) - &yy - &zz) % q;
+        (x3, y3, z3)
+    };
+    EcPointJ {
+        x: take_by_bigint_module(&x3, q),
+        y: take_by_bigint_module(&y3, q),
+        z: take_by_bigint_module(&z3, q),
+    }
+}
+
+/// **jacobian_add** -- addition in Jacobian coordinates (add-2007-bl),
+/// if b has Z = 1, then cheaper mixed Jacobian-affine addition (madd-2007-bl) is used
+pub(crate) fn jacobian_add(ec_curve: &ECurve, a: &EcPointJ, b: &EcPointJ) -> EcPointJ {
+    if a.is_inf() {
+        return b.clone();
+    } else if b.is_inf() {
+        return a.clone();
+    } else if b.z.is_one() {
+        return jacobian_mixed_add(ec_curve, a, b);
+    }
+
+    let q = &ec_curve.q;
+    let z1z1 = (&a.z * &a.z) % q;
+    let z2z2 = (&b.z * &b.z) % q;
+    let u1 = (&a.x * &z2z2) % q;
+    let u2 = (&b.x * &z1z1) % q;
+    let s1 = (&a.y * &b.z * &z2z2) % q;
+    let s2 = (&b.y * &a.z * &z1z1) % q;
+    let h = take_by_bigint_module(&((&u2 - &u1) % q), q);
+    let r = take_by_bigint_module(&((BigInt::from(2_u8) * (&s2 - &s1)) % q), q);
+    if h.is_zero() {
+        return match r.is_zero() {
+            true => jacobian_double(ec_curve, a),
+            false => EcPointJ::neutral(),
+        };
+    }
+    let i = (BigInt::from(4_u8) * &h * &h) % q;
+    let j = (&h * &i) % q;
+    let v = (&u1 * &i) % q;
+    let x3 = (&r * &r - &j - BigInt::from(2_u8) * &v) % q;
+    let y3 = (&r * (&v - &x3) - BigInt::from(2_u8) * &s1 * &j) % q;
+    let z3 = (((&a.z + &b.z) * (&a.z + &b.z) - &z1z1 - &z2z2) * &h) % q;
+    EcPointJ {
+        x: take_by_bigint_module(&x3, q),
+        y: take_by_bigint_module(&y3, q),
+        z: take_by_bigint_module(&z3, q),
+    }
+}
+
+/// **jacobian_mixed_add** -- addition of Jacobian point a and affine point b (Z = 1), madd-2007-bl
+pub(crate) fn jacobian_mixed_add(ec_curve: &ECurve, a: &EcPointJ, b: &EcPointJ) -> EcPointJ {
+    if a.is_inf() {
+        return b.clone();
+    } else if b.is_inf() {
+        return a.clone();
+    }
+
+    let q = &ec_curve.q;
+    let z1z1 = (&a.z * &a.z) % q;
+    let u2 = (&b.x * &z1z1) % q;
+    let s2 = (&b.y * &a.z * &z1z1) % q;
+    let h = take_by_bigint_module(&((&u2 - &a.x) % q), q);
+    let r = take_by_bigint_module(&((BigInt::from(2_u8) * (&s2 - &a.y)) % q), q);
+    if h.is_zero() {
+        return match r.is_zero() {
+            true => jacobian_double(ec_curve, a),
+            false => EcPointJ::neutral(),
+        };
+    }
+    let hh = (&h * &h) % q;
+    let i = (BigInt::from(4_u8) * &hh) % q;
+    let j = (&h * &i) % q;
+    let v = (&a.x * &i) % q;
+    let x3 = (&r * &r - &j - BigInt::from(2_u8) * &v) % q;
+    let y3 = (&r * (&v - &x3) - BigInt::from(2_u8) * &a.y * &j) % q;
+    let z3 = ((&a.z + &h) * (&a.z + &h) - &z1z1 - &hh) % q;
+    EcPointJ {
+        x: take_by_bigint_module(&x3, q),
+        y: take_by_bigint_module(&y3, q),
+        z: take_by_bigint_module(&z3, q),
+    }
+}
+
+pub(crate) fn jacobian_mul(ec_curve: &ECurve, a: &EcPointJ, k: &BigUint) -> EcPointJ {
+    let a = EcPointJ {
+        x: take_by_bigint_module(&(&a.x % &ec_curve.q), &ec_curve.q),
+        y: take_by_bigint_module(&(&a.y % &ec_curve.q), &ec_curve.q),
+        z: take_by_bigint_module(&(&a.z % &ec_curve.q), &ec_curve.q),
+    };
+    let mut r = EcPointJ::neutral();
+
+    // from MSB to LSB, so that a is always added to accumulator and mixed addition could be used
+    for i in (0..k.bits()).rev() {
+        r = jacobian_double(ec_curve, &r);
+        if k.bit(i) {
+            r = jacobian_add(ec_curve, &r, &a)
+        }
+    }
+    r
+}
+
+/// **complete_add** -- complete addition in projective coordinates (Renes–Costello–Batina 2016,
+/// algorithm 1 for arbitrary a and algorithm 4 for a = -3). It has no exceptional cases:
+/// P + P, P + (-P) and P + O are computed by the same sequence of operations,
+/// but it is valid only for curves of odd order (without points of order 2).
+/// Neutral element is returned as (0 : Y : 0) for some Y != 0.
+/// https://eprint.iacr.org/2015/1060.pdf
+pub(crate) fn complete_add(ec_curve: &ECurve, a: &EcPointP, b: &EcPointP) -> EcPointP {
+    let q = &ec_curve.q;
+    let t0 = (&a.x * &b.x) % q;
+    let t1 = (&a.y * &b.y) % q;
+    let t2 = (&a.z * &b.z) % q;
+    // X1Y2 + X2Y1, X1Z2 + X2Z1, Y1Z2 + Y2Z1 with one multiplication each
+    let t3 = ((&a.x + &a.y) * (&b.x + &b.y) - &t0 - &t1) % q;
+    let t4 = ((&a.x + &a.z) * (&b.x + &b.z) - &t0 - &t2) % q;
+    let t5 = ((&a.y + &a.z) * (&b.y + &b.z) - &t1 - &t2) % q;
+    let (x3, y3, z3) = if ec_curve.is_a_minus_3() {
+        let s = (BigInt::from(3_u8) * (&t4 - &ec_curve.b * &t2)) % q;
+        let (m, p) = ((&t1 - &s) % q, (&t1 + &s) % q);
+        let u = (BigInt::from(3_u8) * (&ec_curve.b * &t4 - BigInt::from(3_u8) * &t2 - &t0)) % q;
+        let v = (BigInt::from(3_u8) * (&t0 - &t2)) % q;
+        let x3 = (&t3 * &p - &t5 * &u) % q;
+        let y3 = (&p * &m + &v * &u) % q;
+        let z3 = (&t5 * &m + &t3 * &v) % q;
+        (x3, y3, z3)
+    } else {
+        let b3 = (BigInt::from(3_u8) * &ec_curve.b) % q;
+        let s = (&ec_curve.a * &t4 + &b3 * &t2) % q;
+        let (m, p) = ((&t1 - &s) % q, (&t1 + &s) % q);
+        let u = (BigInt::from(3_u8) * &t0 + &ec_curve.a * &t2) % q;
+        let v = (&b3 * &t4 + &ec_curve.a * (&t0 - &ec_curve.a * &t2)) % q;
+        let x3 = (&t3 * &m - &t5 * &v) % q;
+        let y3 = (&m * &p + &u * &v) % q;
+        let z3 = (&t5 * &p + &t3 * &u) % q;
+        (x3, y3, z3)
+    };
+    EcPointP {
+        x: take_by_bigint_module(&x3, q),
+        y: take_by_bigint_module(&y3, q),
+        z: take_by_bigint_module(&z3, q),
+    }
+}
+
+/// **complete_double** -- exception-free doubling in projective coordinates
+/// (Renes–Costello–Batina 2016, algorithm 3 for arbitrary a and algorithm 6 for a = -3)
+pub(crate) fn complete_double(ec_curve: &ECurve, a: &EcPointP) -> EcPointP {
+    let q = &ec_curve.q;
+    let t0 = (&a.x * &a.x) % q;
+    let t1 = (&a.y * &a.y) % q;
+    let t2 = (&a.z * &a.z) % q;
+    let t3 = (BigInt::from(2_u8) * &a.x * &a.y) % q;
+    let xz = (BigInt::from(2_u8) * &a.x * &a.z) % q;
+    let yz = (BigInt::from(2_u8) * &a.y * &a.z) % q;
+    let (x3, y3, z3) = if ec_curve.is_a_minus_3() {
+        let s = (BigInt::from(3_u8) * (&ec_curve.b * &t2 - &xz)) % q;
+        let (m, p) = ((&t1 - &s) % q, (&t1 + &s) % q);
+        let u = (BigInt::from(3_u8) * (&ec_curve.b * &xz - BigInt::from(3_u8) * &t2 - &t0)) % q;
+        let v = (BigInt::from(3_u8) * (&t0 - &t2)) % q;
+        let x3 = (&m * &t3 - &yz * &u) % q;
+        let y3 = (&m * &p + &v * &u) % q;
+        let z3 = (BigInt::from(4_u8) * &yz * &t1) % q;
+        (x3, y3, z3)
+    } else {
+        let b3 = (BigInt::from(3_u8) * &ec_curve.b) % q;
+        let s = (&ec_curve.a * &xz + &b3 * &t2) % q;
+        let (m, p) = ((&t1 - &s) % q, (&t1 + &s) % q);
+        let u = (&b3 * &xz + &ec_curve.a * (&t0 - &ec_curve.a * &t2)) % q;
+        let v = (BigInt::from(3_u8) * &t0 + &ec_curve.a * &t2) % q;
+        let x3 = (&m * &t3 - &yz * &u) % q;
+        let y3 = (&m * &p + &v * &u) % q;
+        let z3 = (BigInt::from(4_u8) * &yz * &t1) % q;
+        (x3, y3, z3)
+    };
+    EcPointP {
+        x: take_by_bigint_module(&x3, q),
+        y: take_by_bigint_module(&y3, q),
+        z: take_by_bigint_module(&z3, q),
+    }
+}
+
+pub(crate) fn complete_mul(ec_curve: &ECurve, a: &EcPointP, k: &BigUint) -> EcPointP {
+    let mut r = EcPointP::neutral();
+
+    // from MSB to LSB
+    for i in (0..k.bits()).rev() {
+        r = complete_double(ec_curve, &r);
+        if k.bit(i) {
+            r = complete_add(ec_curve, &r, a)
+        }
+    }
+    r
+}
+
+/// **canonical_neutral** -- complete formulas return neutral element as (0 : Y : 0),
+/// so it's replaced with (0 : 1 : 0), that is expected by `EcPointP::is_inf`
+pub(crate) fn canonical_neutral(p: EcPointP) -> EcPointP {
+    match p.z.is_zero() {
+        true => EcPointP::neutral(),
+        false => p,
+    }
+}
+
+// (X, Y, Z) with coordinates in Montgomery form, meaning of coordinates depends on formulas
+pub(crate) type FieldPoint<'a, const N: usize> = (
+    FieldElement<'a, N>,
+    FieldElement<'a, N>,
+    FieldElement<'a, N>,
+);
+
+// calls f::<N>(...) with the least N, such that modulus fits into N limbs, or returns None
+macro_rules! with_limbs {
+    ($q:expr, $f:ident($($arg:expr),*)) => {
+        match $q.bits().div_ceil(64) {
+            1 => $f::<1>($($arg),*),
+            2 => $f::<2>($($arg),*),
+            3 => $f::<3>($($arg),*),
+            4 => $f::<4>($($arg),*),
+            5 => $f::<5>($($arg),*),
+            6 => $f::<6>($($arg),*),
+            7 => $f::<7>($($arg),*),
+            8 => $f::<8>($($arg),*),
+            9 => $f::<9>($($arg),*),
+            _ => None,
+        }
+    };
+}
+pub(crate) use with_limbs;
+
+/// **fixed_width_mul** -- scalar multiplication with fixed-width field elements instead of BigInt,
+/// Jacobian formulas are used for `CoordinateSystem::Projective` and `CoordinateSystem::Jacobian`
+/// and complete ones for `CoordinateSystem::Complete`.
+/// Returns None if modulus doesn't fit into 9 limbs (576 bits)
+pub(crate) fn fixed_width_mul(ec_curve: &ECurve, a: &EcPointP, k: &BigUint) -> Option<EcPointP> {
+    with_limbs!(ec_curve.q, field_mul(ec_curve, a, k))
+}
+
+fn field_mul<const N: usize>(ec_curve: &ECurve, a: &EcPointP, k: &BigUint) -> Option<EcPointP> {
+    let field = PrimeField::<N>::new(&ec_curve.q).ok()?;
+    Some(field_point_mul(&field, ec_curve, a, k))
+}
+
+fn field_point_mul<'f, const N: usize>(
+    field: &'f PrimeField<N>,
+    ec_curve: &ECurve,
+    a: &EcPointP,
+    k: &BigUint,
+) -> EcPointP {
+    if a.is_inf() {
+        return EcPointP::neutral();
+    }
+    let coef_a = field.element(&ec_curve.a);
+    let coef_b = field.element(&ec_curve.b);
+    let a_minus_3 = ec_curve.is_a_minus_3();
+    let (x, y, z) = (
+        field.element(&a.x),
+        field.element(&a.y),
+        field.element(&a.z),
+    );
+    let neutral = (field.zero(), field.one(), field.zero());
+
+    let digits = scalar_digits(k, ec_curve.wnaf);
+    let (x, y, z) = match ec_curve.get_coordinates() {
+        CoordinateSystem::Complete => {
+            let add = |a: &FieldPoint<'f, N>, b: &FieldPoint<'f, N>| {
+                field_complete_add(coef_a, coef_b, a_minus_3, a, b)
+            };
+            let table = odd_multiples((x, y, z), ec_curve.wnaf, add, |a| add(a, a));
+            mul_by_digits(
+                &digits,
+                &table,
+                neutral,
+                add,
+                |a| add(a, a),
+                |a| (a.0, -a.1, a.2),
+            )
+        }
+        CoordinateSystem::Projective | CoordinateSystem::Jacobian => {
+            // (X : Y : Z) => (XZ : YZ^2 : Z) and back (XZ : Y : Z^3)
+            let p = match z == field.one() {
+                true => (x, y, z),
+                false => (x * z, y * z.square(), z),
+            };
+            let add = |a: &FieldPoint<'f, N>, b: &FieldPoint<'f, N>| {
+                field_jacobian_add(coef_a, a_minus_3, a, b)
+            };
+            let double = |a: &FieldPoint<'f, N>| field_jacobian_double(coef_a, a_minus_3, a);
+            let table = odd_multiples(p, ec_curve.wnaf, add, double);
+            let r = mul_by_digits(&digits, &table, neutral, add, double, |a| (a.0, -a.1, a.2));
+            (r.0 * r.2, r.1, r.2.square() * r.2)
+        }
+    };
+    if z.is_zero() {
+        return EcPointP::neutral();
+    }
+    EcPointP {
+        x: x.to_bigint(),
+        y: y.to_bigint(),
+        z: z.to_bigint(),
+    }
+}
+
+// the same formulas as in `jacobian_double`, point with Z = 0 or Y = 0 is mapped to Z = 0
+pub(crate) fn field_jacobian_double<'a, const N: usize>(
+    coef_a: FieldElement<'a, N>,
+    a_minus_3: bool,
+    (x, y, z): &FieldPoint<'a, N>,
+) -> FieldPoint<'a, N> {
+    let (x, y, z) = (*x, *y, *z);
+    let gamma = y.square();
+    let delta = z.square();
+    let z3 = (y + z).square() - gamma - delta;
+    if a_minus_3 {
+        let beta = x * gamma;
+        let alpha = (x - delta) * (x + delta);
+        let alpha = alpha + alpha + alpha;
+        let beta4 = beta + beta + beta + beta;
+        let x3 = alpha.square() - beta4 - beta4;
+        let gamma2 = gamma.square();
+        let gamma8 = gamma2 + gamma2;
+        let gamma8 = gamma8 + gamma8;
+        let y3 = alpha * (beta4 - x3) - gamma8 - gamma8;
+        (x3, y3, z3)
+    } else {
+        let xx = x.square();
+        let yyyy = gamma.square();
+        let s = (x + gamma).square() - xx - yyyy;
+        let s = s + s;
+        let m = xx + xx + xx + coef_a * delta.square();
+        let x3 = m.square() - s - s;
+        let yyyy8 = yyyy + yyyy;
+        let yyyy8 = yyyy8 + yyyy8;
+        let y3 = m * (s - x3) - yyyy8 - yyyy8;
+        (x3, y3, z3)
+    }
+}
+
+// the same formulas as in `jacobian_add`, if b has Z = 1, then mixed addition is used
+pub(crate) fn field_jacobian_add<'a, const N: usize>(
+    coef_a: FieldElement<'a, N>,
+    a_minus_3: bool,
+    a: &FieldPoint<'a, N>,
+    b: &FieldPoint<'a, N>,
+) -> FieldPoint<'a, N> {
+    if a.2.is_zero() {
+        return *b;
+    } else if b.2.is_zero() {
+        return *a;
+    }
+    let one = a.2.field().one();
+    let z1z1 = a.2.square();
+    let (u1, s1, z2z2) = match b.2 == one {
+        true => (a.0, a.1, one),
+        false => {
+            let z2z2 = b.2.square();
+            (a.0 * z2z2, a.1 * b.2 * z2z2, z2z2)
+        }
+    };
+    let u2 = b.0 * z1z1;
+    let s2 = b.1 * a.2 * z1z1;
+    let h = u2 - u1;
+    let r = s2 - s1;
+    let r = r + r;
+    if h.is_zero() {
+        return match r.is_zero() {
+            true => field_jacobian_double(coef_a, a_minus_3, a),
+            false => (one, one, a.2.field().zero()),
+        };
+    }
+    let h2 = h + h;
+    let i = h2.square();
+    let j = h * i;
+    let v = u1 * i;
+    let x3 = r.square() - j - v - v;
+    let s1j = s1 * j;
+    let y3 = r * (v - x3) - s1j - s1j;
+    let z3 = ((a.2 + b.2).square() - z1z1 - z2z2) * h;
+    (x3, y3, z3)
+}
+
+// the same formulas as in `complete_add`, can be used for doubling
+pub(crate) fn field_complete_add<'a, const N: usize>(
+    coef_a: FieldElement<'a, N>,
+    coef_b: FieldElement<'a, N>,
+    a_minus_3: bool,
+    a: &FieldPoint<'a, N>,
+    b: &FieldPoint<'a, N>,
+) -> FieldPoint<'a, N> {
+    let t0 = a.0 * b.0;
+    let t1 = a.1 * b.1;
+    let t2 = a.2 * b.2;
+    let t3 = (a.0 + a.1) * (b.0 + b.1) - t0 - t1;
+    let t4 = (a.0 + a.2) * (b.0 + b.2) - t0 - t2;
+    let t5 = (a.1 + a.2) * (b.1 + b.2) - t1 - t2;
+    let triple = |x: FieldElement<'a, N>| x + x + x;
+    if a_minus_3 {
+        let s = triple(t4 - coef_b * t2);
+        let (m, p) = (t1 - s, t1 + s);
+        let u = triple(coef_b * t4 - triple(t2) - t0);
+        let v = triple(t0 - t2);
+        (t3 * p - t5 * u, p * m + v * u, t5 * m + t3 * v)
+    } else {
+        let b3 = triple(coef_b);
+        let s = coef_a * t4 + b3 * t2;
+        let (m, p) = (t1 - s, t1 + s);
+        let u = triple(t0) + coef_a * t2;
+        let v = b3 * t4 + coef_a * (t0 - coef_a * t2);
+        (t3 * m - t5 * v, m * p + u * v, t5 * p + t3 * u)
+    }
+}
+
+/// **ladder_mul** -- Montgomery ladder with complete formulas: every step does one addition
+/// and one doubling, points are exchanged with conditional swaps. k is reduced modulo `order`
+/// (a multiple of the order of a, e.g. n * h) and the number of steps is always equal
+/// to the bit length of `order`, so timing doesn't depend on k.
+/// Complete formulas fail only if a has order 2
+pub(crate) fn ladder_mul(
+    ec_curve: &ECurve,
+    a: &EcPointP,
+    k: &BigUint,
+    order: &BigUint,
+) -> EcPointP {
+    let bits = order.bits();
+    // k as little-endian limbs padded to the number of steps
+    let mut k_limbs = (k % order).to_u64_digits();
+    k_limbs.resize(bits.div_ceil(64) as usize + 1, 0);
+    let k_bit = |i: u64| (k_limbs[(i / 64) as usize] >> (i % 64)) & 1;
+
+    let r = with_limbs!(ec_curve.q, field_ladder_mul(ec_curve, a, &k_bit, bits));
+    canonical_neutral(r.unwrap_or_else(|| {
+        // BigInt fallback for large moduli, it has the same sequence of operations
+        // and swaps points with masks as well
+        let limbs = ec_curve.q.bits().div_ceil(64) as usize;
+        let (mut r0, mut r1, mut swap) = (EcPointP::neutral(), a.clone(), 0);
+        for i in (0..bits).rev() {
+            let bit = k_bit(i);
+            bigint_point_swap(ec_curve, &mut r0, &mut r1, swap ^ bit, limbs);
+            swap = bit;
+            r1 = complete_add(ec_curve, &r0, &r1);
+            r0 = complete_double(ec_curve, &r0);
+        }
+        bigint_point_swap(ec_curve, &mut r0, &mut r1, swap, limbs);
+        r0
+    }))
+}
+
+// conditional swap of coordinates as `limbs` 64-bit words, the same as `field_point_swap`
+fn bigint_point_swap(
+    ec_curve: &ECurve,
+    a: &mut EcPointP,
+    b: &mut EcPointP,
+    choice: u64,
+    limbs: usize,
+) {
+    let q = &ec_curve.q;
+    bigint_cswap(q, &mut a.x, &mut b.x, choice, limbs);
+    bigint_cswap(q, &mut a.y, &mut b.y, choice, limbs);
+    bigint_cswap(q, &mut a.z, &mut b.z, choice, limbs);
+}
+
+/// **bigint_cswap** -- swaps x and y (reduced modulo q) if choice = 1 with masked XOR
+/// of `limbs` 64-bit words, so the sequence of operations doesn't depend on choice
+pub(crate) fn bigint_cswap(q: &BigInt, x: &mut BigInt, y: &mut BigInt, choice: u64, limbs: usize) {
+    let mask = 0_u64.wrapping_sub(choice & 1);
+    let to_limbs = |x: &BigInt| {
+        let mut r = (((x % q) + q) % q).magnitude().to_u64_digits();
+        r.resize(limbs, 0);
+        r
+    };
+    let from_limbs = |x: &[u64]| {
+        let bytes: Vec<u8> = x.iter().flat_map(|limb| limb.to_le_bytes()).collect();
+        BigInt::from(BigUint::from_bytes_le(&bytes))
+    };
+    let (mut x_limbs, mut y_limbs) = (to_limbs(x), to_limbs(y));
+    for i in 0..limbs {
+        let t = mask & (x_limbs[i] ^ y_limbs[i]);
+        x_limbs[i] ^= t;
+        y_limbs[i] ^= t;
+    }
+    (*x, *y) = (from_limbs(&x_limbs), from_limbs(&y_limbs));
+}
+
+fn field_ladder_mul<const N: usize>(
+    ec_curve: &ECurve,
+    a: &EcPointP,
+    k_bit: &dyn Fn(u64) -> u64,
+    bits: u64,
+) -> Option<EcPointP> {
+    let field = PrimeField::<N>::new(&ec_curve.q).ok()?;
+    let coef_a = field.element(&ec_curve.a);
+    let coef_b = field.element(&ec_curve.b);
+    let a_minus_3 = ec_curve.is_a_minus_3();
+
+    let mut r0 = (field.zero(), field.one(), field.zero());
+    let mut r1 = (
+        field.element(&a.x),
+        field.element(&a.y),
+        field.element(&a.z),
+    );
+    let mut swap = 0;
+    // invariant: r1 - r0 = a
+    for i in (0..bits).rev() {
+        let bit = k_bit(i);
+        field_point_swap(&mut r0, &mut r1, swap ^ bit);
+        swap = bit;
+        r1 = field_complete_add(coef_a, coef_b, a_minus_3, &r0, &r1);
+        r0 = field_complete_add(coef_a, coef_b, a_minus_3, &r0, &r0);
+    }
+    field_point_swap(&mut r0, &mut r1, swap);
+
+    Some(EcPointP {
+        x: r0.0.to_bigint(),
+        y: r0.1.to_bigint(),
+        z: r0.2.to_bigint(),
+    })
+}
+
+fn field_point_swap<'a, const N: usize>(
+    a: &mut FieldPoint<'a, N>,
+    b: &mut FieldPoint<'a, N>,
+    choice: u64,
+) {
+    FieldElement::conditional_swap(&mut a.0, &mut b.0, choice);
+    FieldElement::conditional_swap(&mut a.1, &mut b.1, choice);
+    FieldElement::conditional_swap(&mut a.2, &mut b.2, choice);
+}
+
+/// **to_wnaf** -- width-w non-adjacent form of k (digits from LSB to MSB): k = sum(d_i * 2^i),
+/// every nonzero digit is odd, |d_i| < 2^(w-1) and among any w consecutive digits
+/// at most one is nonzero. Width has to be in [2, 8]
+pub fn to_wnaf(k: &BigUint, w: u8) -> Vec<i8> {
+    assert!(
+        (2..=8).contains(&w),
+        "wNAF width has to be in [2, 8], got {w}"
+    );
+    let (modulus, half) = (1_i16 << w, 1_i16 << (w - 1));
+    let mut k = k.clone();
+    let mut digits = Vec::with_capacity(k.bits() as usize + 1);
+    while !k.is_zero() {
+        let d = match k.bit(0) {
+            true => {
+                // k mods 2^w, i.e. residue in (-2^(w-1), 2^(w-1))
+                let m = (k.iter_u64_digits().next().unwrap_or(0) & (modulus as u64 - 1)) as i16;
+                let d = if m >= half { m - modulus } else { m };
+                match d > 0 {
+                    true => k -= BigUint::from(d as u16),
+                    false => k += BigUint::from((-d) as u16),
+                }
+                d as i8
+            }
+            false => 0,
+        };
+        digits.push(d);
+        k >>= 1;
+    }
+    digits
+}
+
+// wNAF digits if width is set, otherwise binary ones
+fn scalar_digits(k: &BigUint, wnaf: Option<u8>) -> Vec<i8> {
+    match wnaf {
+        Some(w) => to_wnaf(k, w),
+        None => (0..k.bits()).map(|i| k.bit(i) as i8).collect(),
+    }
+}
+
+// P, 3P, 5P, ..., (2^(w-1) - 1)P, or only P without wNAF
+pub(crate) fn odd_multiples<P: Clone>(
+    p: P,
+    wnaf: Option<u8>,
+    add: impl Fn(&P, &P) -> P,
+    double: impl Fn(&P) -> P,
+) -> Vec<P> {
+    let size = wnaf.map_or(1, |w| 1_usize << (w - 2));
+    let mut table = vec![p];
+    if size > 1 {
+        let p2 = double(&table[0]);
+        for i in 1..size {
+            table.push(add(&table[i - 1], &p2));
+        }
+    }
+    table
+}
+
+// left-to-right multiplication by signed digits (from LSB to MSB),
+// d is odd and table[|d| / 2] = |d| * P for nonzero digits
+fn mul_by_digits<P: Clone>(
+    digits: &[i8],
+    table: &[P],
+    neutral: P,
+    add: impl Fn(&P, &P) -> P,
+    double: impl Fn(&P) -> P,
+    negate: impl Fn(&P) -> P,
+) -> P {
+    let mut r = neutral;
+    for d in digits.iter().rev() {
+        r = double(&r);
+        match d.signum() {
+            1 => r = add(&r, &table[(d / 2) as usize]),
+            -1 => r = add(&r, &negate(&table[(-d / 2) as usize])),
+            _ => {}
+        }
+    }
+    r
+}
+
+/// **wnaf_mul** -- scalar multiplication with width-w NAF and table of odd multiples,
+/// BigInt arithmetic in the coordinate system of the curve
+pub(crate) fn wnaf_mul(ec_curve: &ECurve, a: &EcPointP, k: &BigUint, w: u8) -> EcPointP {
+    let digits = to_wnaf(k, w);
+    match ec_curve.get_coordinates() {
+        CoordinateSystem::Projective => {
+            let add = |a: &EcPointP, b: &EcPointP| projective_add(ec_curve, a, b);
+            let double = |a: &EcPointP| projective_double(ec_curve, a);
+            let table = odd_multiples(ec_curve.take_by_module(a), Some(w), add, double);
+            mul_by_digits(&digits, &table, EcPointP::neutral(), add, double, |a| {
+                ec_curve.take_by_module(&a.negative())
+            })
+        }
+        CoordinateSystem::Jacobian => {
+            let add = |a: &EcPointJ, b: &EcPointJ| jacobian_add(ec_curve, a, b);
+            let double = |a: &EcPointJ| jacobian_double(ec_curve, a);
+            let p = EcPointJ::from_projective(&ec_curve.take_by_module(a));
+            let p = EcPointJ {
+                x: take_by_bigint_module(&(&p.x % &ec_curve.q), &ec_curve.q),
+                y: take_by_bigint_module(&(&p.y % &ec_curve.q), &ec_curve.q),
+                z: p.z,
+            };
+            let table = odd_multiples(p, Some(w), add, double);
+            mul_by_digits(&digits, &table, EcPointJ::neutral(), add, double, |a| {
+                EcPointJ {
+                    x: a.x.clone(),
+                    y: take_by_bigint_module(&-&a.y, &ec_curve.q),
+                    z: a.z.clone(),
+                }
+            })
+            .to_projective(ec_curve)
+        }
+        CoordinateSystem::Complete => {
+            let add = |a: &EcPointP, b: &EcPointP| complete_add(ec_curve, a, b);
+            let double = |a: &EcPointP| complete_double(ec_curve, a);
+            let table = odd_multiples(a.clone(), Some(w), add, double);
+            canonical_neutral(mul_by_digits(
+                &digits,
+                &table,
+                EcPointP::neutral(),
+                add,
+                double,
+                |a| a.negative(),
+            ))
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use crate::affine_point::EcPointA;
+use crate::helpers::{inverse, take_by_bigint_module};
+use crate::projective_point::EcPointP;
+use crate::{ECurve, EcInfo, Params};
+use num_bigint::BigInt;
+
+/// **EcIsomorphism** -- isomorphism between two EC in Weierstrass form
+/// ψ: E(a, b) -> E'(u^4 * a, u^6 * b), (x, y) -> (u^2 * x, u^3 * y)
+/// or in projective coordinates (X : Y : Z) -> (u^2 * X : u^3 * Y : Z)
+#[derive(PartialOrd, PartialEq, Clone, Debug)]
+pub struct EcIsomorphism {
+    u: BigInt,
+    from: ECurve,
+    to: ECurve,
+}
+
+impl EcIsomorphism {
+    pub fn new(from: &ECurve, u: &BigInt) -> crate::Result<Self> {
+        let q = &from.q;
+        let u = take_by_bigint_module(&(u % q), q);
+        // u has to be invertible, otherwise map isn't bijective
+        inverse(&u, q)?;
+        let to = ECurve::new(Params {
+            a: take_by_bigint_module(&((&from.a * u.modpow(&BigInt::from(4_u8), q)) % q), q),
+            b: take_by_bigint_module(&((&from.b * u.modpow(&BigInt::from(6_u8), q)) % q), q),
+            q: q.clone(),
+        })?;
+        Ok(EcIsomorphism {
+            u,
+            from: from.clone(),
+            to,
+        })
+    }
+
+    /// **inverse** -- returns ψ^{-1}: E' -> E, i.e. isomorphism with u^{-1}
+    pub fn inverse(&self) -> crate::Result<Self> {
+        Ok(EcIsomorphism {
+            u: inverse(&self.u, &self.from.q)?,
+            from: self.to.clone(),
+            to: self.from.clone(),
+        })
+    }
+
+    pub fn get_u(&self) -> BigInt {
+        self.u.clone()
+    }
+
+    /// **source** -- curve E, points of which are mapped
+    pub fn source(&self) -> &ECurve {
+        &self.from
+    }
+
+    /// **target** -- curve E', into which points are mapped
+    pub fn target(&self) -> &ECurve {
+        &self.to
+    }
+
+    pub fn map_affine(&self, p: &EcPointA) -> EcPointA {
+        if p.is_inf() {
+            return p.clone();
+        }
+        let q = &self.from.q;
+        let u2 = (&self.u * &self.u) % q;
+        EcPointA {
+            x: take_by_bigint_module(&((&p.x * &u2) % q), q),
+            y: take_by_bigint_module(&((&p.y * &u2 * &self.u) % q), q),
+            is_inf: false,
+        }
+    }
+
+    pub fn map_proj(&self, p: &EcPointP) -> EcPointP {
+        if p.is_inf() {
+            return EcPointP::neutral();
+        }
+        let q = &self.from.q;
+        let u2 = (&self.u * &self.u) % q;
+        EcPointP {
+            x: take_by_bigint_module(&((&p.x * &u2) % q), q),
+            y: take_by_bigint_module(&((&p.y * &u2 * &self.u) % q), q),
+            z: take_by_bigint_module(&(&p.z % q), q),
+        }
+    }
+
+    /// **map_ec_info** -- moves EC domain parameters to the target curve,
+    /// order and cofactor are preserved by isomorphism
+    pub fn map_ec_info(&self, ec_info: &EcInfo) -> crate::Result<EcInfo> {
+        EcInfo::new(
+            self.map_proj(&ec_info.bp),
+            ec_info.n.clone(),
+            ec_info.h.clone(),
+            self.to.clone(),
+        )
+    }
+}
@@ -0,0 +1,103 @@
+use crate::affine_point::EcPointA;
+use crate::helpers::{affine_to_jacobian, jacobian_to_affine, take_by_bigint_module};
+use crate::projective_point::EcPointP;
+use crate::ECurve;
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use std::fmt::{Debug, Display, Formatter, LowerHex, UpperHex};
+
+/// **EcPointJ** -- point on EC in Jacobian coordinates (X : Y : Z),
+/// where x = X/Z^2, y = Y/Z^3, so EC equation looks like Y^2 = X^3 + aXZ^4 + bZ^6
+#[derive(Clone, Eq, PartialEq, PartialOrd)]
+pub struct EcPointJ {
+    pub x: BigInt,
+    pub y: BigInt,
+    pub z: BigInt,
+}
+
+impl EcPointJ {
+    pub fn new(x: &BigInt, y: &BigInt, z: &BigInt) -> Self {
+        EcPointJ {
+            x: x.clone(),
+            y: y.clone(),
+            z: z.clone(),
+        }
+    }
+
+    pub fn from_affine(a: &EcPointA) -> Self {
+        affine_to_jacobian(a)
+    }
+
+    pub fn to_affine(&self, ec_curve: &ECurve) -> crate::Result<EcPointA> {
+        jacobian_to_affine(ec_curve, self)
+    }
+
+    /// **from_projective** -- (X : Y : Z) => (XZ : YZ^2 : Z), no inversion is needed
+    pub fn from_projective(p: &EcPointP) -> Self {
+        if p.is_inf() {
+            return EcPointJ::neutral();
+        }
+        EcPointJ {
+            x: &p.x * &p.z,
+            y: &p.y * &p.z * &p.z,
+            z: p.z.clone(),
+        }
+    }
+
+    /// **to_projective** -- (X : Y : Z) => (XZ : Y : Z^3), no inversion is needed
+    pub fn to_projective(&self, ec_curve: &ECurve) -> EcPointP {
+        if self.is_inf() {
+            return EcPointP::neutral();
+        }
+        let q = &ec_curve.q;
+        EcPointP {
+            x: take_by_bigint_module(&((&self.x * &self.z) % q), q),
+            y: take_by_bigint_module(&(&self.y % q), q),
+            z: take_by_bigint_module(&((&self.z * &self.z * &self.z) % q), q),
+        }
+    }
+
+    pub fn is_inf(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    pub fn neutral() -> EcPointJ {
+        EcPointJ {
+            x: BigInt::one(),
+            y: BigInt::one(),
+            z: Default::default(),
+        }
+    }
+
+    pub fn negative(&self) -> EcPointJ {
+        EcPointJ {
+            x: self.x.clone(),
+            y: -self.y.clone(),
+            z: self.z.clone(),
+        }
+    }
+}
+
+impl UpperHex for EcPointJ {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "x: {:X}, y: {:X} ,z: {:X}", self.x, self.y, self.z)
+    }
+}
+
+impl LowerHex for EcPointJ {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "x: {:x}, y: {:x} ,z: {:x}", self.x, self.y, self.z)
+    }
+}
+
+impl Debug for EcPointJ {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "x: {}, y: {} ,z: {}", self.x, self.y, self.z)
+    }
+}
+
+impl Display for EcPointJ {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "x: {}, y: {} ,z: {}", self.x, self.y, self.z)
+    }
+}
@@ -0,0 +1,77 @@
+use crate::affine_point::EcPointA;
+use crate::binary_curve::BinaryCurve;
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+use std::fmt::{Debug, Display, Formatter, LowerHex, UpperHex};
+
+/// **EcPointLD** -- point on binary curve in Lopez-Dahab coordinates (X : Y : Z),
+/// where x = X/Z, y = Y/Z^2, so curve equation looks like Y^2 + XYZ = X^3Z + aX^2Z^2 + bZ^4
+#[derive(Clone, Eq, PartialEq, PartialOrd)]
+pub struct EcPointLD {
+    pub x: BigInt,
+    pub y: BigInt,
+    pub z: BigInt,
+}
+
+impl EcPointLD {
+    pub fn new(x: &BigInt, y: &BigInt, z: &BigInt) -> Self {
+        EcPointLD {
+            x: x.clone(),
+            y: y.clone(),
+            z: z.clone(),
+        }
+    }
+
+    /// **from_affine** -- (x, y) => (x : y : 1)
+    pub fn from_affine(a: &EcPointA) -> Self {
+        if a.is_inf() {
+            return EcPointLD::neutral();
+        }
+        EcPointLD {
+            x: a.get_x(),
+            y: a.get_y(),
+            z: BigInt::one(),
+        }
+    }
+
+    pub fn to_affine(&self, curve: &BinaryCurve) -> crate::Result<EcPointA> {
+        curve.ld_to_affine(self)
+    }
+
+    pub fn is_inf(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// **neutral** -- (1 : 0 : 0)
+    pub fn neutral() -> EcPointLD {
+        EcPointLD {
+            x: BigInt::one(),
+            y: BigInt::zero(),
+            z: BigInt::zero(),
+        }
+    }
+}
+
+impl UpperHex for EcPointLD {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "x: {:X}, y: {:X} ,z: {:X}", self.x, self.y, self.z)
+    }
+}
+
+impl LowerHex for EcPointLD {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "x: {:x}, y: {:x} ,z: {:x}", self.x, self.y, self.z)
+    }
+}
+
+impl Debug for EcPointLD {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "x: {:x}, y: {:x} ,z: {:x}", self.x, self.y, self.z)
+    }
+}
+
+impl Display for EcPointLD {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "x: {:x}, y: {:x} ,z: {:x}", self.x, self.y, self.z)
+    }
+}
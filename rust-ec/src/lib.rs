@@ -1,14 +1,55 @@
 pub mod affine_point;
+pub mod binary_curve;
+pub mod binary_field;
+pub mod curve_point;
+pub mod discrete_log;
+pub mod edwards;
+pub mod encoding;
+pub mod extension_field;
+pub mod factorization;
+pub mod field;
+pub mod fixed_base;
+pub mod generation;
+pub mod glv;
+pub mod hash_to_curve;
 pub mod helpers;
+pub mod isomorphism;
+pub mod jacobian_point;
+pub mod ld_point;
+pub mod modular_polynomial;
+pub mod montgomery;
+pub mod multi_scalar;
+pub mod pairing;
+pub mod point_counting;
+pub mod polynomial;
 pub mod projective_point;
+pub mod security;
+pub mod sqrt;
+pub mod subgroup;
+pub mod traits;
+pub mod twist_point;
 
 use crate::affine_point::EcPointA;
+use crate::discrete_log::DlpSolution;
+use crate::encoding::{decode_point, encode_point, PointFormat};
+use crate::field::BigPrimeField;
+use crate::fixed_base::FixedBaseCache;
+use crate::glv::{glv_mul, GlvEndomorphism};
 use crate::helpers::{
-    check_discriminant, inverse, projective_add, projective_mul, take_by_bigint_module,
+    batch_projective_to_affine, canonical_neutral, check_discriminant, complete_add,
+    complete_double, complete_mul, fixed_width_mul, inverse, jacobian_add, jacobian_double,
+    jacobian_mul, ladder_mul, projective_add, projective_mul, take_by_bigint_module, wnaf_mul,
 };
+use crate::isomorphism::EcIsomorphism;
+use crate::jacobian_point::EcPointJ;
+use crate::montgomery::{MontgomeryCurve, PreGeneratedMontgomeryParams};
+use crate::multi_scalar::multi_scalar_mul;
 use crate::projective_point::EcPointP;
+use crate::security::SecurityReport;
+use crate::sqrt::sqrt_mod;
+use crate::traits::{CurveModel, DomainParameters, Group, PointEncoding};
 use num_bigint::{BigInt, BigUint};
-use num_traits::{Num, One};
+use num_traits::{Num, One, Zero};
 use std::fmt::{Display, Formatter};
 
 /// **ECCurve** -- represents elliptic curve in Weierstrass form
@@ -20,6 +61,37 @@ pub struct ECurve {
     a: BigInt,
     b: BigInt,
     q: BigInt,
+    /// **endomorphism** -- if present, scalar multiplication is done with GLV method
+    endomorphism: Option<GlvEndomorphism>,
+    /// **coordinates** -- coordinate system used for scalar multiplication of projective points
+    coordinates: CoordinateSystem,
+    /// **arithmetic** -- representation of field elements used for scalar multiplication
+    arithmetic: FieldArithmetic,
+    /// **wnaf** -- if present, scalar multiplication uses width-w NAF recoding of the scalar
+    wnaf: Option<u8>,
+}
+
+/// **CoordinateSystem** -- point representation used by `ECurve::proj_point_mul` internally
+#[derive(PartialOrd, PartialEq, Clone, Copy, Debug, Default)]
+pub enum CoordinateSystem {
+    /// homogeneous projective coordinates (X/Z, Y/Z)
+    #[default]
+    Projective,
+    /// Jacobian coordinates (X/Z^2, Y/Z^3)
+    Jacobian,
+    /// homogeneous projective coordinates with complete (exception-free) formulas,
+    /// suitable only for curves of odd order, e.g. prime order curves
+    Complete,
+}
+
+/// **FieldArithmetic** -- representation of field elements used by `ECurve::proj_point_mul` internally
+#[derive(PartialOrd, PartialEq, Clone, Copy, Debug, Default)]
+pub enum FieldArithmetic {
+    /// heap allocated `BigInt`, reduced with `%` after every operation
+    #[default]
+    BigInt,
+    /// stack allocated limbs in Montgomery form (`field::FieldElement`), moduli up to 576 bits
+    FixedWidth,
 }
 
 #[derive(PartialOrd, PartialEq, Clone, Debug)]
@@ -27,7 +99,11 @@ pub struct EcInfo {
     pub bp: EcPointP,
     /// **n** -- order of EC
     pub n: BigUint,
+    /// **h** -- cofactor, i.e. #E(F_q) = n * h
+    pub h: BigUint,
     pub ecurve: ECurve,
+    /// **fixed_base** -- precomputed multiples of bp, built on the first `base_point_mul`
+    fixed_base: FixedBaseCache,
 }
 
 #[derive(Debug)]
@@ -43,6 +119,18 @@ pub enum PreGeneratedParams {
     P224,
     P256,
     P384,
+    P521,
+    Secp256k1,
+    BrainpoolP256r1,
+    BrainpoolP256t1,
+    BrainpoolP384r1,
+    BrainpoolP384t1,
+    BrainpoolP512r1,
+    BrainpoolP512t1,
+    /// Weierstrass model of Curve25519 (Wei25519)
+    Curve25519,
+    /// Weierstrass model of Curve448 (Wei448)
+    Curve448,
 }
 
 #[derive(Debug)]
@@ -50,6 +138,12 @@ pub enum EcError {
     IncorrectParameters(String),
     NonZeroDiscriminant(BigInt),
     ImpossibleToFindInverse(String),
+    IncorrectBasePoint(String),
+    IncorrectPointEncoding(String),
+    PointNotOnCurve(String),
+    InvalidPublicKey(String),
+    DiscreteLogNotFound(String),
+    CurveMismatch(String),
 }
 
 pub type Result<T> = core::result::Result<T, EcError>;
@@ -65,6 +159,13 @@ impl Display for EcError {
                     format!("Discriminant doesn't equal to zero [{x} != 0]"),
                 EcError::ImpossibleToFindInverse(msg) =>
                     format!("Impossible to find inverse for {msg}"),
+                EcError::IncorrectBasePoint(msg) => format!("Incorrect base point: {msg}"),
+                EcError::IncorrectPointEncoding(msg) => format!("Incorrect point encoding: {msg}"),
+                EcError::PointNotOnCurve(msg) => format!("Point doesn't lie on the curve: {msg}"),
+                EcError::InvalidPublicKey(msg) => format!("Invalid public key: {msg}"),
+                EcError::DiscreteLogNotFound(msg) =>
+                    format!("Discrete logarithm isn't found: {msg}"),
+                EcError::CurveMismatch(msg) => format!("Points belong to different curves: {msg}"),
             }
         )
     }
@@ -120,7 +221,7 @@ impl From<&PreGeneratedParams> for Params {
             PreGeneratedParams::P384 => Params {
                 a: BigInt::from(-3),
                 b: BigInt::from_str_radix(
-                    "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875a",
+                    "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef",
                     16,
                 )
                     .unwrap(),
@@ -130,6 +231,163 @@ impl From<&PreGeneratedParams> for Params {
                 )
                     .unwrap(),
             },
+            PreGeneratedParams::P521 => Params {
+                a: BigInt::from(-3),
+                b: BigInt::from_str_radix(
+                    "0051953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00",
+                    16,
+                )
+                    .unwrap(),
+                q: BigInt::from_str_radix(
+                    "6864797660130609714981900799081393217269435300143305409394463459185543183397656052122559640661454554977296311391480858037121987999716643812574028291115057151",
+                    10,
+                )
+                    .unwrap(),
+            },
+            PreGeneratedParams::Secp256k1 => Params {
+                a: BigInt::zero(),
+                b: BigInt::from(7),
+                q: BigInt::from_str_radix(
+                    "115792089237316195423570985008687907853269984665640564039457584007908834671663",
+                    10,
+                )
+                    .unwrap(),
+            },
+            PreGeneratedParams::BrainpoolP256r1 => Params {
+                a: BigInt::from_str_radix(
+                    "7d5a0975fc2c3057eef67530417affe7fb8055c126dc5c6ce94a4b44f330b5d9",
+                    16,
+                )
+                    .unwrap(),
+                b: BigInt::from_str_radix(
+                    "26dc5c6ce94a4b44f330b5d9bbd77cbf958416295cf7e1ce6bccdc18ff8c07b6",
+                    16,
+                )
+                    .unwrap(),
+                q: BigInt::from_str_radix(
+                    "a9fb57dba1eea9bc3e660a909d838d726e3bf623d52620282013481d1f6e5377",
+                    16,
+                )
+                    .unwrap(),
+            },
+            PreGeneratedParams::BrainpoolP256t1 => Params {
+                a: BigInt::from(-3),
+                b: BigInt::from_str_radix(
+                    "662c61c430d84ea4fe66a7733d0b76b7bf93ebc4af2f49256ae58101fee92b04",
+                    16,
+                )
+                    .unwrap(),
+                q: BigInt::from_str_radix(
+                    "a9fb57dba1eea9bc3e660a909d838d726e3bf623d52620282013481d1f6e5377",
+                    16,
+                )
+                    .unwrap(),
+            },
+            PreGeneratedParams::BrainpoolP384r1 => Params {
+                a: BigInt::from_str_radix(
+                    "7bc382c63d8c150c3c72080ace05afa0c2bea28e4fb22787139165efba91f90f8aa5814a503ad4eb04a8c7dd22ce2826",
+                    16,
+                )
+                    .unwrap(),
+                b: BigInt::from_str_radix(
+                    "04a8c7dd22ce28268b39b55416f0447c2fb77de107dcd2a62e880ea53eeb62d57cb4390295dbc9943ab78696fa504c11",
+                    16,
+                )
+                    .unwrap(),
+                q: BigInt::from_str_radix(
+                    "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b412b1da197fb71123acd3a729901d1a71874700133107ec53",
+                    16,
+                )
+                    .unwrap(),
+            },
+            PreGeneratedParams::BrainpoolP384t1 => Params {
+                a: BigInt::from(-3),
+                b: BigInt::from_str_radix(
+                    "7f519eada7bda81bd826dba647910f8c4b9346ed8ccdc64e4b1abd11756dce1d2074aa263b88805ced70355a33b471ee",
+                    16,
+                )
+                    .unwrap(),
+                q: BigInt::from_str_radix(
+                    "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b412b1da197fb71123acd3a729901d1a71874700133107ec53",
+                    16,
+                )
+                    .unwrap(),
+            },
+            PreGeneratedParams::BrainpoolP512r1 => Params {
+                a: BigInt::from_str_radix(
+                    "7830a3318b603b89e2327145ac234cc594cbdd8d3df91610a83441caea9863bc2ded5d5aa8253aa10a2ef1c98b9ac8b57f1117a72bf2c7b9e7c1ac4d77fc94ca",
+                    16,
+                )
+                    .unwrap(),
+                b: BigInt::from_str_radix(
+                    "3df91610a83441caea9863bc2ded5d5aa8253aa10a2ef1c98b9ac8b57f1117a72bf2c7b9e7c1ac4d77fc94cadc083e67984050b75ebae5dd2809bd638016f723",
+                    16,
+                )
+                    .unwrap(),
+                q: BigInt::from_str_radix(
+                    "aadd9db8dbe9c48b3fd4e6ae33c9fc07cb308db3b3c9d20ed6639cca703308717d4d9b009bc66842aecda12ae6a380e62881ff2f2d82c68528aa6056583a48f3",
+                    16,
+                )
+                    .unwrap(),
+            },
+            PreGeneratedParams::BrainpoolP512t1 => Params {
+                a: BigInt::from(-3),
+                b: BigInt::from_str_radix(
+                    "7cbbbcf9441cfab76e1890e46884eae321f70c0bcb4981527897504bec3e36a62bcdfa2304976540f6450085f2dae145c22553b465763689180ea2571867423e",
+                    16,
+                )
+                    .unwrap(),
+                q: BigInt::from_str_radix(
+                    "aadd9db8dbe9c48b3fd4e6ae33c9fc07cb308db3b3c9d20ed6639cca703308717d4d9b009bc66842aecda12ae6a380e62881ff2f2d82c68528aa6056583a48f3",
+                    16,
+                )
+                    .unwrap(),
+            },
+            PreGeneratedParams::Curve25519 | PreGeneratedParams::Curve448 => {
+                MontgomeryCurve::from(value.montgomery_form().unwrap()).weierstrass_params()
+            }
+        }
+    }
+}
+
+impl PreGeneratedParams {
+    /// **montgomery_form** -- for Weierstrass models of Montgomery curves returns corresponding
+    /// Montgomery curve, points can be moved between them with
+    /// `MontgomeryCurve::to_weierstrass_point` and `MontgomeryCurve::from_weierstrass_point`
+    pub fn montgomery_form(&self) -> Option<PreGeneratedMontgomeryParams> {
+        match self {
+            PreGeneratedParams::Curve25519 => Some(PreGeneratedMontgomeryParams::Curve25519),
+            PreGeneratedParams::Curve448 => Some(PreGeneratedMontgomeryParams::Curve448),
+            _ => None,
+        }
+    }
+
+    /// **twist_isomorphism** -- for Brainpool curves returns isomorphism between
+    /// rX curve and its twisted tX version with a = -3 (in both directions),
+    /// i.e. (x, y) -> (x * Z^2, y * Z^3) for rX -> tX (RFC 5639, section 3)
+    pub fn twist_isomorphism(&self) -> Option<EcIsomorphism> {
+        let (r1, z) = match self {
+            PreGeneratedParams::BrainpoolP256r1 | PreGeneratedParams::BrainpoolP256t1 => (
+                PreGeneratedParams::BrainpoolP256r1,
+                "3e2d4bd9597b58639ae7aa669cab9837cf5cf20a2c852d10f655668dfc150ef0",
+            ),
+            PreGeneratedParams::BrainpoolP384r1 | PreGeneratedParams::BrainpoolP384t1 => (
+                PreGeneratedParams::BrainpoolP384r1,
+                "41dfe8dd399331f7166a66076734a89cd0d2bcdb7d068e44e1f378f41ecbae97d2d63dbc87bccddccc5da39e8589291c",
+            ),
+            PreGeneratedParams::BrainpoolP512r1 | PreGeneratedParams::BrainpoolP512t1 => (
+                PreGeneratedParams::BrainpoolP512r1,
+                "12ee58e6764838b69782136f0f2d3ba06e27695716054092e60a80bedb212b64e585d90bce13761f85c3f1d2a64e3be8fea2220f01eba5eeb0f35dbd29d922ab",
+            ),
+            _ => return None,
+        };
+        let r1_to_t1 =
+            EcIsomorphism::new(&ECurve::new(Params::from(r1)).unwrap(), &parse_hex(z)).unwrap();
+        match self {
+            PreGeneratedParams::BrainpoolP256t1
+            | PreGeneratedParams::BrainpoolP384t1
+            | PreGeneratedParams::BrainpoolP512t1 => Some(r1_to_t1.inverse().unwrap()),
+            _ => Some(r1_to_t1),
         }
     }
 }
@@ -141,26 +399,205 @@ impl From<PreGeneratedParams> for EcInfo {
 }
 impl From<&PreGeneratedParams> for EcInfo {
     fn from(value: &PreGeneratedParams) -> Self {
-        match value {
-            PreGeneratedParams::P192 => EcInfo {
-                bp: EcPointP::from_affine(&EcPointA{
-                    x: BigInt::from_str_radix( "188da80eb03090f67cbf20eb43a18800f4ff0afd82ff1012",16).unwrap(),
-                    y: BigInt::from_str_radix( "07192b95ffc8da78631011ed6b24cdd573f977a11e794811",16).unwrap(),
-                    is_inf: false,
-                }),
-                n: BigUint::from_str_radix("6277101735386680763835789423176059013767194773182842284081", 10).unwrap(),
-                ecurve: ECurve::new(Params::from(value)).unwrap(),
-            },
-            PreGeneratedParams::P224 => {
-                todo!()
-            }
-            PreGeneratedParams::P256 => {
-                todo!()
-            }
-            PreGeneratedParams::P384 => {
-                todo!()
+        if let Some(montgomery) = value.montgomery_form() {
+            return EcInfo::from(montgomery);
+        }
+        let (x, y, n) = match value {
+            PreGeneratedParams::P192 => (
+                "188da80eb03090f67cbf20eb43a18800f4ff0afd82ff1012",
+                "07192b95ffc8da78631011ed6b24cdd573f977a11e794811",
+                "ffffffffffffffffffffffff99def836146bc9b1b4d22831",
+            ),
+            PreGeneratedParams::P224 => (
+                "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21",
+                "bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34",
+                "ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3d",
+            ),
+            PreGeneratedParams::P256 => (
+                "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+                "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+                "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+            ),
+            PreGeneratedParams::P384 => (
+                "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7",
+                "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f",
+                "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf581a0db248b0a77aecec196accc52973",
+            ),
+            PreGeneratedParams::P521 => (
+                "00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3dbaa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66",
+                "011839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e662c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650",
+                "01fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409",
+            ),
+            PreGeneratedParams::Secp256k1 => (
+                "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+                "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+                "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+            ),
+            // https://datatracker.ietf.org/doc/html/rfc5639#section-3.4
+            PreGeneratedParams::BrainpoolP256r1 => (
+                "8bd2aeb9cb7e57cb2c4b482ffc81b7afb9de27e1e3bd23c23a4453bd9ace3262",
+                "547ef835c3dac4fd97f8461a14611dc9c27745132ded8e545c1d54c72f046997",
+                "a9fb57dba1eea9bc3e660a909d838d718c397aa3b561a6f7901e0e82974856a7",
+            ),
+            PreGeneratedParams::BrainpoolP256t1 => (
+                "a3e8eb3cc1cfe7b7732213b23a656149afa142c47aafbc2b79a191562e1305f4",
+                "2d996c823439c56d7f7b22e14644417e69bcb6de39d027001dabe8f35b25c9be",
+                "a9fb57dba1eea9bc3e660a909d838d718c397aa3b561a6f7901e0e82974856a7",
+            ),
+            // https://datatracker.ietf.org/doc/html/rfc5639#section-3.6
+            PreGeneratedParams::BrainpoolP384r1 => (
+                "1d1c64f068cf45ffa2a63a81b7c13f6b8847a3e77ef14fe3db7fcafe0cbd10e8e826e03436d646aaef87b2e247d4af1e",
+                "8abe1d7520f9c2a45cb1eb8e95cfd55262b70b29feec5864e19c054ff99129280e4646217791811142820341263c5315",
+                "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b31f166e6cac0425a7cf3ab6af6b7fc3103b883202e9046565",
+            ),
+            PreGeneratedParams::BrainpoolP384t1 => (
+                "18de98b02db9a306f2afcd7235f72a819b80ab12ebd653172476fecd462aabffc4ff191b946a5f54d8d0aa2f418808cc",
+                "25ab056962d30651a114afd2755ad336747f93475b7a1fca3b88f2b6a208ccfe469408584dc2b2912675bf5b9e582928",
+                "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b31f166e6cac0425a7cf3ab6af6b7fc3103b883202e9046565",
+            ),
+            // https://datatracker.ietf.org/doc/html/rfc5639#section-3.7
+            PreGeneratedParams::BrainpoolP512r1 => (
+                "81aee4bdd82ed9645a21322e9c4c6a9385ed9f70b5d916c1b43b62eef4d0098eff3b1f78e2d0d48d50d1687b93b97d5f7c6d5047406a5e688b352209bcb9f822",
+                "7dde385d566332ecc0eabfa9cf7822fdf209f70024a57b1aa000c55b881f8111b2dcde494a5f485e5bca4bd88a2763aed1ca2b2fa8f0540678cd1e0f3ad80892",
+                "aadd9db8dbe9c48b3fd4e6ae33c9fc07cb308db3b3c9d20ed6639cca70330870553e5c414ca92619418661197fac10471db1d381085ddaddb58796829ca90069",
+            ),
+            PreGeneratedParams::BrainpoolP512t1 => (
+                "640ece5c12788717b9c1ba06cbc2a6feba85842458c56dde9db1758d39c0313d82ba51735cdb3ea499aa77a7d6943a64f7a3f25fe26f06b51baa2696fa9035da",
+                "5b534bd595f5af0fa2c892376c84ace1bb4e3019b71634c01131159cae03cee9d9932184beef216bd71df2dadf86a627306ecff96dbb8bace198b61e00f8b332",
+                "aadd9db8dbe9c48b3fd4e6ae33c9fc07cb308db3b3c9d20ed6639cca70330870553e5c414ca92619418661197fac10471db1d381085ddaddb58796829ca90069",
+            ),
+            PreGeneratedParams::Curve25519 | PreGeneratedParams::Curve448 => {
+                unreachable!("Weierstrass models of Montgomery curves are handled above")
             }
+        };
+        let ecurve = match value {
+            // https://bitcointalk.org/index.php?topic=3238.msg45565#msg45565
+            PreGeneratedParams::Secp256k1 => ECurve::new(Params::from(value))
+                .unwrap()
+                .with_endomorphism(GlvEndomorphism {
+                    beta: parse_hex(
+                        "7ae96a2b657c07106e64479eac3434e99cf0497512f58995c1396c28719501ee",
+                    ),
+                    lambda: parse_hex(
+                        "5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72",
+                    ),
+                    n: parse_hex(n),
+                    a1: parse_hex("3086d221a7d46bcde86c90e49284eb15"),
+                    b1: -parse_hex("e4437ed6010e88286f547fa90abfe4c3"),
+                    a2: parse_hex("114ca50f7a8e2f3f657c1108d9d44cfd8"),
+                    b2: parse_hex("3086d221a7d46bcde86c90e49284eb15"),
+                }),
+            // a = -3 for NIST curves, so Jacobian doubling is cheaper
+            PreGeneratedParams::P192
+            | PreGeneratedParams::P224
+            | PreGeneratedParams::P256
+            | PreGeneratedParams::P384
+            | PreGeneratedParams::P521 => ECurve::new(Params::from(value))
+                .unwrap()
+                .with_coordinates(CoordinateSystem::Jacobian)
+                .with_arithmetic(FieldArithmetic::FixedWidth),
+            _ => ECurve::new(Params::from(value))
+                .unwrap()
+                .with_arithmetic(FieldArithmetic::FixedWidth),
+        };
+        // all these curves have prime order, so cofactor is always equal to 1
+        EcInfo::new(
+            EcPointP::from_affine(&EcPointA {
+                x: parse_hex(x),
+                y: parse_hex(y),
+                is_inf: false,
+            }),
+            BigUint::from_str_radix(n, 16).unwrap(),
+            BigUint::one(),
+            ecurve,
+        )
+        .unwrap()
+    }
+}
+
+fn parse_hex(x: &str) -> BigInt {
+    BigInt::from_str_radix(x, 16).unwrap()
+}
+
+impl EcInfo {
+    /// **new** -- creates EC domain parameters, checks that base point lies on the curve
+    /// and that it has order **n**, i.e. n * bp = O
+    pub fn new(bp: EcPointP, n: BigUint, h: BigUint, ecurve: ECurve) -> Result<Self> {
+        if bp.is_inf() || !ecurve.check_projective_point(&bp) {
+            return Err(EcError::IncorrectBasePoint(format!(
+                "({bp}) doesn't lie on the curve"
+            )));
         }
+        if !ecurve.proj_point_mul_any(&bp, &n).is_inf() {
+            return Err(EcError::IncorrectBasePoint(format!(
+                "n * ({bp}) != O, n: {n}"
+            )));
+        }
+        Ok(EcInfo {
+            bp,
+            n,
+            h,
+            ecurve,
+            fixed_base: FixedBaseCache::default(),
+        })
+    }
+
+    /// **base_point_mul** -- computes k * bp in constant time with fixed-base table
+    /// (`fixed_base::FixedBaseTable`), that is built on the first call and shared between clones.
+    /// If the table can't be used, constant-time ladder is used instead
+    pub fn base_point_mul(&self, k: &BigUint) -> EcPointP {
+        self.fixed_base
+            .get_or_build(self)
+            .and_then(|table| table.mul(self, k))
+            .unwrap_or_else(|| {
+                self.ecurve
+                    .proj_point_mul_ct(&self.bp, k, &(&self.n * &self.h))
+            })
+    }
+
+    /// **security_report** -- SafeCurves-style audit of the domain parameters,
+    /// see `security::security_report`
+    pub fn security_report(&self) -> Result<SecurityReport> {
+        security::security_report(self)
+    }
+
+    /// **point_order** -- order of the point, see `subgroup::point_order`
+    pub fn point_order(&self, p: &EcPointP) -> Result<BigUint> {
+        subgroup::point_order(self, p)
+    }
+
+    /// **is_in_subgroup** -- P lies on the curve and n * P = O
+    pub fn is_in_subgroup(&self, p: &EcPointP) -> bool {
+        subgroup::is_in_subgroup(self, p)
+    }
+
+    /// **clear_cofactor** -- h * P
+    pub fn clear_cofactor(&self, p: &EcPointP) -> EcPointP {
+        subgroup::clear_cofactor(self, p)
+    }
+
+    /// **discrete_log** -- k, such that Q = k * bp, with Pohlig-Hellman method,
+    /// see `discrete_log::pohlig_hellman`
+    pub fn discrete_log(&self, q: &EcPointP) -> Result<DlpSolution> {
+        discrete_log::pohlig_hellman(self, &self.bp, q)
+    }
+
+    /// **validate_public_key** -- full public key validation from SEC 1, section 3.2.2.1
+    pub fn validate_public_key(&self, q: &EcPointA) -> Result<()> {
+        subgroup::validate_public_key(self, q)
+    }
+
+    /// **validate_proj_public_key** -- the same as `validate_public_key` for projective point
+    pub fn validate_proj_public_key(&self, q: &EcPointP) -> Result<()> {
+        if q.is_inf() {
+            return Err(EcError::InvalidPublicKey("point at infinity".to_string()));
+        }
+        let affine = self
+            .ecurve
+            .take_by_module(q)
+            .to_affine(&self.ecurve)
+            .map_err(|e| EcError::InvalidPublicKey(e.to_string()))?;
+        subgroup::validate_public_key(self, &affine)
     }
 }
 
@@ -177,14 +614,84 @@ impl ECurve {
             a: params.a,
             b: params.b,
             q: params.q,
+            endomorphism: None,
+            coordinates: CoordinateSystem::default(),
+            arithmetic: FieldArithmetic::default(),
+            wnaf: None,
         };
         Ok(ec)
     }
 
+    /// **with_endomorphism** -- enables GLV scalar multiplication, endomorphism has to be valid
+    /// for all points that are going to be multiplied (i.e. they have to belong to subgroup of order n).
+    /// Subgroup checks and cofactor clearing don't use it
+    pub fn with_endomorphism(mut self, glv: GlvEndomorphism) -> Self {
+        self.endomorphism = Some(glv);
+        self
+    }
+
+    pub fn get_endomorphism(&self) -> Option<&GlvEndomorphism> {
+        self.endomorphism.as_ref()
+    }
+
+    /// **with_coordinates** -- sets coordinate system used for scalar multiplication
+    pub fn with_coordinates(mut self, coordinates: CoordinateSystem) -> Self {
+        self.coordinates = coordinates;
+        self
+    }
+
+    pub fn get_coordinates(&self) -> CoordinateSystem {
+        self.coordinates
+    }
+
+    /// **with_arithmetic** -- sets field elements representation used for scalar multiplication
+    pub fn with_arithmetic(mut self, arithmetic: FieldArithmetic) -> Self {
+        self.arithmetic = arithmetic;
+        self
+    }
+
+    pub fn get_arithmetic(&self) -> FieldArithmetic {
+        self.arithmetic
+    }
+
+    /// **with_wnaf** -- enables width-w NAF scalar multiplication with precomputed odd multiples,
+    /// it's faster, but not constant-time, so it's meant for public scalars (e.g. verification).
+    /// Width has to be in [2, 8]
+    pub fn with_wnaf(mut self, w: u8) -> Result<Self> {
+        if !(2..=8).contains(&w) {
+            return Err(EcError::IncorrectParameters(format!(
+                "wNAF width has to be in [2, 8], got {w}"
+            )));
+        }
+        self.wnaf = Some(w);
+        Ok(self)
+    }
+
+    pub fn get_wnaf(&self) -> Option<u8> {
+        self.wnaf
+    }
+
+    /// **is_a_minus_3** -- checks whether a = -3 mod q, which allows faster doubling
+    pub fn is_a_minus_3(&self) -> bool {
+        take_by_bigint_module(&((&self.a + BigInt::from(3_u8)) % &self.q), &self.q).is_zero()
+    }
+
+    /// **is_same_curve** -- compares curve coefficients by module q
+    pub fn is_same_curve(&self, other: &ECurve) -> bool {
+        self.q == other.q
+            && take_by_bigint_module(&(&self.a % &self.q), &self.q)
+                == take_by_bigint_module(&(&other.a % &other.q), &other.q)
+            && take_by_bigint_module(&(&self.b % &self.q), &self.q)
+                == take_by_bigint_module(&(&other.b % &other.q), &other.q)
+    }
+
     // y^2 = x^3 + ax + b
     pub fn check_affine_point(&self, p: &EcPointA) -> bool {
-        p.y.modpow(&BigInt::from(2_u8), &self.q)
-            == p.x.modpow(&BigInt::from(3_u8), &self.q) + &self.a * &p.x + &self.b
+        take_by_bigint_module(&(p.y.modpow(&BigInt::from(2_u8), &self.q)), &self.q)
+            == take_by_bigint_module(
+                &((p.x.modpow(&BigInt::from(3_u8), &self.q) + &self.a * &p.x + &self.b) % &self.q),
+                &self.q,
+            )
     }
 
     // Y^{2}Z = X^{3} + aXZ^{2} + bZ^3,
@@ -209,10 +716,81 @@ impl ECurve {
         self.proj_point_mul(&a.to_projective(), k).to_affine(self)
     }
     pub fn proj_point_add(&self, a: &EcPointP, b: &EcPointP) -> EcPointP {
-        projective_add(self, a, b)
+        match self.coordinates {
+            CoordinateSystem::Complete => self.complete_point_add(a, b),
+            _ => projective_add(self, a, b),
+        }
     }
     pub fn proj_point_mul(&self, a: &EcPointP, k: &BigUint) -> EcPointP {
-        projective_mul(self, a, k)
+        match &self.endomorphism {
+            Some(glv) => glv_mul(self, glv, a, k),
+            None => self.proj_point_mul_any(a, k),
+        }
+    }
+
+    /// **proj_point_mul_any** -- scalar multiplication without GLV endomorphism, it's valid
+    /// for every point of the curve, not only for the subgroup of order n, where φ(P) = λP.
+    /// It's used by subgroup checks, that have to reject points outside of the subgroup
+    pub(crate) fn proj_point_mul_any(&self, a: &EcPointP, k: &BigUint) -> EcPointP {
+        if let FieldArithmetic::FixedWidth = self.arithmetic {
+            if let Some(r) = fixed_width_mul(self, a, k) {
+                return r;
+            }
+        }
+        match (self.wnaf, self.coordinates) {
+            (Some(w), _) => wnaf_mul(self, a, k, w),
+            (None, CoordinateSystem::Jacobian) => self
+                .jacobian_point_mul(&EcPointJ::from_projective(a), k)
+                .to_projective(self),
+            (None, CoordinateSystem::Projective) => projective_mul(self, a, k),
+            (None, CoordinateSystem::Complete) => canonical_neutral(complete_mul(self, a, k)),
+        }
+    }
+
+    /// **proj_point_mul_ct** -- constant-time scalar multiplication for secret scalars
+    /// (Montgomery ladder with complete formulas). `order` has to be a multiple of the order
+    /// of a, e.g. `n * h`: k is reduced modulo it and number of iterations is always equal
+    /// to `order.bits()`. Endomorphism, coordinate system and arithmetic settings
+    /// of the curve are ignored
+    pub fn proj_point_mul_ct(&self, a: &EcPointP, k: &BigUint, order: &BigUint) -> EcPointP {
+        ladder_mul(self, a, k, order)
+    }
+
+    /// **multi_scalar_mul** -- computes sum(k_i * P_i) faster than separate multiplications:
+    /// interleaved wNAF (Straus-Shamir trick) for a few points and Pippenger's bucket method
+    /// from `multi_scalar::STRAUS_THRESHOLD` points. It isn't constant-time
+    pub fn multi_scalar_mul(&self, terms: &[(EcPointP, BigUint)]) -> EcPointP {
+        multi_scalar_mul(self, terms)
+    }
+
+    /// **complete_point_add** -- addition without exceptional cases (Renes–Costello–Batina),
+    /// works only on curves of odd order
+    pub fn complete_point_add(&self, a: &EcPointP, b: &EcPointP) -> EcPointP {
+        canonical_neutral(complete_add(self, a, b))
+    }
+    pub fn complete_point_double(&self, a: &EcPointP) -> EcPointP {
+        canonical_neutral(complete_double(self, a))
+    }
+
+    // Y^2 = X^3 + aXZ^4 + bZ^6
+    pub fn check_jacobian_point(&self, p: &EcPointJ) -> bool {
+        let z2 = (&p.z * &p.z) % &self.q;
+        let z4 = (&z2 * &z2) % &self.q;
+        take_by_bigint_module(&((&p.y * &p.y) % &self.q), &self.q)
+            == take_by_bigint_module(
+                &((&p.x * &p.x * &p.x + &self.a * &p.x * &z4 + &self.b * &z4 * &z2) % &self.q),
+                &self.q,
+            )
+    }
+
+    pub fn jacobian_point_add(&self, a: &EcPointJ, b: &EcPointJ) -> EcPointJ {
+        jacobian_add(self, a, b)
+    }
+    pub fn jacobian_point_double(&self, a: &EcPointJ) -> EcPointJ {
+        jacobian_double(self, a)
+    }
+    pub fn jacobian_point_mul(&self, a: &EcPointJ, k: &BigUint) -> EcPointJ {
+        jacobian_mul(self, a, k)
     }
 
     /// **transform_proj_point** -- transforms projective point Z coordinate into 1
@@ -225,6 +803,65 @@ impl ECurve {
         Ok(p)
     }
 
+    /// **batch_to_affine** -- transforms points into affine form with a single inversion,
+    /// points at infinity are allowed
+    pub fn batch_to_affine(&self, points: &[EcPointP]) -> Result<Vec<EcPointA>> {
+        batch_projective_to_affine(self, points)
+    }
+
+    /// **batch_transform_proj_points** -- the same as `transform_proj_point` for every point,
+    /// but with a single inversion, points at infinity are returned as `EcPointP::neutral()`
+    pub fn batch_transform_proj_points(&self, points: &[EcPointP]) -> Result<Vec<EcPointP>> {
+        Ok(self
+            .batch_to_affine(points)?
+            .iter()
+            .map(EcPointP::from_affine)
+            .collect())
+    }
+
+    /// **order** -- number of points on the curve including O, see `point_counting::order`
+    pub fn order(&self) -> Result<BigUint> {
+        point_counting::order(self)
+    }
+
+    /// **lift_x** -- finds point (x, y) with the given parity of y (true if y is odd),
+    /// y = sqrt(x^3 + ax + b) mod q
+    pub fn lift_x(&self, x: &BigInt, odd: bool) -> Result<EcPointA> {
+        let x = take_by_bigint_module(&(x % &self.q), &self.q);
+        let rhs = take_by_bigint_module(
+            &((&x * &x * &x + &self.a * &x + &self.b) % &self.q),
+            &self.q,
+        );
+        let y = sqrt_mod(&rhs, &self.q).ok_or_else(|| {
+            EcError::PointNotOnCurve(format!("x^3 + ax + b is a non-residue for x = {x}"))
+        })?;
+        let y = match y.bit(0) == odd {
+            true => y,
+            false if y.is_zero() => {
+                return Err(EcError::PointNotOnCurve(format!(
+                    "the only point with x = {x} has even y = 0"
+                )))
+            }
+            false => &self.q - y,
+        };
+        Ok(EcPointA::new(&x, &y))
+    }
+
+    /// **encode_point** -- SEC 1 octet string of the affine point in the given form
+    pub fn encode_point(&self, p: &EcPointA, format: PointFormat) -> Vec<u8> {
+        encode_point(self, p, format)
+    }
+
+    /// **encode_proj_point** -- the same as `encode_point`, but for projective point
+    pub fn encode_proj_point(&self, p: &EcPointP, format: PointFormat) -> Result<Vec<u8>> {
+        Ok(encode_point(self, &p.to_affine(self)?, format))
+    }
+
+    /// **decode_point** -- parses SEC 1 octet string of any form, point has to lie on the curve
+    pub fn decode_point(&self, bytes: &[u8]) -> Result<EcPointA> {
+        decode_point(self, bytes)
+    }
+
     /// **take_by_module** -- takes all parts of the point by module
     /// i.e. -3 mod 13 = 10 (it removes minuses from point coordinates)
     pub fn take_by_module(&self, p: &EcPointP) -> EcPointP {
@@ -235,3 +872,92 @@ impl ECurve {
         }
     }
 }
+
+impl Group for ECurve {
+    type Point = EcPointP;
+
+    fn identity(&self) -> EcPointP {
+        EcPointP::neutral()
+    }
+    fn is_identity(&self, p: &EcPointP) -> bool {
+        take_by_bigint_module(&(&p.z % &self.q), &self.q).is_zero()
+    }
+    fn add(&self, a: &EcPointP, b: &EcPointP) -> EcPointP {
+        self.proj_point_add(a, b)
+    }
+    fn neg(&self, a: &EcPointP) -> EcPointP {
+        self.take_by_module(&a.negative())
+    }
+    fn normalize(&self, p: &EcPointP) -> Result<EcPointP> {
+        match self.is_identity(p) {
+            true => Ok(EcPointP::neutral()),
+            false => self.transform_proj_point(p),
+        }
+    }
+    fn mul(&self, a: &EcPointP, k: &BigUint) -> EcPointP {
+        self.proj_point_mul(a, k)
+    }
+    fn multi_mul(&self, terms: &[(EcPointP, BigUint)]) -> EcPointP {
+        self.multi_scalar_mul(terms)
+    }
+}
+
+impl CurveModel for ECurve {
+    type BaseField = BigPrimeField;
+
+    fn base_field(&self) -> BigPrimeField {
+        BigPrimeField::new(&self.q)
+    }
+    fn contains(&self, p: &EcPointP) -> bool {
+        self.check_projective_point(p)
+    }
+    fn to_affine(&self, p: &EcPointP) -> Result<(BigInt, BigInt)> {
+        if self.is_identity(p) {
+            return Err(EcError::PointNotOnCurve(
+                "point at infinity has no affine coordinates".to_string(),
+            ));
+        }
+        let a = p.to_affine(self)?;
+        Ok((a.x, a.y))
+    }
+    fn is_same_curve(&self, other: &ECurve) -> bool {
+        ECurve::is_same_curve(self, other)
+    }
+}
+
+/// points are encoded as compressed SEC 1 octet strings
+impl PointEncoding for ECurve {
+    fn encode(&self, p: &EcPointP) -> Result<Vec<u8>> {
+        self.encode_proj_point(p, PointFormat::Compressed)
+    }
+    fn decode(&self, bytes: &[u8]) -> Result<EcPointP> {
+        Ok(EcPointP::from_affine(&self.decode_point(bytes)?))
+    }
+}
+
+impl DomainParameters for EcInfo {
+    type Curve = ECurve;
+
+    fn curve(&self) -> &ECurve {
+        &self.ecurve
+    }
+    fn generator(&self) -> EcPointP {
+        self.bp.clone()
+    }
+    fn order(&self) -> BigUint {
+        self.n.clone()
+    }
+    fn cofactor(&self) -> BigUint {
+        self.h.clone()
+    }
+    fn base_point_mul(&self, k: &BigUint) -> EcPointP {
+        EcInfo::base_point_mul(self, k)
+    }
+    /// constant-time ladder with bit length of n * h steps
+    fn secret_mul(&self, p: &EcPointP, k: &BigUint) -> EcPointP {
+        self.ecurve.proj_point_mul_ct(p, k, &(&self.n * &self.h))
+    }
+    fn validate_point(&self, p: &EcPointP) -> Result<()> {
+        self.validate_proj_public_key(p)
+    }
+}
@@ -0,0 +1,363 @@
+use crate::field::{FieldElement, PrimeField};
+use crate::polynomial::Poly;
+use crate::EcError;
+use num_bigint::BigInt;
+use std::collections::HashMap;
+
+/// **ModularPolynomial** -- canonical modular polynomial G_l(X, Y) mod p, G_l(f(tau), j(tau)) = 0
+/// for f = l^s (eta(l tau) / eta(tau))^{2s}, s = 12 / gcd(12, l - 1). Its degree in Y is
+/// v = s(l - 1) / 12 instead of l + 1 for the classical one, so coefficients are much smaller.
+/// G_l is computed modulo p from q-expansions: power sums of l + 1 roots f_k(q) are found with
+/// Miller's recurrence for powers of Euler's function, elementary symmetric functions of them
+/// with Newton's identities, and then they are expressed as polynomials in j
+pub struct ModularPolynomial<'f, const N: usize> {
+    l: u64,
+    s: u64,
+    /// **coefs** -- coefs[a][b] is coefficient of X^a Y^b, a <= l + 1, b <= v
+    coefs: Vec<Vec<FieldElement<'f, N>>>,
+}
+
+impl<'f, const N: usize> ModularPolynomial<'f, N> {
+    /// **new** -- l has to be an odd prime, p has to be larger than the length of q-expansions,
+    /// that is about 2l^2 / 12 * s
+    pub fn new(field: &'f PrimeField<N>, l: u64) -> crate::Result<Self> {
+        if l < 3
+            || (2..l)
+                .take_while(|d| d * d <= l)
+                .any(|d| l.is_multiple_of(d))
+        {
+            return Err(EcError::IncorrectParameters(format!(
+                "l has to be an odd prime, got l = {l}"
+            )));
+        }
+        let s = 12 / gcd(12, l - 1);
+        let v = (s * (l - 1) / 12) as usize;
+        let len = (l as usize + 1) * v + l as usize * (v + 1) + 2;
+        if field.modulus() <= BigInt::from(len) {
+            return Err(EcError::IncorrectParameters(format!(
+                "modulus {} is too small for modular polynomial of level {l}",
+                field.modulus()
+            )));
+        }
+        let series = Series::new(field, len);
+        let sums = series.power_sums(l, s, v);
+        let elementary = series.newton(&sums, v);
+        let powers = series.j_powers(v);
+        let zero = field.zero();
+
+        // e_k = sum c_d j^d, the pole of order d is removed with c_d j^d
+        let mut coefs = vec![vec![zero; v + 1]; l as usize + 2];
+        for (k, e) in elementary.iter().enumerate() {
+            let lo = e.lo.min(-(v as i64));
+            let mut rem = vec![zero; (1 - lo) as usize];
+            for (i, c) in e.coefs.iter().enumerate() {
+                if e.lo + (i as i64) <= 0 {
+                    rem[(e.lo - lo) as usize + i] = *c;
+                }
+            }
+            for d in (0..=v).rev() {
+                let c = rem[(-(d as i64) - lo) as usize];
+                for (i, x) in powers[d].coefs.iter().enumerate() {
+                    let idx = powers[d].lo + i as i64 - lo;
+                    if idx < rem.len() as i64 {
+                        rem[idx as usize] = rem[idx as usize] - c * *x;
+                    }
+                }
+                // G = prod (X - f_k) = sum (-1)^k e_k X^{l + 1 - k}
+                coefs[l as usize + 1 - k][d] = match k % 2 {
+                    0 => c,
+                    _ => -c,
+                };
+            }
+        }
+        Ok(ModularPolynomial { l, s, coefs })
+    }
+
+    pub fn l(&self) -> u64 {
+        self.l
+    }
+
+    /// **s** -- f = l^s (eta(l tau) / eta(tau))^{2s}
+    pub fn s(&self) -> u64 {
+        self.s
+    }
+
+    /// **coef** -- coefficient of X^a Y^b
+    pub fn coef(&self, a: usize, b: usize) -> FieldElement<'f, N> {
+        self.coefs[a][b]
+    }
+
+    /// **at_y** -- univariate polynomial G_l(X, y)
+    pub fn at_y(&self, y: FieldElement<'f, N>) -> Poly<'f, N> {
+        let field = y.field();
+        let coefs = self
+            .coefs
+            .iter()
+            .map(|row| row.iter().rev().fold(field.zero(), |acc, c| acc * y + *c))
+            .collect();
+        Poly::new(field, coefs)
+    }
+
+    /// **partial** -- d^dx/dX^dx d^dy/dY^dy G_l at (x, y)
+    pub fn partial(
+        &self,
+        x: FieldElement<'f, N>,
+        y: FieldElement<'f, N>,
+        dx: usize,
+        dy: usize,
+    ) -> FieldElement<'f, N> {
+        let field = x.field();
+        // falling factorial k (k - 1) ... (k - d + 1)
+        let factor = |k: usize, d: usize| field.from_u64((k + 1 - d..=k).product::<usize>() as u64);
+        let mut r = field.zero();
+        for (a, row) in self.coefs.iter().enumerate().skip(dx).rev() {
+            let mut c = field.zero();
+            for (b, coef) in row.iter().enumerate().skip(dy).rev() {
+                c = c * y + *coef * factor(b, dy);
+            }
+            r = r * x + c * factor(a, dx);
+        }
+        r
+    }
+}
+
+/// **ModularPolynomials** -- polynomials G_l mod p computed so far, they depend only on p,
+/// so they are shared between curves over the same field
+pub struct ModularPolynomials<'f, const N: usize> {
+    field: &'f PrimeField<N>,
+    polys: HashMap<u64, ModularPolynomial<'f, N>>,
+}
+
+impl<'f, const N: usize> ModularPolynomials<'f, N> {
+    pub fn new(field: &'f PrimeField<N>) -> Self {
+        ModularPolynomials {
+            field,
+            polys: HashMap::new(),
+        }
+    }
+
+    pub fn field(&self) -> &'f PrimeField<N> {
+        self.field
+    }
+
+    /// **get** -- G_l, it's computed on the first call
+    pub fn get(&mut self, l: u64) -> crate::Result<&ModularPolynomial<'f, N>> {
+        if !self.polys.contains_key(&l) {
+            self.polys.insert(l, ModularPolynomial::new(self.field, l)?);
+        }
+        Ok(&self.polys[&l])
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+// Laurent series c_lo q^lo + ... + c_hi q^hi, it's exact up to q^hi
+struct Laurent<'f, const N: usize> {
+    lo: i64,
+    coefs: Vec<FieldElement<'f, N>>,
+}
+
+impl<'f, const N: usize> Laurent<'f, N> {
+    // product truncated after q^hi
+    fn mul(&self, other: &Self, hi: i64, zero: FieldElement<'f, N>) -> Self {
+        let lo = self.lo + other.lo;
+        let mut coefs = vec![zero; (hi - lo + 1).max(0) as usize];
+        for (i, x) in self.coefs.iter().enumerate() {
+            for (k, y) in other
+                .coefs
+                .iter()
+                .enumerate()
+                .take(coefs.len().saturating_sub(i))
+            {
+                coefs[i + k] = coefs[i + k] + *x * *y;
+            }
+        }
+        Laurent { lo, coefs }
+    }
+}
+
+// q-expansions up to q^len, numbers 0..len and their inverses are precomputed
+struct Series<'f, const N: usize> {
+    field: &'f PrimeField<N>,
+    numbers: Vec<FieldElement<'f, N>>,
+    inverses: Vec<FieldElement<'f, N>>,
+    /// **euler** -- prod (1 - q^n) = sum (-1)^m q^{m(3m - 1) / 2} over m in Z,
+    /// (exponent, sign is minus) for nonzero terms
+    euler: Vec<(usize, bool)>,
+}
+
+impl<'f, const N: usize> Series<'f, N> {
+    fn new(field: &'f PrimeField<N>, len: usize) -> Self {
+        let mut numbers = vec![field.zero()];
+        for n in 1..len {
+            numbers.push(numbers[n - 1] + field.one());
+        }
+        // batch inversion: prefix products are inverted at once
+        let mut prefix = vec![field.one()];
+        for n in 1..len {
+            prefix.push(prefix[n - 1] * numbers[n]);
+        }
+        let mut inverses = vec![field.zero(); len];
+        let mut acc = prefix[len - 1].inverse();
+        for n in (1..len).rev() {
+            inverses[n] = acc * prefix[n - 1];
+            acc = acc * numbers[n];
+        }
+
+        let mut euler = vec![];
+        for m in 1.. {
+            let (a, b) = (m * (3 * m - 1) / 2, m * (3 * m + 1) / 2);
+            if a >= len {
+                break;
+            }
+            euler.push((a, m % 2 == 1));
+            if b < len {
+                euler.push((b, m % 2 == 1));
+            }
+        }
+        Series {
+            field,
+            numbers,
+            inverses,
+            euler,
+        }
+    }
+
+    // P^e up to q^(len - 1) for Euler's function P, Miller's recurrence for powers of
+    // sparse series: n Q_n = sum ((e + 1)k - n) P_k Q_{n - k}
+    fn euler_power(&self, e: i64, len: usize) -> Vec<FieldElement<'f, N>> {
+        let mut r = vec![self.field.one()];
+        for n in 1..len {
+            let terms = self
+                .euler
+                .iter()
+                .take_while(|(k, _)| *k <= n)
+                .map(|(k, minus)| {
+                    let c = (e + 1) * *k as i64 - n as i64;
+                    (if *minus { -c } else { c }, r[n - k])
+                });
+            let sum = self.field.small_combination(terms);
+            r.push(sum * self.inverses[n]);
+        }
+        r
+    }
+
+    // power sums S_n = sum f_k^n, n = 1..l + 1, exact up to q^(v + 1). The roots are f and
+    // l^s / f((tau + k) / l), so S_n = l U_l(h_n) + f^n, where U_l picks out exponents
+    // divisible by l and h_n = q^{-nv} P(q)^{2sn} / P(q^l)^{2sn}
+    fn power_sums(&self, l: u64, s: u64, v: usize) -> Vec<Laurent<'f, N>> {
+        let (l, s, hi) = (l as usize, s as i64, v + 1);
+        let l_elem = self.numbers[l];
+        let mut sums = vec![];
+        for n in 1..=l + 1 {
+            let top = n * v + l * hi;
+            let u = self.euler_power(2 * s * n as i64, top + 1);
+            let w = self.euler_power(-2 * s * n as i64, top / l + 2);
+            let mlo = -((n * v / l) as i64);
+            let mut coefs: Vec<FieldElement<'f, N>> = (mlo..=hi as i64)
+                .map(|m| {
+                    let e = (l as i64 * m + (n * v) as i64) as usize;
+                    let acc =
+                        (0..=e / l).fold(self.field.zero(), |acc, i| acc + u[e - l * i] * w[i]);
+                    l_elem * acc
+                })
+                .collect();
+            // f^n = l^{sn} q^{nv} P(q^l)^{2sn} / P(q)^{2sn}
+            if n * v <= hi {
+                let f1 = self.euler_power(-2 * s * n as i64, hi + 1);
+                let f2 = self.euler_power(2 * s * n as i64, hi / l + 2);
+                let c = l_elem.pow(&[s as u64 * n as u64]);
+                for e in n * v..=hi {
+                    let r = e - n * v;
+                    let acc =
+                        (0..=r / l).fold(self.field.zero(), |acc, i| acc + f1[r - l * i] * f2[i]);
+                    let idx = (e as i64 - mlo) as usize;
+                    coefs[idx] = coefs[idx] + c * acc;
+                }
+            }
+            sums.push(Laurent { lo: mlo, coefs });
+        }
+        sums
+    }
+
+    // e_k = (1/k) sum_{i = 1}^{k} (-1)^{i - 1} e_{k - i} S_i, k = 0..l + 1, every product is
+    // exact up to the lowest of exact exponents of its factors, for l <= 200 they reach q^0
+    fn newton(&self, sums: &[Laurent<'f, N>], v: usize) -> Vec<Laurent<'f, N>> {
+        let top = v as i64 + 1;
+        let mut e = vec![Laurent {
+            lo: 0,
+            coefs: vec![self.field.one()],
+        }];
+        // e_0 = 1 is exact everywhere
+        let mut exact = vec![i64::MAX];
+        for k in 1..=sums.len() {
+            let hi = (1..=k)
+                .map(|i| (exact[k - i].saturating_add(sums[i - 1].lo)).min(top + e[k - i].lo))
+                .fold(top, i64::min);
+            let lo = (1..=k).map(|i| e[k - i].lo + sums[i - 1].lo).min().unwrap();
+            let mut coefs = vec![self.field.zero(); (hi - lo + 1).max(0) as usize];
+            for i in 1..=k {
+                let prod = e[k - i].mul(&sums[i - 1], hi, self.field.zero());
+                for (idx, c) in prod.coefs.iter().enumerate() {
+                    let pos = (prod.lo - lo) as usize + idx;
+                    coefs[pos] = match i % 2 {
+                        1 => coefs[pos] + *c,
+                        _ => coefs[pos] - *c,
+                    };
+                }
+            }
+            let k_inv = self.inverses[k];
+            let coefs = coefs.into_iter().map(|c| c * k_inv).collect();
+            assert!(hi >= 0, "e_{k} is exact only up to q^{hi}");
+            e.push(Laurent { lo, coefs });
+            exact.push(hi);
+        }
+        e
+    }
+
+    // j^d, d = 0..v, up to q^(v + 1), j = E4^3 / Delta = q^{-1} + 744 + 196884q + ...
+    fn j_powers(&self, v: usize) -> Vec<Laurent<'f, N>> {
+        let len = v + 3;
+        let field = self.field;
+        // E4 = 1 + 240 sum sigma_3(n) q^n
+        let mut e4 = vec![field.one()];
+        for n in 1..len as u64 {
+            let sigma: u64 = (1..=n).filter(|d| n % d == 0).map(|d| d * d * d).sum();
+            e4.push(field.from_u64(240 * sigma));
+        }
+        let e4 = Laurent { lo: 0, coefs: e4 };
+        let zero = field.zero();
+        let e4_cube = e4
+            .mul(&e4, len as i64 - 1, zero)
+            .mul(&e4, len as i64 - 1, zero);
+        // Delta / q = P^24, its inverse is found term by term
+        let delta = self.euler_power(24, len);
+        let mut inv = vec![field.one()];
+        for n in 1..len {
+            let c = (1..=n).fold(field.zero(), |acc, k| acc + delta[k] * inv[n - k]);
+            inv.push(-c);
+        }
+        let j = Laurent {
+            lo: -1,
+            coefs: e4_cube
+                .mul(&Laurent { lo: 0, coefs: inv }, len as i64 - 1, zero)
+                .coefs,
+        };
+
+        let hi = v as i64 + 1;
+        let mut powers = vec![Laurent {
+            lo: 0,
+            coefs: vec![field.one()],
+        }];
+        for d in 1..=v {
+            let next = powers[d - 1].mul(&j, hi, zero);
+            powers.push(next);
+        }
+        powers
+    }
+}
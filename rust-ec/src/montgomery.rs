@@ -0,0 +1,319 @@
+use crate::affine_point::EcPointA;
+use crate::helpers::{bigint_cswap, inverse, take_by_bigint_module};
+use crate::projective_point::EcPointP;
+use crate::{ECurve, EcError, EcInfo, Params};
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::{Num, One, Zero};
+
+/// **MontgomeryCurve** -- represents elliptic curve in Montgomery form
+/// points satisfy the following equation
+/// By^2 = x^3 + Ax^2 + x, where B(A^2 - 4) mod q != 0
+#[derive(PartialOrd, PartialEq, Clone, Debug)]
+pub struct MontgomeryCurve {
+    a: BigInt,
+    b: BigInt,
+    q: BigInt,
+}
+
+/// **PreGeneratedMontgomeryParams** -- curves from RFC 7748
+#[derive(Debug)]
+pub enum PreGeneratedMontgomeryParams {
+    Curve25519,
+    Curve448,
+}
+
+impl PreGeneratedMontgomeryParams {
+    /// **base_point** -- base point (u, v) from RFC 7748, section 4.1 and 4.2
+    pub fn base_point(&self) -> EcPointA {
+        let (u, v) = match self {
+            PreGeneratedMontgomeryParams::Curve25519 => (
+                "9",
+                "14781619447589544791020593568409986887264606134616475288964881837755586237401",
+            ),
+            PreGeneratedMontgomeryParams::Curve448 => (
+                "5",
+                "355293926785568175264127502063783334808976399387714271831880898435169088786967410002932673765864550910142774147268105838985595290606362",
+            ),
+        };
+        EcPointA {
+            x: BigInt::from_str_radix(u, 10).unwrap(),
+            y: BigInt::from_str_radix(v, 10).unwrap(),
+            is_inf: false,
+        }
+    }
+
+    /// **order** -- order of prime subgroup generated by base point
+    pub fn order(&self) -> BigUint {
+        BigUint::from_str_radix(
+            match self {
+                PreGeneratedMontgomeryParams::Curve25519 => "7237005577332262213973186563042994240857116359379907606001950938285454250989",
+                PreGeneratedMontgomeryParams::Curve448 => "181709681073901722637330951972001133588410340171829515070372549795146003961539585716195755291692375963310293709091662304773755859649779",
+            },
+            10,
+        )
+        .unwrap()
+    }
+
+    pub fn cofactor(&self) -> BigUint {
+        match self {
+            PreGeneratedMontgomeryParams::Curve25519 => BigUint::from(8_u8),
+            PreGeneratedMontgomeryParams::Curve448 => BigUint::from(4_u8),
+        }
+    }
+}
+
+impl From<PreGeneratedMontgomeryParams> for MontgomeryCurve {
+    fn from(value: PreGeneratedMontgomeryParams) -> Self {
+        MontgomeryCurve::from(&value)
+    }
+}
+impl From<&PreGeneratedMontgomeryParams> for MontgomeryCurve {
+    fn from(value: &PreGeneratedMontgomeryParams) -> Self {
+        match value {
+            // q = 2^255 - 19
+            PreGeneratedMontgomeryParams::Curve25519 => MontgomeryCurve::new(
+                &BigInt::from(486662),
+                &BigInt::one(),
+                &BigInt::from_str_radix(
+                    "57896044618658097711785492504343953926634992332820282019728792003956564819949",
+                    10,
+                )
+                .unwrap(),
+            )
+            .unwrap(),
+            // q = 2^448 - 2^224 - 1
+            PreGeneratedMontgomeryParams::Curve448 => MontgomeryCurve::new(
+                &BigInt::from(156326),
+                &BigInt::one(),
+                &BigInt::from_str_radix(
+                    "726838724295606890549323807888004534353641360687318060281490199180612328166730772686396383698676545930088884461843637361053498018365439",
+                    10,
+                )
+                .unwrap(),
+            )
+            .unwrap(),
+        }
+    }
+}
+
+impl From<PreGeneratedMontgomeryParams> for EcInfo {
+    fn from(value: PreGeneratedMontgomeryParams) -> Self {
+        EcInfo::from(&value)
+    }
+}
+/// EC domain parameters of the Weierstrass model of Montgomery curve
+impl From<&PreGeneratedMontgomeryParams> for EcInfo {
+    fn from(value: &PreGeneratedMontgomeryParams) -> Self {
+        let curve = MontgomeryCurve::from(value);
+        EcInfo::new(
+            curve
+                .to_weierstrass_point(&value.base_point())
+                .unwrap()
+                .to_projective(),
+            value.order(),
+            value.cofactor(),
+            curve.to_weierstrass().unwrap(),
+        )
+        .unwrap()
+    }
+}
+
+impl MontgomeryCurve {
+    pub fn new(a: &BigInt, b: &BigInt, q: &BigInt) -> crate::Result<Self> {
+        let (a, b) = (
+            take_by_bigint_module(&(a % q), q),
+            take_by_bigint_module(&(b % q), q),
+        );
+        if (&b * (&a * &a - BigInt::from(4_u8))) % q == BigInt::zero() {
+            return Err(EcError::IncorrectParameters(format!(
+                "B(A^2 - 4) = 0 mod q, A: {a}, B: {b}, q: {q}"
+            )));
+        }
+        Ok(MontgomeryCurve { a, b, q: q.clone() })
+    }
+
+    pub fn get_a(&self) -> BigInt {
+        self.a.clone()
+    }
+    pub fn get_b(&self) -> BigInt {
+        self.b.clone()
+    }
+    pub fn get_q(&self) -> BigInt {
+        self.q.clone()
+    }
+
+    // By^2 = x^3 + Ax^2 + x
+    pub fn check_point(&self, p: &EcPointA) -> bool {
+        if p.is_inf() {
+            return true;
+        }
+        take_by_bigint_module(&((&self.b * &p.y * &p.y) % &self.q), &self.q)
+            == take_by_bigint_module(
+                &((&p.x * &p.x * &p.x + &self.a * &p.x * &p.x + &p.x) % &self.q),
+                &self.q,
+            )
+    }
+
+    /// **ladder** -- x-only Montgomery ladder (RFC 7748, section 5), computes u(kP) from u(P).
+    /// It always makes `bits` iterations, so k has to be less than 2^bits, and coordinates
+    /// are exchanged with masked conditional swaps (cswap), so timing doesn't depend on k.
+    /// If kP = O, then 0 is returned
+    pub fn ladder(&self, u: &BigInt, k: &BigUint, bits: u64) -> BigInt {
+        let q = &self.q;
+        let limbs = q.bits().div_ceil(64) as usize;
+        // k as little-endian limbs padded to the number of iterations
+        let mut k_limbs = k.to_u64_digits();
+        k_limbs.resize((bits.div_ceil(64) as usize).max(k_limbs.len()), 0);
+        let k_bit = |t: u64| (k_limbs[(t / 64) as usize] >> (t % 64)) & 1;
+        let a24 = (&self.a - BigInt::from(2_u8)) * inverse(&BigInt::from(4_u8), q).unwrap() % q;
+        let x1 = take_by_bigint_module(&(u % q), q);
+        let (mut x2, mut z2) = (BigInt::one(), BigInt::zero());
+        let (mut x3, mut z3) = (x1.clone(), BigInt::one());
+        let mut swap = 0;
+
+        for t in (0..bits).rev() {
+            let k_t = k_bit(t);
+            swap ^= k_t;
+            bigint_cswap(q, &mut x2, &mut x3, swap, limbs);
+            bigint_cswap(q, &mut z2, &mut z3, swap, limbs);
+            swap = k_t;
+
+            let a = &x2 + &z2;
+            let aa = (&a * &a) % q;
+            let b = &x2 - &z2;
+            let bb = (&b * &b) % q;
+            let e = &aa - &bb;
+            let c = &x3 + &z3;
+            let d = &x3 - &z3;
+            let da = (&d * &a) % q;
+            let cb = (&c * &b) % q;
+            x3 = ((&da + &cb) * (&da + &cb)) % q;
+            z3 = (&x1 * (&da - &cb) * (&da - &cb)) % q;
+            x2 = (&aa * &bb) % q;
+            z2 = (&e * (&aa + &a24 * &e)) % q;
+        }
+        bigint_cswap(q, &mut x2, &mut x3, swap, limbs);
+        bigint_cswap(q, &mut z2, &mut z3, swap, limbs);
+
+        // z2^(q - 2) = z2^{-1}, and 0 if z2 = 0
+        let z2_inv = take_by_bigint_module(&z2, q).modpow(&(q - BigInt::from(2_u8)), q);
+        take_by_bigint_module(&((x2 * z2_inv) % q), q)
+    }
+
+    /// **weierstrass_params** -- parameters of birationally equivalent curve in Weierstrass form
+    /// a = (3 - A^2) / 3B^2, b = (2A^3 - 9A) / 27B^3
+    pub fn weierstrass_params(&self) -> Params {
+        let q = &self.q;
+        let (a, b) = (&self.a, &self.b);
+        let inv_3b2 = inverse(&((BigInt::from(3_u8) * b * b) % q), q).unwrap();
+        let inv_27b3 = inverse(&((BigInt::from(27_u8) * b * b * b) % q), q).unwrap();
+        Params {
+            a: take_by_bigint_module(&(((BigInt::from(3_u8) - a * a) * inv_3b2) % q), q),
+            b: take_by_bigint_module(
+                &(((BigInt::from(2_u8) * a * a * a - BigInt::from(9_u8) * a) * inv_27b3) % q),
+                q,
+            ),
+            q: q.clone(),
+        }
+    }
+
+    pub fn to_weierstrass(&self) -> crate::Result<ECurve> {
+        ECurve::new(self.weierstrass_params())
+    }
+
+    /// **to_weierstrass_point** -- maps (u, v) -> (u/B + A/3B, v/B)
+    pub fn to_weierstrass_point(&self, p: &EcPointA) -> crate::Result<EcPointA> {
+        if p.is_inf() {
+            return Ok(p.clone());
+        }
+        let q = &self.q;
+        let b_inv = inverse(&self.b, q)?;
+        let a_3b = (&self.a * inverse(&((BigInt::from(3_u8) * &self.b) % q), q)?) % q;
+        Ok(EcPointA {
+            x: take_by_bigint_module(&((&p.x * &b_inv + a_3b) % q), q),
+            y: take_by_bigint_module(&((&p.y * &b_inv) % q), q),
+            is_inf: false,
+        })
+    }
+
+    /// **from_weierstrass_point** -- maps (x, y) -> (B(x - A/3B), By),
+    /// inverse map to `to_weierstrass_point`
+    pub fn from_weierstrass_point(&self, p: &EcPointA) -> crate::Result<EcPointA> {
+        if p.is_inf() {
+            return Ok(p.clone());
+        }
+        let q = &self.q;
+        let a_3b = (&self.a * inverse(&((BigInt::from(3_u8) * &self.b) % q), q)?) % q;
+        Ok(EcPointA {
+            x: take_by_bigint_module(&((&self.b * (&p.x - a_3b)) % q), q),
+            y: take_by_bigint_module(&((&self.b * &p.y) % q), q),
+            is_inf: false,
+        })
+    }
+
+    /// **from_weierstrass_proj_point** -- the same as `from_weierstrass_point`,
+    /// but takes projective point on Weierstrass model of the curve
+    pub fn from_weierstrass_proj_point(&self, p: &EcPointP) -> crate::Result<EcPointA> {
+        self.from_weierstrass_point(&p.to_affine(&self.to_weierstrass()?)?)
+    }
+}
+
+/// **decode_scalar25519** -- decodes 32-byte little-endian scalar with clamping (RFC 7748, section 5)
+pub fn decode_scalar25519(k: &[u8; 32]) -> BigUint {
+    let mut k = *k;
+    k[0] &= 248;
+    k[31] &= 127;
+    k[31] |= 64;
+    BigUint::from_bytes_le(&k)
+}
+
+/// **decode_scalar448** -- decodes 56-byte little-endian scalar with clamping (RFC 7748, section 5)
+pub fn decode_scalar448(k: &[u8; 56]) -> BigUint {
+    let mut k = *k;
+    k[0] &= 252;
+    k[55] |= 128;
+    BigUint::from_bytes_le(&k)
+}
+
+/// **decode_u_coordinate** -- decodes little-endian u-coordinate of exactly ceil(bits / 8) bytes,
+/// unused most significant bits (if bits % 8 != 0) are masked
+pub fn decode_u_coordinate(u: &[u8], bits: u64) -> crate::Result<BigInt> {
+    let len = bits.div_ceil(8) as usize;
+    if len == 0 || u.len() != len {
+        return Err(EcError::IncorrectPointEncoding(format!(
+            "u-coordinate has {} bytes, expected {len}",
+            u.len()
+        )));
+    }
+    let mut u = u.to_vec();
+    let unused_bits = (8 - bits % 8) % 8;
+    if unused_bits > 0 {
+        u[len - 1] &= 0xff >> unused_bits;
+    }
+    Ok(BigInt::from_bytes_le(Sign::Plus, &u))
+}
+
+/// **encode_u_coordinate** -- encodes u-coordinate as `len`-byte little-endian string
+pub fn encode_u_coordinate(u: &BigInt, len: usize) -> Vec<u8> {
+    let mut bytes = u.to_bytes_le().1;
+    bytes.resize(len, 0);
+    bytes
+}
+
+/// **x25519** -- X25519 function from RFC 7748
+pub fn x25519(k: &[u8; 32], u: &[u8; 32]) -> [u8; 32] {
+    let curve = MontgomeryCurve::from(PreGeneratedMontgomeryParams::Curve25519);
+    // the length of u always matches 255 bits
+    let u = decode_u_coordinate(u, 255).unwrap();
+    let res = curve.ladder(&u, &decode_scalar25519(k), 255);
+    encode_u_coordinate(&res, 32).try_into().unwrap()
+}
+
+/// **x448** -- X448 function from RFC 7748
+pub fn x448(k: &[u8; 56], u: &[u8; 56]) -> [u8; 56] {
+    let curve = MontgomeryCurve::from(PreGeneratedMontgomeryParams::Curve448);
+    // the length of u always matches 448 bits
+    let u = decode_u_coordinate(u, 448).unwrap();
+    let res = curve.ladder(&u, &decode_scalar448(k), 448);
+    encode_u_coordinate(&res, 56).try_into().unwrap()
+}
@@ -0,0 +1,189 @@
+use crate::field::PrimeField;
+use crate::helpers::{
+    field_complete_add, field_jacobian_add, field_jacobian_double, odd_multiples, to_wnaf,
+    with_limbs, FieldPoint,
+};
+use crate::projective_point::EcPointP;
+use crate::{CoordinateSystem, ECurve, FieldArithmetic};
+use num_bigint::BigUint;
+
+/// **STRAUS_THRESHOLD** -- from this number of points Pippenger's method is used
+pub const STRAUS_THRESHOLD: usize = 64;
+/// **STRAUS_WNAF_WIDTH** -- window width of every scalar in Straus' method
+const STRAUS_WNAF_WIDTH: u8 = 5;
+
+/// **multi_scalar_mul** -- computes sum(k_i * P_i) with field arithmetic of the curve
+pub(crate) fn multi_scalar_mul(ec_curve: &ECurve, terms: &[(EcPointP, BigUint)]) -> EcPointP {
+    if ec_curve.get_arithmetic() == FieldArithmetic::FixedWidth {
+        if let Some(r) = with_limbs!(ec_curve.q, field_multi_scalar_mul(ec_curve, terms)) {
+            return r;
+        }
+    }
+    let points = terms
+        .iter()
+        .map(|(p, _)| ec_curve.take_by_module(p))
+        .collect();
+    let scalars: Vec<BigUint> = terms.iter().map(|(_, k)| k.clone()).collect();
+    multi_mul(
+        points,
+        &scalars,
+        EcPointP::neutral(),
+        |a, b| ec_curve.proj_point_add(a, b),
+        |a| ec_curve.proj_point_add(a, a),
+        |a| ec_curve.take_by_module(&a.negative()),
+    )
+}
+
+fn field_multi_scalar_mul<const N: usize>(
+    ec_curve: &ECurve,
+    terms: &[(EcPointP, BigUint)],
+) -> Option<EcPointP> {
+    let field = PrimeField::<N>::new(&ec_curve.q).ok()?;
+    Some(field_points_mul(&field, ec_curve, terms))
+}
+
+fn field_points_mul<'f, const N: usize>(
+    field: &'f PrimeField<N>,
+    ec_curve: &ECurve,
+    terms: &[(EcPointP, BigUint)],
+) -> EcPointP {
+    let coef_a = field.element(&ec_curve.a);
+    let coef_b = field.element(&ec_curve.b);
+    let a_minus_3 = ec_curve.is_a_minus_3();
+    let scalars: Vec<BigUint> = terms.iter().map(|(_, k)| k.clone()).collect();
+    let neutral = (field.zero(), field.one(), field.zero());
+    let negate = |a: &FieldPoint<'f, N>| (a.0, -a.1, a.2);
+
+    let (x, y, z) = match ec_curve.get_coordinates() {
+        CoordinateSystem::Complete => {
+            let add = |a: &FieldPoint<'f, N>, b: &FieldPoint<'f, N>| {
+                field_complete_add(coef_a, coef_b, a_minus_3, a, b)
+            };
+            let points = terms
+                .iter()
+                .map(|(p, _)| {
+                    (
+                        field.element(&p.x),
+                        field.element(&p.y),
+                        field.element(&p.z),
+                    )
+                })
+                .collect();
+            multi_mul(points, &scalars, neutral, add, |a| add(a, a), negate)
+        }
+        CoordinateSystem::Projective | CoordinateSystem::Jacobian => {
+            let add = |a: &FieldPoint<'f, N>, b: &FieldPoint<'f, N>| {
+                field_jacobian_add(coef_a, a_minus_3, a, b)
+            };
+            let double = |a: &FieldPoint<'f, N>| field_jacobian_double(coef_a, a_minus_3, a);
+            // (X : Y : Z) => (XZ : YZ^2 : Z) and back (XZ : Y : Z^3)
+            let points = terms
+                .iter()
+                .map(|(p, _)| {
+                    let z = field.element(&p.z);
+                    (field.element(&p.x) * z, field.element(&p.y) * z.square(), z)
+                })
+                .collect();
+            let r = multi_mul(points, &scalars, neutral, add, double, negate);
+            (r.0 * r.2, r.1, r.2.square() * r.2)
+        }
+    };
+    if z.is_zero() {
+        return EcPointP::neutral();
+    }
+    EcPointP {
+        x: x.to_bigint(),
+        y: y.to_bigint(),
+        z: z.to_bigint(),
+    }
+}
+
+// chooses the method by the number of points, add has to handle doubling and neutral element
+fn multi_mul<P: Clone>(
+    points: Vec<P>,
+    scalars: &[BigUint],
+    neutral: P,
+    add: impl Fn(&P, &P) -> P,
+    double: impl Fn(&P) -> P,
+    negate: impl Fn(&P) -> P,
+) -> P {
+    match points.len() < STRAUS_THRESHOLD {
+        true => straus(points, scalars, neutral, add, double, negate),
+        false => pippenger(points, scalars, neutral, add, double),
+    }
+}
+
+/// **straus** -- interleaved wNAF: all scalars share one chain of doublings,
+/// every point has its own table of odd multiples
+fn straus<P: Clone>(
+    points: Vec<P>,
+    scalars: &[BigUint],
+    neutral: P,
+    add: impl Fn(&P, &P) -> P,
+    double: impl Fn(&P) -> P,
+    negate: impl Fn(&P) -> P,
+) -> P {
+    let digits: Vec<Vec<i8>> = scalars
+        .iter()
+        .map(|k| to_wnaf(k, STRAUS_WNAF_WIDTH))
+        .collect();
+    let tables: Vec<Vec<P>> = points
+        .into_iter()
+        .map(|p| odd_multiples(p, Some(STRAUS_WNAF_WIDTH), &add, &double))
+        .collect();
+    let len = digits.iter().map(|d| d.len()).max().unwrap_or(0);
+
+    let mut r = neutral;
+    for i in (0..len).rev() {
+        r = double(&r);
+        for (d, table) in digits.iter().zip(tables.iter()) {
+            match d.get(i).copied().unwrap_or(0) {
+                d if d > 0 => r = add(&r, &table[(d / 2) as usize]),
+                d if d < 0 => r = add(&r, &negate(&table[(-d / 2) as usize])),
+                _ => {}
+            }
+        }
+    }
+    r
+}
+
+/// **pippenger** -- bucket method: for every c-bit window points are put into buckets by digit,
+/// then sum(d * B_d) is computed with running sums, so every point costs one addition per window
+fn pippenger<P: Clone>(
+    points: Vec<P>,
+    scalars: &[BigUint],
+    neutral: P,
+    add: impl Fn(&P, &P) -> P,
+    double: impl Fn(&P) -> P,
+) -> P {
+    // c ≈ ln(n) is close to optimal
+    let c = ((points.len().ilog2() as u64 * 69) / 100 + 2) as usize;
+    let bits = scalars.iter().map(|k| k.bits()).max().unwrap_or(0);
+    let windows = bits.div_ceil(c as u64);
+    let mask = BigUint::from((1_u64 << c) - 1);
+
+    let mut r = neutral.clone();
+    for w in (0..windows).rev() {
+        for _ in 0..c {
+            r = double(&r);
+        }
+        let mut buckets = vec![neutral.clone(); (1 << c) - 1];
+        for (p, k) in points.iter().zip(scalars.iter()) {
+            let d = ((k >> (w as usize * c)) & &mask)
+                .iter_u64_digits()
+                .next()
+                .unwrap_or(0);
+            if d != 0 {
+                buckets[d as usize - 1] = add(&buckets[d as usize - 1], p);
+            }
+        }
+        // sum(d * B_d) = B_m + (B_m + B_{m-1}) + ... + (B_m + ... + B_1)
+        let (mut running, mut sum) = (neutral.clone(), neutral.clone());
+        for b in buckets.iter().rev() {
+            running = add(&running, b);
+            sum = add(&sum, &running);
+        }
+        r = add(&r, &sum);
+    }
+    r
+}
@@ -0,0 +1,418 @@
+use crate::affine_point::EcPointA;
+use crate::extension_field::{ExtensionTower, Fp12, Fp2};
+use crate::projective_point::EcPointP;
+use crate::twist_point::EcPointTwist;
+use crate::{ECurve, EcError, EcInfo, Params};
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::{Num, One, Signed, Zero};
+
+/// **TwistType** -- kind of sextic twist E' of E: y^2 = x^3 + b, w^6 = xi
+#[derive(PartialOrd, PartialEq, Clone, Copy, Debug)]
+pub enum TwistType {
+    /// E': y^2 = x^3 + b/xi, psi(x, y) = (x * w^2, y * w^3)
+    D,
+    /// E': y^2 = x^3 + b * xi, psi(x, y) = (x / w^2, y / w^3)
+    M,
+}
+
+/// **TwistedCurve** -- sextic twist E'(GF(p^2)): y^2 = x^3 + b, where G2 lives
+#[derive(Clone, PartialEq, Debug)]
+pub struct TwistedCurve {
+    b: Fp2,
+    twist: TwistType,
+    tower: ExtensionTower,
+    /// **psi_x**, **psi_y** -- coefficients of untwist-Frobenius-twist endomorphism,
+    /// psi(x, y) = (conj(x) * psi_x, conj(y) * psi_y)
+    psi_x: Fp2,
+    psi_y: Fp2,
+}
+
+/// **PairingFamily** -- family of pairing-friendly curves with embedding degree 12,
+/// it defines the loop of Miller's algorithm for optimal ate pairing
+#[derive(PartialOrd, PartialEq, Clone, Debug)]
+pub enum PairingFamily {
+    /// Barreto-Naehrig curves, p = 36u^4 + 36u^3 + 24u^2 + 6u + 1, loop length is 6u + 2
+    Bn { u: BigInt },
+    /// Barreto-Lynn-Scott curves, r = x^4 - x^2 + 1, loop length is x
+    Bls12 { x: BigInt },
+}
+
+/// **PairingInfo** -- parameters of optimal ate pairing e: G1 x G2 -> GT,
+/// G1 is the subgroup of E(GF(p)) described by `EcInfo`, G2 is the subgroup of order n
+/// of the twist E'(GF(p^2)), GT is the subgroup of n-th roots of unity in GF(p^12)
+#[derive(Clone, PartialEq, Debug)]
+pub struct PairingInfo {
+    pub g1: EcInfo,
+    /// **g2** -- generator of G2
+    pub g2: EcPointTwist,
+    pub twist: TwistedCurve,
+    pub family: PairingFamily,
+    /// **hard_exponent** -- (p^4 - p^2 + 1) / n
+    hard_exponent: BigInt,
+}
+
+#[derive(Debug)]
+pub enum PreGeneratedPairingParams {
+    /// alt_bn128 from EIP-196/197
+    Bn254,
+    /// https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-pairing-friendly-curves-11#section-4.2.1
+    Bls12_381,
+}
+
+impl TwistedCurve {
+    pub fn new(b: &Fp2, twist: TwistType, tower: &ExtensionTower) -> crate::Result<Self> {
+        let b = tower.fp2_add(b, &Fp2::zero());
+        if b.is_zero() {
+            return Err(EcError::IncorrectParameters(
+                "b of the twist has to be non-zero".to_string(),
+            ));
+        }
+        let (gamma_2, gamma_3) = (tower.get_frobenius_coeff(2), tower.get_frobenius_coeff(3));
+        let (psi_x, psi_y) = match twist {
+            TwistType::D => (gamma_2, gamma_3),
+            TwistType::M => (tower.fp2_inverse(&gamma_2)?, tower.fp2_inverse(&gamma_3)?),
+        };
+        Ok(TwistedCurve {
+            b,
+            twist,
+            tower: tower.clone(),
+            psi_x,
+            psi_y,
+        })
+    }
+
+    pub fn get_b(&self) -> Fp2 {
+        self.b.clone()
+    }
+    pub fn get_twist(&self) -> TwistType {
+        self.twist
+    }
+    pub fn get_tower(&self) -> &ExtensionTower {
+        &self.tower
+    }
+
+    pub fn check_point(&self, q: &EcPointTwist) -> bool {
+        if q.is_inf() {
+            return true;
+        }
+        let t = &self.tower;
+        let lhs = t.fp2_square(&q.y);
+        let rhs = t.fp2_add(&t.fp2_mul(&t.fp2_square(&q.x), &q.x), &self.b);
+        lhs == rhs
+    }
+
+    pub fn point_negative(&self, q: &EcPointTwist) -> EcPointTwist {
+        EcPointTwist {
+            x: q.x.clone(),
+            y: self.tower.fp2_neg(&q.y),
+            is_inf: q.is_inf,
+        }
+    }
+
+    pub fn point_add(&self, a: &EcPointTwist, b: &EcPointTwist) -> crate::Result<EcPointTwist> {
+        match self.slope(a, b)? {
+            Some(lambda) => Ok(self.chord_point(a, b, &lambda)),
+            None if a.is_inf() => Ok(b.clone()),
+            None if b.is_inf() => Ok(a.clone()),
+            None => Ok(EcPointTwist::neutral()),
+        }
+    }
+
+    pub fn point_double(&self, a: &EcPointTwist) -> crate::Result<EcPointTwist> {
+        self.point_add(a, a)
+    }
+
+    pub fn point_mul(&self, a: &EcPointTwist, k: &BigUint) -> crate::Result<EcPointTwist> {
+        let mut r = EcPointTwist::neutral();
+        for i in (0..k.bits()).rev() {
+            r = self.point_double(&r)?;
+            if k.bit(i) {
+                r = self.point_add(&r, a)?;
+            }
+        }
+        Ok(r)
+    }
+
+    /// **frobenius** -- psi^(-1) o pi_p o psi, acts on G2 as multiplication by p
+    pub fn frobenius(&self, q: &EcPointTwist) -> EcPointTwist {
+        if q.is_inf() {
+            return q.clone();
+        }
+        let t = &self.tower;
+        EcPointTwist {
+            x: t.fp2_mul(&t.fp2_conjugate(&q.x), &self.psi_x),
+            y: t.fp2_mul(&t.fp2_conjugate(&q.y), &self.psi_y),
+            is_inf: false,
+        }
+    }
+
+    // slope of the line through a and b (tangent if a = b), None if the line is vertical
+    // or one of the points is O
+    fn slope(&self, a: &EcPointTwist, b: &EcPointTwist) -> crate::Result<Option<Fp2>> {
+        let t = &self.tower;
+        if a.is_inf() || b.is_inf() {
+            return Ok(None);
+        }
+        if a.x != b.x {
+            let dy = t.fp2_sub(&b.y, &a.y);
+            let dx = t.fp2_sub(&b.x, &a.x);
+            return Ok(Some(t.fp2_mul(&dy, &t.fp2_inverse(&dx)?)));
+        }
+        if a.y != b.y || a.y.is_zero() {
+            return Ok(None);
+        }
+        // 3x^2 / 2y
+        let x2 = t.fp2_square(&a.x);
+        let num = t.fp2_add(&t.fp2_add(&x2, &x2), &x2);
+        Ok(Some(
+            t.fp2_mul(&num, &t.fp2_inverse(&t.fp2_add(&a.y, &a.y))?),
+        ))
+    }
+
+    fn chord_point(&self, a: &EcPointTwist, b: &EcPointTwist, lambda: &Fp2) -> EcPointTwist {
+        let t = &self.tower;
+        let x = t.fp2_sub(&t.fp2_sub(&t.fp2_square(lambda), &a.x), &b.x);
+        let y = t.fp2_sub(&t.fp2_mul(lambda, &t.fp2_sub(&a.x, &x)), &a.y);
+        EcPointTwist::new(&x, &y)
+    }
+
+    // value of the line through psi(a) and psi(b) at point p of E(GF(p)), up to factors
+    // from proper subfields of GF(p^12), which are killed by final exponentiation.
+    // Returns the value and a + b
+    fn line(
+        &self,
+        a: &EcPointTwist,
+        b: &EcPointTwist,
+        p: &EcPointA,
+    ) -> crate::Result<(Fp12, EcPointTwist)> {
+        let t = &self.tower;
+        let (xp, yp) = (
+            Fp2::new(&p.x, &BigInt::zero()),
+            Fp2::new(&p.y, &BigInt::zero()),
+        );
+        let zero = Fp2::zero();
+        let lambda = match self.slope(a, b)? {
+            Some(lambda) => lambda,
+            None if a.is_inf() || b.is_inf() => return Ok((Fp12::one(), self.point_add(a, b)?)),
+            // vertical line x_p - x_a * w^2 (D-type) or x_p * w^2 - x_a (M-type)
+            None => {
+                let x_neg = t.fp2_neg(&a.x);
+                let g = match self.twist {
+                    TwistType::D => [xp, zero.clone(), x_neg, zero.clone(), zero.clone(), zero],
+                    TwistType::M => [x_neg, zero.clone(), xp, zero.clone(), zero.clone(), zero],
+                };
+                return Ok((Fp12::from_powers_of_w(g), EcPointTwist::neutral()));
+            }
+        };
+        // y_p - y - lambda(x_p - x) with lambda_E = lambda * w for D-type, lambda / w for M-type,
+        // the latter is multiplied by w^3
+        let c = t.fp2_sub(&t.fp2_mul(&lambda, &a.x), &a.y);
+        let l = t.fp2_neg(&t.fp2_mul(&lambda, &xp));
+        let g = match self.twist {
+            TwistType::D => [yp, l, zero.clone(), c, zero.clone(), zero],
+            TwistType::M => [c, zero.clone(), l, yp, zero.clone(), zero],
+        };
+        Ok((Fp12::from_powers_of_w(g), self.chord_point(a, b, &lambda)))
+    }
+}
+
+impl From<PreGeneratedPairingParams> for PairingInfo {
+    fn from(value: PreGeneratedPairingParams) -> Self {
+        PairingInfo::from(&value)
+    }
+}
+
+impl From<&PreGeneratedPairingParams> for PairingInfo {
+    fn from(value: &PreGeneratedPairingParams) -> Self {
+        let parse_hex = |x: &str| BigInt::from_str_radix(x, 16).unwrap();
+        let (p, n, h, b, xi, twist, g1, g2, family) = match value {
+            PreGeneratedPairingParams::Bn254 => (
+                "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47",
+                "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001",
+                "1",
+                3,
+                9,
+                TwistType::D,
+                ["1", "2"],
+                [
+                    "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+                    "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+                    "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+                    "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+                ],
+                PairingFamily::Bn {
+                    u: parse_hex("44e992b44a6909f1"),
+                },
+            ),
+            PreGeneratedPairingParams::Bls12_381 => (
+                "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab",
+                "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+                "396c8c005555e1568c00aaab0000aaab",
+                4,
+                1,
+                TwistType::M,
+                [
+                    "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb",
+                    "08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1",
+                ],
+                [
+                    "024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8",
+                    "13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e",
+                    "0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801",
+                    "0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be",
+                ],
+                PairingFamily::Bls12 {
+                    x: -parse_hex("d201000000010000"),
+                },
+            ),
+        };
+        let p = parse_hex(p);
+        let b = BigInt::from(b);
+        let xi = Fp2::new(&BigInt::from(xi), &BigInt::one());
+        let tower = ExtensionTower::new(&p, &xi);
+        let twist_b = match twist {
+            TwistType::D => tower.fp2_mul_by_fp(&tower.fp2_inverse(&xi).unwrap(), &b),
+            TwistType::M => tower.fp2_mul_by_fp(&xi, &b),
+        };
+        let ecurve = ECurve::new(Params {
+            a: BigInt::zero(),
+            b,
+            q: p,
+        })
+        .unwrap();
+        let g1 = EcInfo::new(
+            EcPointP::from_affine(&EcPointA::new(&parse_hex(g1[0]), &parse_hex(g1[1]))),
+            BigUint::from_str_radix(n, 16).unwrap(),
+            BigUint::from_str_radix(h, 16).unwrap(),
+            ecurve,
+        )
+        .unwrap();
+        let g2 = EcPointTwist::new(
+            &Fp2::new(&parse_hex(g2[0]), &parse_hex(g2[1])),
+            &Fp2::new(&parse_hex(g2[2]), &parse_hex(g2[3])),
+        );
+        PairingInfo::new(
+            g1,
+            g2,
+            TwistedCurve::new(&twist_b, twist, &tower).unwrap(),
+            family,
+        )
+        .unwrap()
+    }
+}
+
+impl PairingInfo {
+    /// **new** -- checks that generator of G2 lies on the twist and has order n of G1
+    pub fn new(
+        g1: EcInfo,
+        g2: EcPointTwist,
+        twist: TwistedCurve,
+        family: PairingFamily,
+    ) -> crate::Result<Self> {
+        if g2.is_inf() || !twist.check_point(&g2) {
+            return Err(EcError::IncorrectBasePoint(format!(
+                "({g2}) doesn't lie on the twist"
+            )));
+        }
+        if !twist.point_mul(&g2, &g1.n)?.is_inf() {
+            return Err(EcError::IncorrectBasePoint(format!(
+                "n * ({g2}) != O, n: {}",
+                g1.n
+            )));
+        }
+        let p = twist.get_tower().get_p();
+        let p2 = &p * &p;
+        let n = BigInt::from_biguint(Sign::Plus, g1.n.clone());
+        let cyclotomic = &p2 * &p2 - &p2 + BigInt::one();
+        if !(&cyclotomic % &n).is_zero() {
+            return Err(EcError::IncorrectParameters(format!(
+                "embedding degree of the curve isn't 12 for n: {n}"
+            )));
+        }
+        Ok(PairingInfo {
+            g1,
+            g2,
+            twist,
+            family,
+            hard_exponent: cyclotomic / n,
+        })
+    }
+
+    /// **g2_mul** -- k * g2
+    pub fn g2_mul(&self, k: &BigUint) -> crate::Result<EcPointTwist> {
+        self.twist.point_mul(&self.g2, k)
+    }
+
+    /// **is_in_g2** -- Q lies on the twist and n * Q = O
+    pub fn is_in_g2(&self, q: &EcPointTwist) -> bool {
+        self.twist.check_point(q)
+            && self
+                .twist
+                .point_mul(q, &self.g1.n)
+                .is_ok_and(|r| r.is_inf())
+    }
+
+    /// **pairing** -- optimal ate pairing e(P, Q), P in G1, Q in G2
+    pub fn pairing(&self, p: &EcPointA, q: &EcPointTwist) -> crate::Result<Fp12> {
+        if !self.g1.is_in_subgroup(&p.to_projective()) {
+            return Err(EcError::PointNotOnCurve(format!(
+                "({p:?}) doesn't lie in G1"
+            )));
+        }
+        if !self.is_in_g2(q) {
+            return Err(EcError::PointNotOnCurve(format!("({q}) doesn't lie in G2")));
+        }
+        let f = self.miller_loop(p, q)?;
+        self.final_exponentiation(&f)
+    }
+
+    /// **miller_loop** -- f_{6u+2,Q}(P) * l_{T,pi(Q)}(P) * l_{T,-pi^2(Q)}(P) for BN curves
+    /// and f_{x,Q}(P) for BLS12 curves, vertical lines are omitted
+    pub fn miller_loop(&self, p: &EcPointA, q: &EcPointTwist) -> crate::Result<Fp12> {
+        if p.is_inf() || q.is_inf() {
+            return Ok(Fp12::one());
+        }
+        let tower = self.twist.get_tower();
+        let p = EcPointA::new(&tower.fp(&p.x), &tower.fp(&p.y));
+        let s = match &self.family {
+            PairingFamily::Bn { u } => 6 * u + 2,
+            PairingFamily::Bls12 { x } => x.clone(),
+        };
+        let s_abs = s.abs();
+        let mut f = Fp12::one();
+        let mut t = q.clone();
+        for i in (0..s_abs.bits() - 1).rev() {
+            let (l, t2) = self.twist.line(&t, &t, &p)?;
+            f = tower.fp12_mul(&tower.fp12_square(&f), &l);
+            t = t2;
+            if s_abs.bit(i) {
+                let (l, tq) = self.twist.line(&t, q, &p)?;
+                f = tower.fp12_mul(&f, &l);
+                t = tq;
+            }
+        }
+        // f_{-s,Q} = 1 / (f_{s,Q} * v_{sQ}), and 1/f = conj(f) after final exponentiation
+        if s.is_negative() {
+            f = tower.fp12_conjugate(&f);
+            t = self.twist.point_negative(&t);
+        }
+        if let PairingFamily::Bn { .. } = self.family {
+            let q1 = self.twist.frobenius(q);
+            let q2 = self.twist.point_negative(&self.twist.frobenius(&q1));
+            let (l, tq1) = self.twist.line(&t, &q1, &p)?;
+            f = tower.fp12_mul(&f, &l);
+            let (l, _) = self.twist.line(&tq1, &q2, &p)?;
+            f = tower.fp12_mul(&f, &l);
+        }
+        Ok(f)
+    }
+
+    /// **final_exponentiation** -- f^((p^12 - 1) / n), easy part (p^6 - 1)(p^2 + 1) uses
+    /// conjugation and Frobenius map, hard part (p^4 - p^2 + 1) / n is a plain exponentiation
+    pub fn final_exponentiation(&self, f: &Fp12) -> crate::Result<Fp12> {
+        let tower = self.twist.get_tower();
+        let f = tower.fp12_mul(&tower.fp12_conjugate(f), &tower.fp12_inverse(f)?);
+        let f = tower.fp12_mul(&tower.fp12_frobenius(&f, 2), &f);
+        Ok(tower.fp12_pow(&f, &self.hard_exponent))
+    }
+}
@@ -0,0 +1,1145 @@
+use crate::affine_point::EcPointA;
+use crate::field::{FieldElement, PrimeField};
+use crate::helpers::{take_by_bigint_module, with_limbs};
+use crate::modular_polynomial::{ModularPolynomial, ModularPolynomials};
+use crate::polynomial::{Poly, PolyModulus};
+use crate::projective_point::EcPointP;
+use crate::sqrt::jacobi_symbol;
+use crate::{ECurve, EcError, Params};
+use num_bigint::{BigInt, BigUint, Sign};
+use num_traits::{One, ToPrimitive, Zero};
+use std::collections::HashMap;
+
+/// **NAIVE_BITS** -- points of curves over such small fields are counted one by one
+pub const NAIVE_BITS: u64 = 16;
+/// **BSGS_BITS** -- up to this size of q the order is found with baby-step giant-step only,
+/// that takes O(q^{1/4}) point operations
+pub const BSGS_BITS: u64 = 64;
+/// **SEA_BSGS_BITS** -- SEA stops, when the rest of candidates for t are checked
+/// with match-and-sort in about 2^SEA_BSGS_BITS point operations
+const SEA_BSGS_BITS: u64 = 17;
+/// **SCHOOF_BSGS_BITS** -- Schoof's algorithm stops, when there're less than
+/// min(q^{1/4}, 2^SCHOOF_BSGS_BITS) candidates for t, the rest are checked with baby-step giant-step
+const SCHOOF_BSGS_BITS: u64 = 36;
+/// **MAX_CANDIDATES** -- points of small order give too many candidates, so they are skipped
+const MAX_CANDIDATES: usize = 64;
+/// **MAX_POINTS** -- number of points of the curve and its twist, that are tried
+const MAX_POINTS: usize = 64;
+
+/// **order** -- number of points #E(GF(q)) including O, method is chosen by size of q:
+/// naive counting, baby-step giant-step or Schoof-Elkies-Atkin algorithm
+pub fn order(ec: &ECurve) -> crate::Result<BigUint> {
+    match ec.q.bits() {
+        bits if bits <= NAIVE_BITS => naive_order(ec),
+        bits if bits <= BSGS_BITS => bsgs_order(ec),
+        _ => sea_order(ec),
+    }
+}
+
+/// **naive_order** -- #E = q + 1 + sum((x^3 + ax + b) / q) over all x, takes O(q) operations
+pub fn naive_order(ec: &ECurve) -> crate::Result<BigUint> {
+    let q = &ec.q;
+    let mut n = q + BigInt::one();
+    let mut x = BigInt::zero();
+    while &x < q {
+        n += jacobi_symbol(&(&x * &x * &x + &ec.a * &x + &ec.b), q).ok_or_else(|| odd_q(q))?;
+        x += 1;
+    }
+    Ok(n.to_biguint().unwrap())
+}
+
+/// **bsgs_order** -- finds the only N in Hasse interval [q + 1 - 2sqrt(q), q + 1 + 2sqrt(q)],
+/// such that N * P = O for points P of the curve, with baby-step giant-step.
+/// If the group has small exponent, points of the quadratic twist are used (Mestre's method)
+pub fn bsgs_order(ec: &ECurve) -> crate::Result<BigUint> {
+    order_by_trace(ec, &BigUint::zero(), &BigUint::one())
+}
+
+/// **schoof_order** -- Schoof's algorithm: t = q + 1 - #E is found modulo small primes l
+/// from the action of Frobenius on l-torsion, then by CRT and the rest of candidates
+/// are checked with baby-step giant-step. Polynomial arithmetic takes O(l^2 log(l) log(q))
+/// field operations per l, so curves over 160-256-bit fields take minutes, `sea_order` is
+/// used for them instead
+pub fn schoof_order(ec: &ECurve) -> crate::Result<BigUint> {
+    let q = ec.q.to_biguint().unwrap();
+    let bound = (&q << 4_u8).sqrt() >> (q.bits() / 4).min(SCHOOF_BSGS_BITS);
+    let (mut t, mut m) = (BigUint::zero(), BigUint::one());
+    let mut l = 2_u64;
+    while m <= bound {
+        if BigUint::from(l) != q {
+            // t = t0 + m * ((t_l - t0) / m mod l)
+            let t_l = trace_mod_l(ec, l)?;
+            let t_mod_l = (&t % l).to_u64().unwrap();
+            let m_inv = (&m % l).modpow(&BigUint::from(l - 2), &BigUint::from(l));
+            let k = ((t_l + l - t_mod_l) % l) * m_inv.to_u64().unwrap() % l;
+            t += &m * k;
+            m *= l;
+        }
+        l = next_prime(l);
+    }
+    order_by_trace(ec, &t, &m)
+}
+
+/// **sea_order** -- Schoof-Elkies-Atkin algorithm. For a prime l modular polynomial G_l(X, j(E))
+/// has roots in GF(q) iff Frobenius has an eigenvalue on E[l] (Elkies prime l). Then a root
+/// gives l-isogeny, its kernel polynomial of degree (l - 1) / 2 replaces division polynomial
+/// of degree (l^2 - 1) / 2 in Schoof's step and t mod l is found from the eigenvalue.
+/// For other (Atkin) primes degree of irreducible factors of G_l(X, j(E)) leaves a few
+/// candidates for t mod l. Primes are taken from the cheapest per bit of t, until there're less
+/// than about 2^(2 SEA_BSGS_BITS) candidates, they're checked with match-and-sort baby-step
+/// giant-step. NIST P-256 takes about 15 seconds.
+/// Curves with j = 0 or j = 1728 are counted with `schoof_order`, since formulas for the isogeny
+/// divide by a and b
+pub fn sea_order(ec: &ECurve) -> crate::Result<BigUint> {
+    with_limbs!(ec.q, field_sea_order(ec)).unwrap_or_else(|| {
+        Err(EcError::IncorrectParameters(format!(
+            "modulus {} doesn't fit into fixed-width field elements",
+            ec.q
+        )))
+    })
+}
+
+/// **sea_in_field** -- `sea_order` with modular polynomials, that are shared between calls
+/// for curves over the same field
+pub(crate) fn sea_in_field<const N: usize>(
+    ec: &ECurve,
+    polys: &mut ModularPolynomials<'_, N>,
+) -> crate::Result<BigUint> {
+    let field = polys.field();
+    let (a, b) = (field.element(&ec.a), field.element(&ec.b));
+    if a.is_zero() || b.is_zero() {
+        return schoof_order(ec);
+    }
+    let q = ec.q.to_biguint().unwrap();
+    // j = 1728 * 4a^3 / (4a^3 + 27b^2)
+    let a3 = field.from_u64(4) * a * a * a;
+    let j = field.from_u64(1728) * a3 * (a3 + field.from_u64(27) * b * b).inverse();
+
+    let (mut t, mut m) = (
+        BigUint::from(trace_in_field(field, ec, 2)?),
+        BigUint::from(2_u8),
+    );
+    let mut atkin = vec![];
+    for l in sea_primes(&q) {
+        let count = ((&q << 4_u8).sqrt() / &m + 1_u8).bits() as f64;
+        if select_atkin(count, &atkin).1 <= 2.0 * SEA_BSGS_BITS as f64 {
+            break;
+        }
+        match sea_step(ec, polys, l, j)? {
+            Some(SeaStep::Elkies(t_l)) => {
+                let t_mod_l = (&t % l).to_u64().unwrap();
+                let m_inv = (&m % l).modpow(&BigUint::from(l - 2), &BigUint::from(l));
+                let k = ((t_l + l - t_mod_l) % l) * m_inv.to_u64().unwrap() % l;
+                t += &m * k;
+                m *= l;
+            }
+            Some(SeaStep::Atkin(candidates)) => atkin.push((l, candidates)),
+            None => {}
+        }
+    }
+    match_and_sort(ec, &t, &m, &atkin)
+}
+
+/// **trace_mod_l** -- step of Schoof's algorithm: t mod l for prime l != q,
+/// where t = q + 1 - #E is trace of Frobenius endomorphism
+pub fn trace_mod_l(ec: &ECurve, l: u64) -> crate::Result<u64> {
+    if BigUint::from(l) == ec.q.to_biguint().unwrap() {
+        return Err(EcError::IncorrectParameters(format!(
+            "l has to differ from characteristic, got l = {l}"
+        )));
+    }
+    with_limbs!(ec.q, field_trace_mod_l(ec, l)).unwrap_or_else(|| {
+        Err(EcError::IncorrectParameters(format!(
+            "modulus {} doesn't fit into fixed-width field elements",
+            ec.q
+        )))
+    })
+}
+
+/// **quadratic_twist** -- curve y^2 = x^3 + ad^2 x + bd^3 for quadratic non-residue d,
+/// its order is 2q + 2 - #E
+pub fn quadratic_twist(ec: &ECurve) -> crate::Result<ECurve> {
+    let q = &ec.q;
+    let mut d = BigInt::from(2_u8);
+    while jacobi_symbol(&d, q).ok_or_else(|| odd_q(q))? != -1 {
+        d += 1;
+    }
+    let d2 = (&d * &d) % q;
+    ECurve::new(Params {
+        a: take_by_bigint_module(&((&ec.a * &d2) % q), q),
+        b: take_by_bigint_module(&((&ec.b * &d2 * &d) % q), q),
+        q: q.clone(),
+    })
+}
+
+// odd primes l != q for SEA in order of cost per bit of t, computation of G_l takes about
+// l^2.5 v^1.5 and Elkies or Atkin step about l^2 log q operations, v = (l - 1) s / 12
+fn sea_primes(q: &BigUint) -> Vec<u64> {
+    let bits = q.bits() as f64;
+    let cost = |l: u64| {
+        let v = (l - 1) / gcd(12, l - 1);
+        let l = l as f64;
+        (l.powf(2.5) * (v as f64).powf(1.5) + 2.0 * l * l * bits) / l.log2()
+    };
+    let mut primes: Vec<u64> = (3..64.max(2 * q.bits()))
+        .filter(|l| next_prime(l - 1) == *l && BigUint::from(*l) != *q)
+        .collect();
+    primes.sort_by(|a, b| cost(*a).total_cmp(&cost(*b)));
+    primes
+}
+
+fn odd_q(q: &BigInt) -> EcError {
+    EcError::IncorrectParameters(format!("q has to be an odd prime, got {q}"))
+}
+
+fn next_prime(n: u64) -> u64 {
+    (n + 1..)
+        .find(|k| (2..).take_while(|d| d * d <= *k).all(|d| k % d != 0))
+        .unwrap()
+}
+
+// N = q + 1 - t, t = t0 mod m, is searched in Hasse interval with points of E and its twist
+fn order_by_trace(ec: &ECurve, t0: &BigUint, m: &BigUint) -> crate::Result<BigUint> {
+    let err = || {
+        Err(EcError::IncorrectParameters(format!(
+            "can't determine order of the curve over GF({})",
+            ec.q
+        )))
+    };
+    let q = ec.q.to_biguint().unwrap();
+    let bound = (&q << 2_u8).sqrt();
+    let (low, high) = (&q + 1_u8 - &bound, &q + 1_u8 + &bound);
+    let residue = (&q + 1_u8 + m - t0 % m) % m;
+    let start = &low + (&residue + m - &low % m) % m;
+    if start > high {
+        return err();
+    }
+    let count = match ((&high - &start) / m + 1_u8).to_u64() {
+        Some(count) => count,
+        None => return err(),
+    };
+    if count == 1 {
+        return Ok(start);
+    }
+
+    // N * P = O for P on E and (2q + 2 - N) * P' = O for P' on twist
+    let twist = quadratic_twist(ec)?;
+    let twist_start = (&q << 1_u8) + 2_u8 - &start - m * (count - 1);
+    let mut points = [PointSource::new(ec), PointSource::new(&twist)];
+    let mut candidates: Option<Vec<u64>> = None;
+    for k in 0..MAX_POINTS {
+        let source = &mut points[k % 2];
+        let p = match source.next() {
+            Some(p) => p,
+            None => continue,
+        };
+        let indices = match k % 2 {
+            0 => multiples_to_zero(ec, &p, &start, m, count)?,
+            _ => multiples_to_zero(&twist, &p, &twist_start, m, count)?
+                .map(|v| v.iter().rev().map(|i| count - 1 - i).collect()),
+        };
+        let indices = match indices {
+            Some(indices) => indices,
+            None => continue,
+        };
+        let c = match candidates {
+            Some(c) => c.into_iter().filter(|i| indices.contains(i)).collect(),
+            None => indices,
+        };
+        match c.len() {
+            0 => return err(),
+            1 => return Ok(&start + m * c[0]),
+            _ => candidates = Some(c),
+        }
+    }
+    err()
+}
+
+// points (x, y) with even y for x = 0, 1, 2, ...
+struct PointSource<'a> {
+    ec: &'a ECurve,
+    x: BigInt,
+}
+
+impl<'a> PointSource<'a> {
+    fn new(ec: &'a ECurve) -> Self {
+        PointSource {
+            ec,
+            x: BigInt::zero(),
+        }
+    }
+
+    fn next(&mut self) -> Option<EcPointP> {
+        while self.x < self.ec.q {
+            let p = self.ec.lift_x(&self.x, false);
+            self.x += 1;
+            if let Ok(p) = p {
+                return Some(EcPointP::from_affine(&p));
+            }
+        }
+        None
+    }
+}
+
+// all i < count, such that (start + i * step) * P = O, None if there're too many of them
+fn multiples_to_zero(
+    ec: &ECurve,
+    p: &EcPointP,
+    start: &BigUint,
+    step: &BigUint,
+    count: u64,
+) -> crate::Result<Option<Vec<u64>>> {
+    let s = ec.proj_point_mul(p, step);
+    let base = ec.proj_point_mul(p, start);
+    let key = |a: &EcPointA| (a.get_x(), a.get_y());
+    let m = (count as f64).sqrt().ceil() as u64;
+
+    // baby steps j * S
+    let mut baby = Vec::with_capacity(m as usize);
+    let mut cur = EcPointP::neutral();
+    for _ in 0..m {
+        baby.push(cur.clone());
+        cur = ec.proj_point_add(&cur, &s);
+    }
+    let baby = ec.batch_to_affine(&baby)?;
+    let minus_base = ec.take_by_module(&base.negative()).to_affine(ec)?;
+
+    // S has order r < m, so (start + i * step) * P depends only on i mod r
+    if let Some(r) = baby.iter().skip(1).position(|p| p.is_inf()) {
+        let r = r as u64 + 1;
+        let zeros: Vec<u64> = (0..r).filter(|j| baby[*j as usize] == minus_base).collect();
+        if zeros.len() as u64 * count.div_ceil(r) > MAX_CANDIDATES as u64 {
+            return Ok(None);
+        }
+        let mut res: Vec<u64> = zeros
+            .iter()
+            .flat_map(|z| (*z..count).step_by(r as usize))
+            .collect();
+        res.sort_unstable();
+        return Ok(Some(res));
+    }
+
+    // giant steps -(base + g * m * S) = j * S
+    let table: HashMap<(BigInt, BigInt), u64> = baby
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(j, p)| (key(p), j as u64))
+        .collect();
+    let giant = ec.take_by_module(&ec.proj_point_mul(&s, &BigUint::from(m)).negative());
+    let mut t = ec.take_by_module(&base.negative());
+    let mut res = vec![];
+    for g in 0..count.div_ceil(m) {
+        let t_affine = t.to_affine(ec)?;
+        let j = match t_affine.is_inf() {
+            true => Some(0),
+            false => table.get(&key(&t_affine)).copied(),
+        };
+        if let Some(j) = j {
+            let i = g * m + j;
+            if i < count {
+                res.push(i);
+            }
+            if res.len() > MAX_CANDIDATES {
+                return Ok(None);
+            }
+        }
+        t = ec.proj_point_add(&t, &giant);
+    }
+    Ok(Some(res))
+}
+
+fn field_trace_mod_l<const N: usize>(ec: &ECurve, l: u64) -> Option<crate::Result<u64>> {
+    let field = PrimeField::<N>::new(&ec.q).ok()?;
+    Some(trace_in_field(&field, ec, l))
+}
+
+fn trace_in_field<const N: usize>(
+    field: &PrimeField<N>,
+    ec: &ECurve,
+    l: u64,
+) -> crate::Result<u64> {
+    let q = ec.q.to_biguint().unwrap();
+    let (a, b) = (field.element(&ec.a), field.element(&ec.b));
+    let x = Poly::x(field);
+    // f = x^3 + ax + b
+    let f = Poly::new(field, vec![b, a, field.zero(), field.one()]);
+    if l == 2 {
+        // t is even iff there's a point of order 2, i.e. gcd(x^q - x, f) != 1
+        let xq = PolyModulus::new(&f).pow(&x, &q);
+        return Ok((xq.sub(&x).gcd(&f).degree() == Some(0)) as u64);
+    }
+
+    // x-coordinates of l-torsion points are roots of l-th division polynomial,
+    // when an inversion fails, computations are repeated modulo the found factor of it
+    let mut h = division_polynomial(field, a, b, l);
+    loop {
+        match frobenius_trace(&PolyModulus::new(&h), &f, a, &q, l) {
+            Ok(Some(t)) => return Ok(t),
+            Ok(None) => break,
+            Err(g) if g.degree().is_some_and(|d| d > 0 && d < h.degree().unwrap()) => h = g,
+            Err(_) => break,
+        }
+    }
+    Err(EcError::IncorrectParameters(format!(
+        "trace mod {l} isn't found, modulus {} has to be prime",
+        ec.q
+    )))
+}
+
+// g_n: psi_n = g_n for odd n and psi_n = 2y * g_n for even n, (2y)^2 = 4f
+fn division_polynomial<'f, const N: usize>(
+    field: &'f PrimeField<N>,
+    a: FieldElement<'f, N>,
+    b: FieldElement<'f, N>,
+    l: u64,
+) -> Poly<'f, N> {
+    let c = |x: u64| field.from_u64(x);
+    let f = Poly::new(field, vec![b, a, field.zero(), field.one()]);
+    let f2 = f.mul(&f).scale(c(16));
+    let g3 = Poly::new(
+        field,
+        vec![-(a * a), c(12) * b, c(6) * a, field.zero(), c(3)],
+    );
+    let g4 = Poly::new(
+        field,
+        vec![
+            -(c(8) * b * b) - a * a * a,
+            -(c(4) * a * b),
+            -(c(5) * a * a),
+            c(20) * b,
+            c(5) * a,
+            field.zero(),
+            field.one(),
+        ],
+    )
+    .scale(c(2));
+    let one = Poly::constant(field.one());
+    let mut g = vec![Poly::zero(field), one.clone(), one, g3, g4];
+    for n in 5..=l as usize {
+        let m = n / 2;
+        let next = match (n % 2, m % 2) {
+            (1, 0) => f2
+                .mul(&g[m + 2])
+                .mul(&cube(&g[m]))
+                .sub(&g[m - 1].mul(&cube(&g[m + 1]))),
+            (1, _) => g[m + 2]
+                .mul(&cube(&g[m]))
+                .sub(&f2.mul(&g[m - 1]).mul(&cube(&g[m + 1]))),
+            _ => g[m].mul(
+                &g[m + 2]
+                    .mul(&g[m - 1].mul(&g[m - 1]))
+                    .sub(&g[m - 2].mul(&g[m + 1].mul(&g[m + 1]))),
+            ),
+        };
+        g.push(next);
+    }
+    g.swap_remove(l as usize)
+}
+
+fn cube<'f, const N: usize>(p: &Poly<'f, N>) -> Poly<'f, N> {
+    p.mul(p).mul(p)
+}
+
+// point (X(x), Y(x) * y) of E over GF(q)[x] / (h), y^2 = f
+#[derive(Clone)]
+enum RingPoint<'f, const N: usize> {
+    Inf,
+    Affine(Poly<'f, N>, Poly<'f, N>),
+}
+
+struct RingCurve<'r, 'f, const N: usize> {
+    ring: &'r PolyModulus<'f, N>,
+    f: Poly<'f, N>,
+    a: FieldElement<'f, N>,
+}
+
+impl<'r, 'f, const N: usize> RingCurve<'r, 'f, N> {
+    fn add(
+        &self,
+        p: &RingPoint<'f, N>,
+        r: &RingPoint<'f, N>,
+    ) -> Result<RingPoint<'f, N>, Poly<'f, N>> {
+        let ((x1, y1), (x2, y2)) = match (p, r) {
+            (RingPoint::Inf, _) => return Ok(r.clone()),
+            (_, RingPoint::Inf) => return Ok(p.clone()),
+            (RingPoint::Affine(x1, y1), RingPoint::Affine(x2, y2)) => ((x1, y1), (x2, y2)),
+        };
+        if x1 == x2 {
+            return match (y1 == y2, y1.add(y2).is_zero()) {
+                (true, _) => self.double(p),
+                (_, true) => Ok(RingPoint::Inf),
+                // P1 = P2 for some points and P1 = -P2 for others
+                _ => Err(y1.sub(y2).gcd(self.ring.modulus())),
+            };
+        }
+        // lambda = L * y, L = (Y1 - Y2) / (X1 - X2)
+        let lambda = self.ring.mul(&y1.sub(y2), &self.ring.inverse(&x1.sub(x2))?);
+        Ok(self.chord(&lambda, x1, y1, x2))
+    }
+
+    fn double(&self, p: &RingPoint<'f, N>) -> Result<RingPoint<'f, N>, Poly<'f, N>> {
+        let (x, y) = match p {
+            RingPoint::Inf => return Ok(RingPoint::Inf),
+            RingPoint::Affine(_, y) if y.is_zero() => return Ok(RingPoint::Inf),
+            RingPoint::Affine(x, y) => (x, y),
+        };
+        // lambda = L * y, L = (3X^2 + a) / (2Yf)
+        let field = self.ring.modulus().field();
+        let numerator = self
+            .ring
+            .square(x)
+            .scale(field.from_u64(3))
+            .add(&Poly::constant(self.a));
+        let denominator = self.ring.mul(y, &self.f).scale(field.from_u64(2));
+        let lambda = self.ring.mul(&numerator, &self.ring.inverse(&denominator)?);
+        Ok(self.chord(&lambda, x, y, x))
+    }
+
+    // X3 = L^2 f - X1 - X2, Y3 = L(X1 - X3) - Y1
+    fn chord(
+        &self,
+        lambda: &Poly<'f, N>,
+        x1: &Poly<'f, N>,
+        y1: &Poly<'f, N>,
+        x2: &Poly<'f, N>,
+    ) -> RingPoint<'f, N> {
+        let x3 = self
+            .ring
+            .mul(&self.ring.square(lambda), &self.f)
+            .sub(x1)
+            .sub(x2);
+        let y3 = self.ring.mul(lambda, &x1.sub(&x3)).sub(y1);
+        RingPoint::Affine(x3, y3)
+    }
+
+    fn mul(&self, p: &RingPoint<'f, N>, k: u64) -> Result<RingPoint<'f, N>, Poly<'f, N>> {
+        let mut r = RingPoint::Inf;
+        for i in (0..u64::BITS - k.leading_zeros()).rev() {
+            r = self.double(&r)?;
+            if (k >> i) & 1 == 1 {
+                r = self.add(&r, p)?;
+            }
+        }
+        Ok(r)
+    }
+}
+
+// finds t mod l from pi^2(P) + (q mod l) * P = t * pi(P) for generic point P = (x, y) of E[l],
+// error contains a proper factor of the modulus
+fn frobenius_trace<'f, const N: usize>(
+    ring: &PolyModulus<'f, N>,
+    f: &Poly<'f, N>,
+    a: FieldElement<'f, N>,
+    q: &BigUint,
+    l: u64,
+) -> Result<Option<u64>, Poly<'f, N>> {
+    let field = ring.modulus().field();
+    let x = ring.reduce(&Poly::x(field));
+    let f = ring.reduce(f);
+    // pi(P) = (x^q, f^((q - 1) / 2) * y), pi^2(P) = (x^(q^2), f^((q^2 - 1) / 2) * y)
+    let x1 = ring.pow(&x, q);
+    let y1 = ring.pow(&f, &(q >> 1_u8));
+    let x2 = ring.pow(&x1, q);
+    let y2 = ring.mul(&y1, &ring.pow(&y1, q));
+
+    let curve = RingCurve { ring, f, a };
+    let p = RingPoint::Affine(x, Poly::constant(field.one()));
+    let q_mod_l = (q % l).to_u64().unwrap();
+    let lhs = curve.add(&RingPoint::Affine(x2, y2), &curve.mul(&p, q_mod_l)?)?;
+    let (lhs_x, lhs_y) = match lhs {
+        RingPoint::Inf => return Ok(Some(0)),
+        RingPoint::Affine(x, y) => (x, y),
+    };
+
+    let pi = RingPoint::Affine(x1, y1);
+    let mut t = pi.clone();
+    for tau in 1..=(l - 1) / 2 {
+        if let RingPoint::Affine(tx, ty) = &t {
+            if *tx == lhs_x {
+                return match (*ty == lhs_y, ty.add(&lhs_y).is_zero()) {
+                    (true, _) => Ok(Some(tau)),
+                    (_, true) => Ok(Some(l - tau)),
+                    _ => Err(ty.sub(&lhs_y).gcd(ring.modulus())),
+                };
+            }
+        }
+        t = curve.add(&t, &pi)?;
+    }
+    Ok(None)
+}
+
+fn field_sea_order<const N: usize>(ec: &ECurve) -> Option<crate::Result<BigUint>> {
+    let field = PrimeField::<N>::new(&ec.q).ok()?;
+    Some(sea_in_field(ec, &mut ModularPolynomials::new(&field)))
+}
+
+enum SeaStep {
+    /// t mod l
+    Elkies(u64),
+    /// candidates for t mod l
+    Atkin(Vec<u64>),
+}
+
+// Elkies or Atkin step for prime l, None if formulas for the isogeny degenerate
+fn sea_step<const N: usize>(
+    ec: &ECurve,
+    polys: &mut ModularPolynomials<'_, N>,
+    l: u64,
+    j: FieldElement<'_, N>,
+) -> crate::Result<Option<SeaStep>> {
+    let field = polys.field();
+    let q = ec.q.to_biguint().unwrap();
+    let g = polys.get(l)?;
+    let ring = PolyModulus::new(&g.at_y(j));
+    let x = Poly::x(field);
+    let xq = ring.pow(&x, &q);
+    let roots = xq.sub(&x).gcd(ring.modulus());
+    if roots.degree() == Some(0) {
+        // Frobenius acts on l + 1 subgroups of order l as a permutation with cycles of length r
+        let r = frobenius_order(&ring, &xq, l);
+        return Ok(Some(SeaStep::Atkin(atkin_traces(&q, l, r))));
+    }
+
+    let (a, b) = (field.element(&ec.a), field.element(&ec.b));
+    let f = find_root(&roots, &q);
+    let (a_iso, b_iso, p1) = match elkies_isogeny(g, a, b, j, f) {
+        Some(isogeny) => isogeny,
+        None => return Ok(None),
+    };
+    let kernel = kernel_polynomial(a, b, a_iso, b_iso, p1, l);
+    Ok(
+        eigenvalue(&PolyModulus::new(&kernel), a, b, &q, l).map(|lambda| {
+            // t = lambda + q / lambda mod l
+            let q_l = (&q % l).to_u64().unwrap();
+            SeaStep::Elkies((lambda + q_l * inverse_mod(lambda, l)) % l)
+        }),
+    )
+}
+
+// a^{-1} mod prime l
+fn inverse_mod(a: u64, l: u64) -> u64 {
+    BigUint::from(a)
+        .modpow(&BigUint::from(l - 2), &BigUint::from(l))
+        .to_u64()
+        .unwrap()
+}
+
+// a root of polynomial, that splits into distinct linear factors, it's found with
+// equal-degree factorization: gcd((x + d)^((q - 1) / 2) - 1, h) for d = 0, 1, 2, ...
+fn find_root<'f, const N: usize>(h: &Poly<'f, N>, q: &BigUint) -> FieldElement<'f, N> {
+    let field = h.field();
+    let mut h = h.monic();
+    let mut d = field.zero();
+    while h.degree() != Some(1) {
+        let ring = PolyModulus::new(&h);
+        let x_d = Poly::new(field, vec![d, field.one()]);
+        let s = ring
+            .pow(&x_d, &(q >> 1_u8))
+            .sub(&Poly::constant(field.one()));
+        let g = s.gcd(&h);
+        if let Some(k) = g.degree().filter(|k| *k > 0 && Some(*k) < h.degree()) {
+            h = match 2 * k <= h.degree().unwrap() {
+                true => g,
+                false => h.div_rem(&g).0.monic(),
+            };
+        }
+        d = d + field.one();
+    }
+    -h.coefs()[0]
+}
+
+// Weierstrass model y^2 = x^3 + a'x + b' of the isogenous curve E' = E / C, f is a root of
+// G_l(X, j), and p1 = sum of x-coordinates of points of the kernel C. It's computed from
+// derivatives of G_l and Eisenstein series E4, E6 of both curves (Muller, Blake-Seroussi-Smart),
+// None if some of the derivatives are zero
+fn elkies_isogeny<'f, const N: usize>(
+    g: &ModularPolynomial<'f, N>,
+    a: FieldElement<'f, N>,
+    b: FieldElement<'f, N>,
+    j: FieldElement<'f, N>,
+    f: FieldElement<'f, N>,
+) -> Option<(
+    FieldElement<'f, N>,
+    FieldElement<'f, N>,
+    FieldElement<'f, N>,
+)> {
+    let field = f.field();
+    let c = |x: u64| field.from_u64(x);
+    let (l, s) = (g.l(), g.s());
+    let e4 = -(c(48) * a);
+    let e6 = c(864) * b;
+    let delta = (e4 * e4 * e4 - e6 * e6) * c(1728).inverse();
+    let dj = -(j * e6 * e4.inverse());
+    let (gx, gy) = (g.partial(f, j, 1, 0), g.partial(f, j, 0, 1));
+    let (gxx, gxy, gyy) = (
+        g.partial(f, j, 2, 0),
+        g.partial(f, j, 1, 1),
+        g.partial(f, j, 0, 2),
+    );
+    if gx.is_zero() || f.is_zero() {
+        return None;
+    }
+    let df = -(gy * dj * gx.inverse());
+    let dlog_f = df * f.inverse();
+    let e6_e4 = e6 * e4.inverse();
+    let q_tilde = c(2) * e6_e4 * e6_e4 * c(3).inverse() + e4 * c(2).inverse();
+    let num = gxx * df * df
+        + c(2) * gxy * df * dj
+        + gyy * dj * dj
+        + gx * df * dlog_f * (c(1) + c(s).inverse())
+        + gx * f * c(s) * e4 * c(144).inverse()
+        + gy * j * q_tilde;
+    let l_elem = c(l);
+    let l2 = l_elem * l_elem;
+    let e4_iso = c(144) * num * (gx * f * c(s) * l2).inverse();
+    let l12 = l2 * l2 * l2 * l2 * l2 * l2;
+    let delta_iso = f.pow(&[12 / s]) * delta * l12.inverse();
+    let j_iso = e4_iso * e4_iso * e4_iso * delta_iso.inverse();
+    let f_iso = l_elem.pow(&[s]) * f.inverse();
+    let (gx_iso, gy_iso) = (g.partial(f_iso, j_iso, 1, 0), g.partial(f_iso, j_iso, 0, 1));
+    if delta_iso.is_zero() || j_iso.is_zero() || gy_iso.is_zero() {
+        return None;
+    }
+    let dj_iso = gx_iso * f_iso * dlog_f * gy_iso.inverse();
+    let e6_iso = -(e4_iso * dj_iso * (l_elem * j_iso).inverse());
+    let a_iso = -(l2 * l2 * e4_iso * c(48).inverse());
+    let b_iso = l2 * l2 * l2 * e6_iso * c(864).inverse();
+    let p1 = -(l_elem * dlog_f * c(2 * s).inverse());
+    Some((a_iso, b_iso, p1))
+}
+
+// coefficients c_k of Weierstrass function p(z) = z^{-2} + sum c_k z^{2k}, k = 1..n
+fn weierstrass_coefs<'f, const N: usize>(
+    a: FieldElement<'f, N>,
+    b: FieldElement<'f, N>,
+    n: usize,
+) -> Vec<FieldElement<'f, N>> {
+    let field = a.field();
+    let mut c = vec![field.zero(), -(a * field.from_u64(5).inverse())];
+    c.push(-(b * field.from_u64(7).inverse()));
+    for k in 3..=n {
+        let sum = (1..k - 1).fold(field.zero(), |acc, i| acc + c[i] * c[k - 1 - i]);
+        let d = ((k - 2) * (2 * k + 3)) as u64;
+        c.push(field.from_u64(3) * sum * field.from_u64(d).inverse());
+    }
+    c.truncate(n + 1);
+    c
+}
+
+// kernel polynomial D(x) = prod (x - x(P)) over (l - 1) / 2 points P of the kernel up to sign,
+// it's found from D(p(z)) = z^{-2d} exp(L(z^2)), where L is determined by p(z) on E and E'
+fn kernel_polynomial<'f, const N: usize>(
+    a: FieldElement<'f, N>,
+    b: FieldElement<'f, N>,
+    a_iso: FieldElement<'f, N>,
+    b_iso: FieldElement<'f, N>,
+    p1: FieldElement<'f, N>,
+    l: u64,
+) -> Poly<'f, N> {
+    let field = a.field();
+    let d = (l as usize - 1) / 2;
+    let inv = |x: usize| field.from_u64(x as u64).inverse();
+    let c = weierstrass_coefs(a, b, d);
+    let c_iso = weierstrass_coefs(a_iso, b_iso, d);
+    // L(w) = -p1 w - sum (c'_k - l c_k) w^{k + 1} / ((2k + 1)(2k + 2)), w = z^2
+    let mut log = vec![field.zero(); d + 1];
+    log[1] = -p1;
+    for k in 1..d {
+        log[k + 1] = -((c_iso[k] - field.from_u64(l) * c[k]) * inv((2 * k + 1) * (2 * k + 2)));
+    }
+    // E = exp(L) mod w^{d + 1} from E' = L'E
+    let mut exp = vec![field.one()];
+    for n in 1..=d {
+        let sum = (1..=n).fold(field.zero(), |acc, k| {
+            acc + field.from_u64(k as u64) * log[k] * exp[n - k]
+        });
+        exp.push(sum * inv(n));
+    }
+    // w p(z) = 1 + sum c_k w^{k + 1} and its powers
+    let mut wp = vec![field.zero(); d + 1];
+    wp[0] = field.one();
+    wp[2..=d].copy_from_slice(&c[1..d]);
+    let mut powers = vec![{
+        let mut one = vec![field.zero(); d + 1];
+        one[0] = field.one();
+        one
+    }];
+    for i in 1..=d {
+        let prev = &powers[i - 1];
+        let next = (0..=d)
+            .map(|m| (0..=m).fold(field.zero(), |acc, k| acc + prev[m - k] * wp[k]))
+            .collect();
+        powers.push(next);
+    }
+    // sum delta_i w^{d - i} (w p)^i = E, delta_d = 1, the system is triangular
+    let mut delta = vec![field.zero(); d + 1];
+    delta[d] = field.one();
+    for m in 1..=d {
+        let i0 = d - m;
+        delta[i0] = (i0 + 1..=d).fold(exp[m], |acc, i| acc - delta[i] * powers[i][m - (d - i)]);
+    }
+    Poly::new(field, delta)
+}
+
+// eigenvalue lambda of Frobenius on the kernel: pi(P) = lambda * P for P = (x, y), x is a root
+// of the kernel polynomial, pi(P) = (x^q, f^((q - 1) / 2) * y)
+fn eigenvalue<const N: usize>(
+    ring: &PolyModulus<'_, N>,
+    a: FieldElement<'_, N>,
+    b: FieldElement<'_, N>,
+    q: &BigUint,
+    l: u64,
+) -> Option<u64> {
+    let field = a.field();
+    let x = ring.reduce(&Poly::x(field));
+    let f = ring.reduce(&Poly::new(field, vec![b, a, field.zero(), field.one()]));
+    let xq = ring.pow(&x, q);
+    let yq = ring.pow(&f, &(q >> 1_u8));
+    let curve = RingCurve { ring, f, a };
+    let p = RingPoint::Affine(x, Poly::constant(field.one()));
+    let mut kp = p.clone();
+    for k in 1..=(l - 1) / 2 {
+        if let RingPoint::Affine(kx, ky) = &kp {
+            if *kx == xq {
+                return Some(match *ky == yq {
+                    true => k,
+                    false => l - k,
+                });
+            }
+        }
+        kp = curve.add(&kp, &p).ok()?;
+    }
+    None
+}
+
+// the smallest r > 1, r | l + 1, such that x^(q^r) = x mod G_l(x, j). Frobenius is a linear
+// map of GF(q)[x] / (G_l), its matrix is made of x^(qi), so x^(q^r) are found with
+// O(l^2) operations each instead of polynomial multiplications
+fn frobenius_order<const N: usize>(ring: &PolyModulus<'_, N>, xq: &Poly<'_, N>, l: u64) -> u64 {
+    let field = ring.modulus().field();
+    let n = l as usize + 1;
+    let mut columns = vec![Poly::constant(field.one())];
+    for i in 1..n {
+        columns.push(ring.mul(&columns[i - 1], xq));
+    }
+    let x = Poly::x(field);
+    let mut p = xq.clone();
+    for r in 2..=n as u64 / 2 {
+        let mut coefs = vec![field.zero(); n];
+        for (c, column) in p.coefs().iter().zip(columns.iter()) {
+            for (k, x) in column.coefs().iter().enumerate() {
+                coefs[k] = coefs[k] + *c * *x;
+            }
+        }
+        p = Poly::new(field, coefs);
+        if (l + 1).is_multiple_of(r) && p == x {
+            return r;
+        }
+    }
+    l + 1
+}
+
+// t mod l with t^2 = q (g + 1 / g + 2) for elements g of order r in GF(l^2):
+// eigenvalues of Frobenius are lambda and q / lambda, lambda^2 / q = g
+fn atkin_traces(q: &BigUint, l: u64, r: u64) -> Vec<u64> {
+    let q_l = (q % l).to_u64().unwrap();
+    // GF(l^2) = GF(l)(sqrt(nr)) for non-residue nr, elements are pairs (u, v) = u + v sqrt(nr)
+    let nr = (2..l)
+        .find(|x| jacobi_symbol(&BigInt::from(*x), &BigInt::from(l)) == Some(-1))
+        .unwrap();
+    let mul = |x: (u64, u64), y: (u64, u64)| {
+        (
+            (x.0 * y.0 + nr * x.1 % l * y.1) % l,
+            (x.0 * y.1 + x.1 * y.0) % l,
+        )
+    };
+    let pow = |mut x: (u64, u64), mut e: u64| {
+        let mut r = (1, 0);
+        while e > 0 {
+            if e & 1 == 1 {
+                r = mul(r, x);
+            }
+            x = mul(x, x);
+            e >>= 1;
+        }
+        r
+    };
+    let order = l * l - 1;
+    let factors: Vec<u64> = (2..=order)
+        .filter(|d| {
+            order.is_multiple_of(*d) && (2..*d).take_while(|k| k * k <= *d).all(|k| d % k != 0)
+        })
+        .collect();
+    let generator = (0..l)
+        .flat_map(|u| (1..l).map(move |v| (u, v)))
+        .find(|g| factors.iter().all(|d| pow(*g, order / d) != (1, 0)))
+        .unwrap();
+    let mut traces = vec![];
+    for k in (1..=r).filter(|k| gcd(*k, r) == 1) {
+        let g = pow(generator, order / r * k);
+        let g_inv = pow(g, order - 1);
+        // g + 1 / g is in GF(l)
+        let t2 = q_l * ((g.0 + g_inv.0 + 2) % l) % l;
+        traces.extend((0..l).filter(|t| t * t % l == t2));
+    }
+    traces.sort_unstable();
+    traces.dedup();
+    traces
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+// Atkin primes, that are used in match-and-sort, and log2 of its work, `count` is log2 of
+// number of candidates, that are left by Elkies primes. Primes with the smallest share of
+// candidates are taken, while they decrease the estimate
+fn select_atkin(count: f64, atkin: &[(u64, Vec<u64>)]) -> (Vec<usize>, f64) {
+    let mut order: Vec<usize> = (0..atkin.len()).collect();
+    let share = |i: &usize| atkin[*i].1.len() as f64 / atkin[*i].0 as f64;
+    order.sort_by(|x, y| share(x).total_cmp(&share(y)));
+    // |C_1| * ... * |C_k| combinations of residues and (count / (l_1 * ... * l_k) + k + 2) steps
+    let estimate = |chosen: &[usize]| {
+        let (mut residues, mut modulus) = (0.0, 0.0);
+        for i in chosen {
+            residues += (atkin[*i].1.len() as f64).log2();
+            modulus += (atkin[*i].0 as f64).log2();
+        }
+        residues + ((count - modulus).exp2() + chosen.len() as f64 + 2.0).log2()
+    };
+    let mut chosen = vec![];
+    let mut best = estimate(&chosen);
+    for i in order {
+        chosen.push(i);
+        let e = estimate(&chosen);
+        if e >= best {
+            chosen.pop();
+            break;
+        }
+        best = e;
+    }
+    (chosen, best)
+}
+
+// N = q + 1 - t for t = t0 + mu, where u mod l is restricted by Atkin primes l,
+// u * R = Q for R = m * P, Q = (q + 1 - t0) * P. Atkin primes are split into two groups,
+// u = x1 + x2 + Mk, where x1, x2 are combinations of residues of the groups lifted by CRT,
+// M is the product of primes and Q - (x1 + Mk_b) * R = (x2 + MBk_g) * R is solved
+// with a hash table for one side
+fn match_and_sort(
+    ec: &ECurve,
+    t0: &BigUint,
+    m: &BigUint,
+    atkin: &[(u64, Vec<u64>)],
+) -> crate::Result<BigUint> {
+    let err = || {
+        EcError::IncorrectParameters(format!(
+            "can't determine order of the curve over GF({})",
+            ec.q
+        ))
+    };
+    let q = ec.q.to_biguint().unwrap();
+    let bound = BigInt::from((&q << 2_u8).sqrt());
+    let (t0, m) = (BigInt::from(t0.clone()), BigInt::from(m.clone()));
+    // u in [u_low, u_high], so |t| <= 2sqrt(q)
+    let (low, high) = (-&bound - &t0, &bound - &t0);
+    let u_low = -floor_div(&-low, &m);
+    let u_high = floor_div(&high, &m);
+    if u_low > u_high {
+        return Err(err());
+    }
+    let count = (&u_high - &u_low + 1_u8).bits() as f64;
+    let (chosen, _) = select_atkin(count, atkin);
+
+    // residues of u = (t - t0) / m, the groups have close numbers of combinations
+    let mut groups: [Vec<(u64, Vec<u64>)>; 2] = [vec![], vec![]];
+    let mut sizes = [1_usize; 2];
+    let mut primes: Vec<&(u64, Vec<u64>)> = chosen.iter().map(|i| &atkin[*i]).collect();
+    primes.sort_by_key(|(_, c)| std::cmp::Reverse(c.len()));
+    for (l, candidates) in primes {
+        let m_inv = inverse_mod((&m % l).to_u64().unwrap(), *l);
+        let t0_l = (&t0 % l).to_u64().unwrap();
+        let residues = candidates
+            .iter()
+            .map(|c| (c + l - t0_l) % l * m_inv % l)
+            .collect();
+        let g = (sizes[1] < sizes[0]) as usize;
+        sizes[g] *= candidates.len();
+        groups[g].push((*l, residues));
+    }
+    let modulus: BigUint = groups
+        .iter()
+        .flatten()
+        .map(|(l, _)| BigUint::from(*l))
+        .product();
+    // lifted residue is r mod l and 0 mod other primes
+    let lift = |l: u64, r: u64| {
+        let rest = &modulus / l;
+        let inv = inverse_mod((&rest % l).to_u64().unwrap(), l);
+        rest * (r * inv % l)
+    };
+    let lifted: Vec<Vec<Vec<BigUint>>> = groups
+        .iter()
+        .map(|g| {
+            g.iter()
+                .map(|(l, residues)| residues.iter().map(|r| lift(*l, *r)).collect())
+                .collect()
+        })
+        .collect();
+    let modulus = BigInt::from(modulus);
+    let k_low = floor_div(&u_low, &modulus) - chosen.len();
+    let k_count = (floor_div(&u_high, &modulus) - &k_low + 1_u8)
+        .to_u64()
+        .ok_or_else(err)?;
+    let steps =
+        ((sizes[1] as f64 * k_count as f64 / sizes[0] as f64).sqrt() as u64).clamp(1, k_count);
+
+    let twist = quadratic_twist(ec)?;
+    let mut points = [PointSource::new(ec), PointSource::new(&twist)];
+    let mut candidates: Option<Vec<BigUint>> = None;
+    for i in 0..MAX_POINTS {
+        let p = match points[i % 2].next() {
+            Some(p) => p,
+            None => continue,
+        };
+        let c: Vec<BigUint> = match (i % 2, &candidates) {
+            (0, None) => {
+                let r = ec.proj_point_mul(&p, &m.to_biguint().unwrap());
+                let base = ec.proj_point_mul(&p, &(&q + 1_u8 - t0.to_biguint().unwrap()));
+                let steps = (&modulus, &k_low, k_count, steps);
+                match match_points(ec, &r, &base, &lifted, steps)? {
+                    Some(u) => u
+                        .iter()
+                        .filter(|u| **u >= u_low && **u <= u_high)
+                        .map(|u| {
+                            (BigInt::from(&q + 1_u8) - &t0 - &m * u)
+                                .to_biguint()
+                                .unwrap()
+                        })
+                        .collect(),
+                    None => continue,
+                }
+            }
+            (_, None) => continue,
+            // N * P = O on E and (2q + 2 - N) * P = O on the twist
+            (0, Some(c)) => c
+                .iter()
+                .filter(|n| ec.proj_point_mul(&p, n).is_inf())
+                .cloned()
+                .collect(),
+            (_, Some(c)) => c
+                .iter()
+                .filter(|n| {
+                    twist
+                        .proj_point_mul(&p, &((&q << 1_u8) + 2_u8 - *n))
+                        .is_inf()
+                })
+                .cloned()
+                .collect(),
+        };
+        match c.len() {
+            0 => return Err(err()),
+            1 => return Ok(c[0].clone()),
+            _ => candidates = Some(c),
+        }
+    }
+    Err(err())
+}
+
+// all u = x1 + x2 + M(k_low + k_b + B k_g), such that u * R = Q, None if there're too many
+// of them (R has small order)
+fn match_points(
+    ec: &ECurve,
+    r: &EcPointP,
+    base: &EcPointP,
+    lifted: &[Vec<Vec<BigUint>>],
+    (modulus, k_low, k_count, steps): (&BigInt, &BigInt, u64, u64),
+) -> crate::Result<Option<Vec<BigInt>>> {
+    let key = |a: &EcPointA| (a.is_inf(), a.get_x(), a.get_y());
+    let neg = |p: &EcPointP| match p.is_inf() {
+        true => EcPointP::neutral(),
+        false => ec.take_by_module(&p.negative()),
+    };
+    let modulus_r = ec.proj_point_mul(r, &modulus.to_biguint().unwrap());
+    let sums: Vec<Vec<(EcPointP, BigUint)>> =
+        lifted.iter().map(|g| combinations(ec, r, g)).collect();
+
+    // baby steps Q - (x1 + M k_low) R - k_b M R
+    let shift = ec.proj_point_mul(&modulus_r, k_low.magnitude());
+    let start = match k_low.sign() {
+        Sign::Minus => ec.proj_point_add(base, &shift),
+        _ => ec.proj_point_add(base, &neg(&shift)),
+    };
+    let minus_modulus_r = neg(&modulus_r);
+    let mut baby = vec![];
+    for (p, _) in sums[0].iter() {
+        let mut cur = ec.proj_point_add(&start, &neg(p));
+        for _ in 0..steps {
+            baby.push(cur.clone());
+            cur = ec.proj_point_add(&cur, &minus_modulus_r);
+        }
+    }
+    let mut table = HashMap::new();
+    for (i, p) in ec.batch_to_affine(&baby)?.iter().enumerate() {
+        if table.insert(key(p), i as u64).is_some() {
+            return Ok(None);
+        }
+    }
+
+    // giant steps (x2 + M B k_g) R
+    let giant_step = ec.proj_point_mul(&modulus_r, &BigUint::from(steps));
+    let giant_count = k_count.div_ceil(steps);
+    let mut giant = vec![];
+    for (p, _) in sums[1].iter() {
+        let mut cur = p.clone();
+        for _ in 0..giant_count {
+            giant.push(cur.clone());
+            cur = ec.proj_point_add(&cur, &giant_step);
+        }
+    }
+    let mut res = vec![];
+    for (i, p) in ec.batch_to_affine(&giant)?.iter().enumerate() {
+        if let Some(b) = table.get(&key(p)) {
+            let (x1, k_b) = (&sums[0][(b / steps) as usize].1, b % steps);
+            let (x2, k_g) = (&sums[1][i / giant_count as usize].1, i as u64 % giant_count);
+            let k = k_low + k_b + k_g * steps;
+            res.push(BigInt::from(x1 + x2) + modulus * k);
+            if res.len() > MAX_CANDIDATES {
+                return Ok(None);
+            }
+        }
+    }
+    Ok(Some(res))
+}
+
+// x * R for all sums x of residues, one residue is taken from every list
+fn combinations(ec: &ECurve, r: &EcPointP, group: &[Vec<BigUint>]) -> Vec<(EcPointP, BigUint)> {
+    let mut sums = vec![(EcPointP::neutral(), BigUint::zero())];
+    for residues in group {
+        let points: Vec<(EcPointP, &BigUint)> = residues
+            .iter()
+            .map(|x| (ec.proj_point_mul(r, x), x))
+            .collect();
+        sums = sums
+            .iter()
+            .flat_map(|(p, x)| {
+                points
+                    .iter()
+                    .map(move |(rp, rx)| (ec.proj_point_add(p, rp), x + *rx))
+            })
+            .collect();
+    }
+    sums
+}
+
+// floor(a / b) for b > 0
+fn floor_div(a: &BigInt, b: &BigInt) -> BigInt {
+    match a.sign() {
+        Sign::Minus => -((-a + b - 1_u8) / b),
+        _ => a / b,
+    }
+}
@@ -0,0 +1,366 @@
+use crate::field::{FieldElement, PrimeField};
+use num_bigint::BigUint;
+
+/// **KARATSUBA_THRESHOLD** -- shorter polynomials are multiplied with schoolbook method
+const KARATSUBA_THRESHOLD: usize = 16;
+
+/// **Poly** -- polynomial over GF(p), coefficients are stored from the lowest degree
+/// and the leading one is never zero (zero polynomial has no coefficients)
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Poly<'f, const N: usize> {
+    field: &'f PrimeField<N>,
+    coefs: Vec<FieldElement<'f, N>>,
+}
+
+impl<'f, const N: usize> Poly<'f, N> {
+    pub fn new(field: &'f PrimeField<N>, coefs: Vec<FieldElement<'f, N>>) -> Self {
+        let mut p = Poly { field, coefs };
+        p.normalize();
+        p
+    }
+
+    pub fn zero(field: &'f PrimeField<N>) -> Self {
+        Poly {
+            field,
+            coefs: vec![],
+        }
+    }
+
+    pub fn constant(c: FieldElement<'f, N>) -> Self {
+        Poly::new(c.field(), vec![c])
+    }
+
+    /// **x** -- polynomial x
+    pub fn x(field: &'f PrimeField<N>) -> Self {
+        Poly::new(field, vec![field.zero(), field.one()])
+    }
+
+    pub fn field(&self) -> &'f PrimeField<N> {
+        self.field
+    }
+
+    pub fn coefs(&self) -> &[FieldElement<'f, N>] {
+        &self.coefs
+    }
+
+    /// **degree** -- None for zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.coefs.len().checked_sub(1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefs.is_empty()
+    }
+
+    pub fn leading_coef(&self) -> FieldElement<'f, N> {
+        self.coefs.last().copied().unwrap_or(self.field.zero())
+    }
+
+    fn normalize(&mut self) {
+        while self.coefs.last().is_some_and(|c| c.is_zero()) {
+            self.coefs.pop();
+        }
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        let (long, short) = match self.coefs.len() >= other.coefs.len() {
+            true => (self, other),
+            false => (other, self),
+        };
+        let mut coefs = long.coefs.clone();
+        for (c, s) in coefs.iter_mut().zip(short.coefs.iter()) {
+            *c = *c + *s;
+        }
+        Poly::new(self.field, coefs)
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        self.add(&other.neg())
+    }
+
+    pub fn neg(&self) -> Self {
+        Poly::new(self.field, self.coefs.iter().map(|c| -*c).collect())
+    }
+
+    pub fn scale(&self, c: FieldElement<'f, N>) -> Self {
+        Poly::new(self.field, self.coefs.iter().map(|x| *x * c).collect())
+    }
+
+    /// **mul** -- product with Karatsuba's method for long polynomials
+    pub fn mul(&self, other: &Self) -> Self {
+        Poly::new(
+            self.field,
+            mul_slices(&self.coefs, &other.coefs, self.field.zero()),
+        )
+    }
+
+    /// **square** -- the same as `mul(self)`, but symmetric products are computed once
+    pub fn square(&self) -> Self {
+        Poly::new(self.field, sqr_slices(&self.coefs, self.field.zero()))
+    }
+
+    /// **monic** -- divides polynomial by its leading coefficient
+    pub fn monic(&self) -> Self {
+        match self.is_zero() {
+            true => self.clone(),
+            false => self.scale(self.leading_coef().inverse()),
+        }
+    }
+
+    /// **div_rem** -- long division, divisor has to be nonzero
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let d = divisor.degree().expect("division by zero polynomial");
+        let lc_inv = divisor.leading_coef().inverse();
+        let mut r = self.coefs.clone();
+        if r.len() <= d {
+            return (Poly::zero(self.field), self.clone());
+        }
+        let mut q = vec![self.field.zero(); r.len() - d];
+        for i in (0..q.len()).rev() {
+            let c = r[i + d] * lc_inv;
+            q[i] = c;
+            for (j, x) in divisor.coefs.iter().enumerate() {
+                r[i + j] = r[i + j] - c * *x;
+            }
+        }
+        r.truncate(d);
+        (Poly::new(self.field, q), Poly::new(self.field, r))
+    }
+
+    /// **gcd** -- monic greatest common divisor
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            let r = a.div_rem(&b).1;
+            (a, b) = (b, r);
+        }
+        a.monic()
+    }
+
+    /// **eval** -- value at the point with Horner's method
+    pub fn eval(&self, x: FieldElement<'f, N>) -> FieldElement<'f, N> {
+        self.coefs
+            .iter()
+            .rev()
+            .fold(self.field.zero(), |acc, c| acc * x + *c)
+    }
+}
+
+// product of coefficient slices, the shorter part is split off when lengths differ a lot
+fn mul_slices<'f, const N: usize>(
+    a: &[FieldElement<'f, N>],
+    b: &[FieldElement<'f, N>],
+    zero: FieldElement<'f, N>,
+) -> Vec<FieldElement<'f, N>> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let len = a.len() + b.len() - 1;
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        let mut r = vec![zero; len];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                r[i + j] = r[i + j] + *x * *y;
+            }
+        }
+        return r;
+    }
+
+    let m = a.len().max(b.len()) / 2;
+    if a.len() <= m || b.len() <= m {
+        let (long, short) = match a.len() > b.len() {
+            true => (a, b),
+            false => (b, a),
+        };
+        let (l0, l1) = long.split_at(m);
+        let mut r = mul_slices(l0, short, zero);
+        r.resize(len, zero);
+        for (i, c) in mul_slices(l1, short, zero).into_iter().enumerate() {
+            r[m + i] = r[m + i] + c;
+        }
+        return r;
+    }
+
+    // (a0 + a1 x^m)(b0 + b1 x^m) = z0 + ((a0 + a1)(b0 + b1) - z0 - z2) x^m + z2 x^2m
+    let (a0, a1) = a.split_at(m);
+    let (b0, b1) = b.split_at(m);
+    let z0 = mul_slices(a0, b0, zero);
+    let z2 = mul_slices(a1, b1, zero);
+    let z1 = mul_slices(&add_slices(a0, a1, zero), &add_slices(b0, b1, zero), zero);
+    let mut r = vec![zero; len.max(m + z1.len())];
+    for (i, c) in z0.iter().enumerate() {
+        r[i] = r[i] + *c;
+        r[m + i] = r[m + i] - *c;
+    }
+    for (i, c) in z2.iter().enumerate() {
+        r[2 * m + i] = r[2 * m + i] + *c;
+        r[m + i] = r[m + i] - *c;
+    }
+    for (i, c) in z1.iter().enumerate() {
+        r[m + i] = r[m + i] + *c;
+    }
+    r.truncate(len);
+    r
+}
+
+fn sqr_slices<'f, const N: usize>(
+    a: &[FieldElement<'f, N>],
+    zero: FieldElement<'f, N>,
+) -> Vec<FieldElement<'f, N>> {
+    if a.is_empty() {
+        return vec![];
+    }
+    let len = 2 * a.len() - 1;
+    if a.len() < KARATSUBA_THRESHOLD {
+        let mut r = vec![zero; len];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in a.iter().enumerate().skip(i + 1) {
+                r[i + j] = r[i + j] + *x * *y;
+            }
+        }
+        for c in r.iter_mut() {
+            *c = *c + *c;
+        }
+        for (i, x) in a.iter().enumerate() {
+            r[2 * i] = r[2 * i] + *x * *x;
+        }
+        return r;
+    }
+
+    // (a0 + a1 x^m)^2 = z0 + ((a0 + a1)^2 - z0 - z2) x^m + z2 x^2m
+    let m = a.len() / 2;
+    let (a0, a1) = a.split_at(m);
+    let z0 = sqr_slices(a0, zero);
+    let z2 = sqr_slices(a1, zero);
+    let z1 = sqr_slices(&add_slices(a0, a1, zero), zero);
+    let mut r = vec![zero; len.max(m + z1.len())];
+    for (i, c) in z0.iter().enumerate() {
+        r[i] = r[i] + *c;
+        r[m + i] = r[m + i] - *c;
+    }
+    for (i, c) in z2.iter().enumerate() {
+        r[2 * m + i] = r[2 * m + i] + *c;
+        r[m + i] = r[m + i] - *c;
+    }
+    for (i, c) in z1.iter().enumerate() {
+        r[m + i] = r[m + i] + *c;
+    }
+    r.truncate(len);
+    r
+}
+
+fn add_slices<'f, const N: usize>(
+    a: &[FieldElement<'f, N>],
+    b: &[FieldElement<'f, N>],
+    zero: FieldElement<'f, N>,
+) -> Vec<FieldElement<'f, N>> {
+    (0..a.len().max(b.len()))
+        .map(|i| *a.get(i).unwrap_or(&zero) + *b.get(i).unwrap_or(&zero))
+        .collect()
+}
+
+/// **PolyModulus** -- arithmetic in GF(p)[x] / (m(x)), reduction is done with two multiplications
+/// by precomputed inverse of reversed m (Barrett's method), so it's fast enough for long moduli
+pub struct PolyModulus<'f, const N: usize> {
+    modulus: Poly<'f, N>,
+    /// **inv_rev** -- x^d m(1/x) inverted as power series mod x^(d - 1)
+    inv_rev: Vec<FieldElement<'f, N>>,
+}
+
+impl<'f, const N: usize> PolyModulus<'f, N> {
+    /// **new** -- modulus is made monic, it has to be nonconstant
+    pub fn new(modulus: &Poly<'f, N>) -> Self {
+        let modulus = modulus.monic();
+        let d = modulus.degree().expect("zero modulus");
+        assert!(d > 0, "modulus has to be nonconstant");
+        let rev: Vec<FieldElement<'f, N>> = modulus.coefs.iter().rev().copied().collect();
+        let zero = modulus.field.zero();
+
+        // Newton iteration g = g(2 - rev * g), every step doubles precision
+        let mut inv_rev = vec![modulus.field.one()];
+        let mut precision = 1;
+        while precision < d - 1 {
+            precision = (2 * precision).min(d - 1);
+            let mut e = mul_slices(&rev[..precision.min(rev.len())], &inv_rev, zero);
+            e.resize(precision, zero);
+            for c in e.iter_mut() {
+                *c = -*c;
+            }
+            e[0] = e[0] + modulus.field.from_u64(2);
+            inv_rev = mul_slices(&inv_rev, &e, zero);
+            inv_rev.truncate(precision);
+        }
+        inv_rev.truncate(d - 1);
+        PolyModulus { modulus, inv_rev }
+    }
+
+    pub fn modulus(&self) -> &Poly<'f, N> {
+        &self.modulus
+    }
+
+    /// **reduce** -- a mod m
+    pub fn reduce(&self, a: &Poly<'f, N>) -> Poly<'f, N> {
+        let d = self.modulus.coefs.len() - 1;
+        if a.coefs.len() <= d {
+            return a.clone();
+        }
+        // number of quotient coefficients
+        let k = a.coefs.len() - d;
+        if k > self.inv_rev.len() {
+            return a.div_rem(&self.modulus).1;
+        }
+        let zero = a.field.zero();
+        let rev_a: Vec<FieldElement<'f, N>> = a.coefs.iter().rev().take(k).copied().collect();
+        let mut q = mul_slices(&rev_a, &self.inv_rev[..k], zero);
+        q.truncate(k);
+        q.reverse();
+        let qm = mul_slices(&q, &self.modulus.coefs, zero);
+        let r = (0..d).map(|i| a.coefs[i] - qm[i]).collect();
+        Poly::new(a.field, r)
+    }
+
+    pub fn mul(&self, a: &Poly<'f, N>, b: &Poly<'f, N>) -> Poly<'f, N> {
+        self.reduce(&a.mul(b))
+    }
+
+    pub fn square(&self, a: &Poly<'f, N>) -> Poly<'f, N> {
+        self.reduce(&a.square())
+    }
+
+    /// **pow** -- a^e mod m with fixed 4-bit windows from MSB
+    pub fn pow(&self, a: &Poly<'f, N>, e: &BigUint) -> Poly<'f, N> {
+        let mut table = vec![Poly::constant(a.field.one()), self.reduce(a)];
+        for i in 2..16 {
+            table.push(self.mul(&table[i - 1], &table[1]));
+        }
+        let mut r = table[0].clone();
+        for i in (0..e.bits().div_ceil(4)).rev() {
+            for _ in 0..4 {
+                r = self.square(&r);
+            }
+            let digit = (0..4).fold(0, |acc, j| acc | ((e.bit(4 * i + j) as usize) << j));
+            if digit != 0 {
+                r = self.mul(&r, &table[digit]);
+            }
+        }
+        r
+    }
+
+    /// **inverse** -- a^{-1} mod m with extended Euclidean algorithm,
+    /// if a isn't invertible, monic gcd(a, m) is returned as error
+    pub fn inverse(&self, a: &Poly<'f, N>) -> Result<Poly<'f, N>, Poly<'f, N>> {
+        let field = self.modulus.field;
+        let (mut r0, mut r1) = (self.modulus.clone(), self.reduce(a));
+        let (mut s0, mut s1) = (Poly::zero(field), Poly::constant(field.one()));
+        while !r1.is_zero() {
+            let (q, r) = r0.div_rem(&r1);
+            let s = s0.sub(&q.mul(&s1));
+            (r0, r1) = (r1, r);
+            (s0, s1) = (s1, s);
+        }
+        match r0.degree() {
+            Some(0) => Ok(self.reduce(&s0.scale(r0.leading_coef().inverse()))),
+            _ => Err(r0.monic()),
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use crate::factorization::{factorize, Factorization};
+use crate::generation::is_probable_prime;
+use crate::{EcError, EcInfo};
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, ToPrimitive, Zero};
+use std::fmt::{Display, Formatter};
+
+/// **RHO_SECURITY_BITS** -- minimal cost of Pollard's rho in bits, as in SafeCurves
+pub const RHO_SECURITY_BITS: f64 = 100.0;
+/// **CM_DISCRIMINANT_BITS** -- minimal size of CM field discriminant in bits, as in SafeCurves
+pub const CM_DISCRIMINANT_BITS: u64 = 100;
+/// **MAX_COFACTOR** -- the biggest accepted cofactor (Curve25519 has h = 8)
+pub const MAX_COFACTOR: u32 = 8;
+/// **MAX_EMBEDDING_DEGREE** -- embedding degree is searched up to this bound,
+/// transfer to GF(q^k) with bigger k is considered infeasible
+pub const MAX_EMBEDDING_DEGREE: u64 = 10_000;
+
+/// **Criterion** -- SafeCurves-style check of `SecurityReport`
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Criterion {
+    /// n is prime, n * bp = O and #E = n * h
+    SubgroupOrder,
+    /// h <= MAX_COFACTOR
+    Cofactor,
+    /// Pollard's rho takes at least 2^RHO_SECURITY_BITS additions, i.e. 0.886 * sqrt(n)
+    Rho,
+    /// embedding degree k >= (n - 1) / 100, protects from MOV and Frey-Ruck attacks
+    Transfer,
+    /// n != q, protects from Smart's attack on anomalous curves (t = 1)
+    Anomalous,
+    /// rho on the largest prime order subgroup of the quadratic twist takes at least
+    /// 2^RHO_SECURITY_BITS additions, protects from invalid-curve attacks on x-only ladders
+    Twist,
+    /// |D| >= 2^CM_DISCRIMINANT_BITS for discriminant D of CM field Q(sqrt(t^2 - 4q))
+    CmDiscriminant,
+}
+
+/// **SecurityCheck** -- result of one criterion with human-readable details
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SecurityCheck {
+    pub criterion: Criterion,
+    pub passed: bool,
+    pub details: String,
+}
+
+impl Display for SecurityCheck {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let status = match self.passed {
+            true => "ok",
+            false => "FAILED",
+        };
+        write!(f, "{:?}: {status} ({})", self.criterion, self.details)
+    }
+}
+
+/// **SecurityReport** -- numbers, that the criteria are based on, and results of the checks
+#[derive(Clone, Debug, PartialEq)]
+pub struct SecurityReport {
+    /// **curve_order** -- #E(GF(q))
+    pub curve_order: BigUint,
+    /// **trace** -- t = q + 1 - #E
+    pub trace: BigInt,
+    /// **rho_bits** -- log2(0.886 * sqrt(n)), expected number of additions of Pollard's rho
+    pub rho_bits: f64,
+    /// **embedding_degree** -- the least k with q^k = 1 mod n, None if k > MAX_EMBEDDING_DEGREE
+    pub embedding_degree: Option<u64>,
+    /// **twist_order** -- order of the quadratic twist, 2q + 2 - #E
+    pub twist_order: BigUint,
+    pub twist_factorization: Factorization,
+    /// **twist_rho_bits** -- rho cost for the largest prime factor of twist order,
+    /// if the order isn't fully factored, sqrt(cofactor) is used as lower bound of the factor
+    pub twist_rho_bits: f64,
+    /// **cm_discriminant** -- D, where t^2 - 4q = s^2 * D and D = 1 mod 4 or D = 4d
+    /// for square-free d. Unfactored part of t^2 - 4q is assumed to be square-free
+    pub cm_discriminant: BigInt,
+    pub checks: Vec<SecurityCheck>,
+}
+
+impl SecurityReport {
+    /// **is_secure** -- all criteria are passed
+    pub fn is_secure(&self) -> bool {
+        self.checks.iter().all(|check| check.passed)
+    }
+
+    /// **check** -- result of the given criterion
+    pub fn check(&self, criterion: Criterion) -> &SecurityCheck {
+        self.checks
+            .iter()
+            .find(|check| check.criterion == criterion)
+            .unwrap()
+    }
+
+    /// **failed** -- criteria, that aren't passed
+    pub fn failed(&self) -> Vec<&SecurityCheck> {
+        self.checks.iter().filter(|check| !check.passed).collect()
+    }
+}
+
+/// **security_report** -- checks the domain parameters against SafeCurves-style criteria.
+/// #E is taken as n * h, if it can be proven without point counting (n is prime, n > 4sqrt(q),
+/// n * bp = O and n * h is in Hasse interval), otherwise it's found with `ECurve::order`
+pub fn security_report(info: &EcInfo) -> crate::Result<SecurityReport> {
+    let ec = &info.ecurve;
+    let q = ec.q.to_biguint().unwrap();
+    let n = &info.n;
+    if n.is_zero() {
+        return Err(EcError::IncorrectBasePoint("n = 0".to_string()));
+    }
+    let n_is_prime = is_probable_prime(n);
+    let bp_has_order_n = !info.bp.is_inf()
+        && ec.check_projective_point(&info.bp)
+        && ec.proj_point_mul_any(&info.bp, n).is_inf();
+
+    let hasse_bound = &q << 2_u8;
+    let claimed = n * &info.h;
+    let claimed_trace = to_bigint(&q) + 1 - to_bigint(&claimed);
+    let curve_order = match n_is_prime
+        && bp_has_order_n
+        && n * n > &hasse_bound << 2_u8
+        && &claimed_trace * &claimed_trace <= to_bigint(&hasse_bound)
+    {
+        true => claimed,
+        false => ec.order()?,
+    };
+    let trace = to_bigint(&q) + 1 - to_bigint(&curve_order);
+    let mut checks = Vec::new();
+
+    checks.push(SecurityCheck {
+        criterion: Criterion::SubgroupOrder,
+        passed: n_is_prime && bp_has_order_n && n * &info.h == curve_order,
+        details: format!(
+            "n = {n} is {}, n * bp {} O, #E = {curve_order}",
+            if n_is_prime { "prime" } else { "composite" },
+            if bp_has_order_n { "=" } else { "!=" }
+        ),
+    });
+
+    checks.push(SecurityCheck {
+        criterion: Criterion::Cofactor,
+        passed: info.h <= BigUint::from(MAX_COFACTOR),
+        details: format!("h = {}, at most {MAX_COFACTOR} is accepted", info.h),
+    });
+
+    let rho = rho_bits(n);
+    checks.push(SecurityCheck {
+        criterion: Criterion::Rho,
+        passed: rho >= RHO_SECURITY_BITS,
+        details: format!("rho cost is 2^{rho:.1}, at least 2^{RHO_SECURITY_BITS} is needed"),
+    });
+
+    let embedding_degree = embedding_degree(&q, n);
+    let (passed, details) = match embedding_degree {
+        Some(k) => (
+            BigUint::from(k) * 100_u8 >= n - 1_u8,
+            format!("k = {k}, at least (n - 1) / 100 is needed"),
+        ),
+        None if (&q % n).is_zero() => (true, "q = 0 mod n, see anomalous check".to_string()),
+        None => (true, format!("k > {MAX_EMBEDDING_DEGREE}")),
+    };
+    checks.push(SecurityCheck {
+        criterion: Criterion::Transfer,
+        passed,
+        details,
+    });
+
+    checks.push(SecurityCheck {
+        criterion: Criterion::Anomalous,
+        passed: *n != q && curve_order != q,
+        details: format!("t = {trace}"),
+    });
+
+    let twist_order = (&q << 1_u8) + 2_u8 - &curve_order;
+    let twist_factorization = factorize(&twist_order);
+    let largest = twist_factorization
+        .largest_factor()
+        .cloned()
+        .unwrap_or_default()
+        .max(twist_factorization.cofactor.sqrt());
+    let twist_rho_bits = rho_bits(&largest);
+    checks.push(SecurityCheck {
+        criterion: Criterion::Twist,
+        passed: twist_rho_bits >= RHO_SECURITY_BITS,
+        details: format!(
+            "twist order {twist_order}, rho cost is {}2^{twist_rho_bits:.1}",
+            if twist_factorization.is_complete() {
+                ""
+            } else {
+                "at least "
+            }
+        ),
+    });
+
+    let cm_discriminant = cm_discriminant(&(to_bigint(&hasse_bound) - &trace * &trace));
+    checks.push(SecurityCheck {
+        criterion: Criterion::CmDiscriminant,
+        passed: cm_discriminant.bits() > CM_DISCRIMINANT_BITS,
+        details: format!(
+            "D = {cm_discriminant}, |D| ~ 2^{}, at least 2^{CM_DISCRIMINANT_BITS} is needed",
+            cm_discriminant.bits()
+        ),
+    });
+
+    Ok(SecurityReport {
+        curve_order,
+        trace,
+        rho_bits: rho,
+        embedding_degree,
+        twist_order,
+        twist_factorization,
+        twist_rho_bits,
+        cm_discriminant,
+        checks,
+    })
+}
+
+fn to_bigint(x: &BigUint) -> BigInt {
+    BigInt::from(x.clone())
+}
+
+// log2(x) with precision of f64
+fn log2(x: &BigUint) -> f64 {
+    let shift = x.bits().saturating_sub(64);
+    (x >> shift).to_f64().unwrap().log2() + shift as f64
+}
+
+// sqrt(pi / 4) * sqrt(n) = 0.886 * sqrt(n) additions
+fn rho_bits(n: &BigUint) -> f64 {
+    match n.is_zero() {
+        true => 0.0,
+        false => (0.886_f64).log2() + log2(n) / 2.0,
+    }
+}
+
+// the least k <= MAX_EMBEDDING_DEGREE, such that q^k = 1 mod n
+fn embedding_degree(q: &BigUint, n: &BigUint) -> Option<u64> {
+    let q = q % n;
+    if q.is_zero() {
+        return None;
+    }
+    let mut x = q.clone();
+    for k in 1..=MAX_EMBEDDING_DEGREE {
+        if x.is_one() {
+            return Some(k);
+        }
+        x = (&x * &q) % n;
+    }
+    None
+}
+
+// fundamental discriminant of Q(sqrt(-m)) for m = 4q - t^2 > 0
+fn cm_discriminant(m: &BigInt) -> BigInt {
+    let factorization = factorize(m.magnitude());
+    let square_free = factorization
+        .factors
+        .iter()
+        .filter(|(_, e)| e & 1 == 1)
+        .fold(factorization.cofactor.clone(), |acc, (p, _)| acc * p);
+    let d = -to_bigint(&square_free);
+    match (&square_free % 4_u8).to_u8() == Some(3) {
+        true => d,
+        false => d * 4,
+    }
+}
@@ -0,0 +1,119 @@
+use crate::helpers::take_by_bigint_module;
+use num_bigint::BigInt;
+use num_traits::{One, Zero};
+
+/// **legendre_symbol** -- (a / p) = a^((p - 1) / 2) mod p for odd prime p,
+/// returns 1 for quadratic residues, -1 for non-residues and 0 if p | a
+pub fn legendre_symbol(a: &BigInt, p: &BigInt) -> i8 {
+    let a = take_by_bigint_module(&(a % p), p);
+    let r = a.modpow(&((p - BigInt::one()) >> 1), p);
+    match r {
+        r if r.is_zero() => 0,
+        r if r.is_one() => 1,
+        _ => -1,
+    }
+}
+
+/// **jacobi_symbol** -- (a / n) for odd positive n, is computed with quadratic reciprocity
+/// without exponentiation, so it's the same as Legendre symbol for prime n, but much faster.
+/// Returns None for even or non-positive n, the symbol isn't defined for them
+pub fn jacobi_symbol(a: &BigInt, n: &BigInt) -> Option<i8> {
+    if n.sign() != num_bigint::Sign::Plus || !n.bit(0) {
+        return None;
+    }
+    let mut a = take_by_bigint_module(&(a % n), n);
+    let mut n = n.clone();
+    let mut result = 1;
+    while !a.is_zero() {
+        // (2 / n) = -1 if n = 3, 5 mod 8
+        let e = a.trailing_zeros().unwrap_or(0);
+        a >>= e;
+        let n_mod_8 = n.iter_u64_digits().next().unwrap_or(0) & 7;
+        if e & 1 == 1 && (n_mod_8 == 3 || n_mod_8 == 5) {
+            result = -result;
+        }
+        // (a / n) = -(n / a) if a = n = 3 mod 4
+        if n_mod_8 & 3 == 3 && a.iter_u64_digits().next().unwrap_or(0) & 3 == 3 {
+            result = -result;
+        }
+        (a, n) = (&n % &a, a);
+    }
+    match n.is_one() {
+        true => Some(result),
+        false => Some(0),
+    }
+}
+
+/// **sqrt_mod** -- square root of a modulo odd prime p, returns None for non-residues.
+/// Fast paths: a^((p + 1) / 4) for p = 3 mod 4 and Atkin's algorithm for p = 5 mod 8,
+/// Tonelli-Shanks otherwise. Which one of the two roots r and p - r is returned isn't specified
+pub fn sqrt_mod(a: &BigInt, p: &BigInt) -> Option<BigInt> {
+    let a = take_by_bigint_module(&(a % p), p);
+    if a.is_zero() {
+        return Some(a);
+    }
+    if jacobi_symbol(&a, p) != Some(1) {
+        return None;
+    }
+    let p_mod_8 = p.iter_u64_digits().next().unwrap_or(0) & 7;
+    let r = match p_mod_8 {
+        3 | 7 => a.modpow(&((p + BigInt::one()) >> 2), p),
+        5 => atkin(&a, p),
+        _ => tonelli_shanks(&a, p)?,
+    };
+    // p isn't checked for primality, the algorithms above give garbage for composite p,
+    // so the root is verified (Tonelli-Shanks also gives up if there's no small non-residue)
+    match (&r * &r) % p == a {
+        true => Some(r),
+        false => None,
+    }
+}
+
+// p = 5 mod 8: b = (2a)^((p - 5) / 8), i = 2ab^2 (i^2 = -1), r = ab(i - 1)
+fn atkin(a: &BigInt, p: &BigInt) -> BigInt {
+    let a2: BigInt = (a << 1) % p;
+    let b = a2.modpow(&((p - BigInt::from(5_u8)) >> 3), p);
+    let i = (&a2 * &b * &b) % p;
+    take_by_bigint_module(&((a * &b * (i - BigInt::one())) % p), p)
+}
+
+// p - 1 = s * 2^e, where s is odd, a has to be a quadratic residue.
+// The least non-residue of prime p is less than 2 * ln(p)^2 (under GRH), so the search
+// is bounded by 2 * bits(p)^2, it fails for composite p like odd perfect squares
+// (all Jacobi symbols are 0 or 1 then)
+fn tonelli_shanks(a: &BigInt, p: &BigInt) -> Option<BigInt> {
+    let p_minus_1 = p - BigInt::one();
+    let e = p_minus_1.trailing_zeros()?;
+    let s = &p_minus_1 >> e;
+    let bound = BigInt::from(2 * p.bits() * p.bits());
+    let mut z = BigInt::from(2_u8);
+    while jacobi_symbol(&z, p) != Some(-1) {
+        z += 1;
+        if z > bound || z >= *p {
+            return None;
+        }
+    }
+
+    let mut m = e;
+    let mut c = z.modpow(&s, p);
+    let mut t = a.modpow(&s, p);
+    let mut r = a.modpow(&((&s + BigInt::one()) >> 1), p);
+    while !t.is_one() {
+        // the least i, such that t^(2^i) = 1
+        let mut i = 0;
+        let mut t2 = t.clone();
+        while !t2.is_one() {
+            t2 = (&t2 * &t2) % p;
+            i += 1;
+            if i == m {
+                return None;
+            }
+        }
+        let b = c.modpow(&(BigInt::one() << (m - i - 1)), p);
+        m = i;
+        c = (&b * &b) % p;
+        t = (&t * &c) % p;
+        r = (&r * &b) % p;
+    }
+    Some(r)
+}
@@ -0,0 +1,81 @@
+use crate::affine_point::EcPointA;
+use crate::factorization::factorize;
+use crate::projective_point::EcPointP;
+use crate::{EcError, EcInfo};
+use num_bigint::{BigInt, BigUint};
+use num_traits::One;
+
+/// **point_order** -- the least k > 0, such that k * P = O. Starts from the group order n * h
+/// and divides it by its prime factors p_i, while (k / p_i) * P = O, so n * h has to be factored
+pub fn point_order(info: &EcInfo, p: &EcPointP) -> crate::Result<BigUint> {
+    let ec = &info.ecurve;
+    if p.is_inf() {
+        return Ok(BigUint::one());
+    }
+    if !ec.check_projective_point(p) {
+        return Err(EcError::PointNotOnCurve(format!("({p})")));
+    }
+    let group_order = &info.n * &info.h;
+    if !ec.proj_point_mul_any(p, &group_order).is_inf() {
+        return Err(EcError::IncorrectParameters(format!(
+            "n * h * ({p}) != O, n * h isn't the group order: {group_order}"
+        )));
+    }
+    let factorization = factorize(&group_order);
+    if !factorization.is_complete() {
+        return Err(EcError::IncorrectParameters(format!(
+            "n * h isn't fully factored, composite part: {}",
+            factorization.cofactor
+        )));
+    }
+
+    let mut order = group_order;
+    for (prime, e) in factorization.factors {
+        for _ in 0..e {
+            let candidate = &order / &prime;
+            if !ec.proj_point_mul_any(p, &candidate).is_inf() {
+                break;
+            }
+            order = candidate;
+        }
+    }
+    Ok(order)
+}
+
+/// **is_in_subgroup** -- checks that P lies on the curve and belongs to the subgroup
+/// generated by bp, i.e. n * P = O (point at infinity belongs to it too)
+pub fn is_in_subgroup(info: &EcInfo, p: &EcPointP) -> bool {
+    p.is_inf()
+        || (info.ecurve.check_projective_point(p)
+            && info.ecurve.proj_point_mul_any(p, &info.n).is_inf())
+}
+
+/// **clear_cofactor** -- h * P, maps any point of the curve into the subgroup of order n
+pub fn clear_cofactor(info: &EcInfo, p: &EcPointP) -> EcPointP {
+    match info.h.is_one() {
+        true => p.clone(),
+        false => info.ecurve.proj_point_mul_any(p, &info.h),
+    }
+}
+
+/// **validate_public_key** -- Elliptic Curve Public Key Validation Primitive
+/// (SEC 1, section 3.2.2.1): Q != O, 0 <= x, y < q, Q lies on the curve and n * Q = O
+pub fn validate_public_key(info: &EcInfo, q: &EcPointA) -> crate::Result<()> {
+    let ec = &info.ecurve;
+    let err = |msg: String| Err(EcError::InvalidPublicKey(msg));
+    if q.is_inf() {
+        return err("point at infinity".to_string());
+    }
+    let point = format!("({}, {})", q.x, q.y);
+    let in_range = |c: &BigInt| c.sign() != num_bigint::Sign::Minus && *c < ec.q;
+    if !in_range(&q.x) || !in_range(&q.y) {
+        return err(format!("coordinates of {point} aren't in range [0, q - 1]"));
+    }
+    if !ec.check_affine_point(q) {
+        return err(format!("{point} doesn't lie on the curve"));
+    }
+    if !ec.proj_point_mul_any(&q.to_projective(), &info.n).is_inf() {
+        return err(format!("n * {point} != O"));
+    }
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use crate::EcError;
+use num_bigint::BigUint;
+use std::fmt::Debug;
+
+/// **Field** -- finite field. The field object holds parameters (modulus, reduction polynomial),
+/// elements are plain values, e.g. `BigPrimeField` or `BinaryField` with BigInt elements
+pub trait Field {
+    type Element: Clone + PartialEq + Debug;
+
+    fn zero(&self) -> Self::Element;
+    fn one(&self) -> Self::Element;
+    fn add(&self, a: &Self::Element, b: &Self::Element) -> Self::Element;
+    fn sub(&self, a: &Self::Element, b: &Self::Element) -> Self::Element;
+    fn mul(&self, a: &Self::Element, b: &Self::Element) -> Self::Element;
+    fn inverse(&self, a: &Self::Element) -> crate::Result<Self::Element>;
+    /// **to_integer** -- Field-Element-to-Integer conversion, SEC 1, section 2.3.9
+    fn to_integer(&self, a: &Self::Element) -> BigUint;
+    /// **bits** -- the largest bit length of `to_integer` values: bits of q for GF(q), m for GF(2^m)
+    fn bits(&self) -> u64;
+
+    fn neg(&self, a: &Self::Element) -> Self::Element {
+        self.sub(&self.zero(), a)
+    }
+
+    fn square(&self, a: &Self::Element) -> Self::Element {
+        self.mul(a, a)
+    }
+
+    fn is_zero(&self, a: &Self::Element) -> bool {
+        *a == self.zero()
+    }
+
+    /// **pow** -- a^e with square-and-multiply
+    fn pow(&self, a: &Self::Element, e: &BigUint) -> Self::Element {
+        let mut r = self.one();
+        for i in (0..e.bits()).rev() {
+            r = self.square(&r);
+            if e.bit(i) {
+                r = self.mul(&r, a);
+            }
+        }
+        r
+    }
+}
+
+/// **Group** -- group law on points of a curve model. The curve holds parameters,
+/// points are plain values in any coordinate system the curve works with
+pub trait Group {
+    type Point: Clone + PartialEq + Debug;
+
+    fn identity(&self) -> Self::Point;
+    fn is_identity(&self, p: &Self::Point) -> bool;
+    fn add(&self, a: &Self::Point, b: &Self::Point) -> Self::Point;
+    fn neg(&self, a: &Self::Point) -> Self::Point;
+    /// **normalize** -- canonical representation of the point (e.g. Z = 1),
+    /// so equal points become equal values
+    fn normalize(&self, p: &Self::Point) -> crate::Result<Self::Point>;
+
+    fn double(&self, a: &Self::Point) -> Self::Point {
+        self.add(a, a)
+    }
+
+    fn sub(&self, a: &Self::Point, b: &Self::Point) -> Self::Point {
+        self.add(a, &self.neg(b))
+    }
+
+    /// **mul** -- k * P with double-and-add
+    fn mul(&self, a: &Self::Point, k: &BigUint) -> Self::Point {
+        let mut r = self.identity();
+        for i in (0..k.bits()).rev() {
+            r = self.double(&r);
+            if k.bit(i) {
+                r = self.add(&r, a);
+            }
+        }
+        r
+    }
+
+    /// **multi_mul** -- k_1 * P_1 + ... + k_m * P_m
+    fn multi_mul(&self, terms: &[(Self::Point, BigUint)]) -> Self::Point {
+        terms
+            .iter()
+            .fold(self.identity(), |r, (p, k)| self.add(&r, &self.mul(p, k)))
+    }
+
+    /// **point_eq** -- P = Q as points of the curve, not as coordinates
+    fn point_eq(&self, a: &Self::Point, b: &Self::Point) -> bool {
+        match (self.normalize(a), self.normalize(b)) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+/// **ElementOf** -- element type of the base field of the curve
+pub type ElementOf<C> = <<C as CurveModel>::BaseField as Field>::Element;
+
+/// **CurveModel** -- curve equation over a base field together with its group law
+pub trait CurveModel: Group {
+    type BaseField: Field;
+
+    fn base_field(&self) -> Self::BaseField;
+    /// **contains** -- point satisfies the curve equation
+    fn contains(&self, p: &Self::Point) -> bool;
+    /// **to_affine** -- affine coordinates (x, y), fails for the identity if it lies at infinity
+    fn to_affine(&self, p: &Self::Point) -> crate::Result<(ElementOf<Self>, ElementOf<Self>)>;
+    /// **is_same_curve** -- the same equation over the same field, settings of scalar
+    /// multiplication (coordinates, wNAF, GLV) aren't compared
+    fn is_same_curve(&self, other: &Self) -> bool;
+}
+
+/// **PointEncoding** -- serialization of points to octet strings
+pub trait PointEncoding: Group {
+    fn encode(&self, p: &Self::Point) -> crate::Result<Vec<u8>>;
+    /// **decode** -- parses the octet string, the point has to lie on the curve
+    fn decode(&self, bytes: &[u8]) -> crate::Result<Self::Point>;
+}
+
+/// **PointOf** -- point type of the curve of domain parameters
+pub type PointOf<D> = <<D as DomainParameters>::Curve as Group>::Point;
+
+/// **DomainParameters** -- curve, generator of its prime order subgroup, order n and
+/// cofactor h, e.g. `EcInfo` or `BinaryEcInfo`
+pub trait DomainParameters {
+    type Curve: CurveModel;
+
+    fn curve(&self) -> &Self::Curve;
+    fn generator(&self) -> PointOf<Self>;
+    fn order(&self) -> BigUint;
+    fn cofactor(&self) -> BigUint;
+
+    /// **base_point_mul** -- k * G for secret k, constant-time as `secret_mul`
+    fn base_point_mul(&self, k: &BigUint) -> PointOf<Self>;
+
+    /// **secret_mul** -- k * P for secret k, every model has to supply constant-time
+    /// multiplication, `Group::mul` is variable-time
+    fn secret_mul(&self, p: &PointOf<Self>, k: &BigUint) -> PointOf<Self>;
+
+    /// **validate_point** -- public key validation: P != O, P lies on the curve and n * P = O
+    fn validate_point(&self, p: &PointOf<Self>) -> crate::Result<()> {
+        let curve = self.curve();
+        if curve.is_identity(p) {
+            return Err(EcError::InvalidPublicKey("point at infinity".to_string()));
+        }
+        if !curve.contains(p) {
+            return Err(EcError::InvalidPublicKey(format!(
+                "({p:?}) doesn't lie on the curve"
+            )));
+        }
+        match curve.is_identity(&curve.mul(p, &self.order())) {
+            true => Ok(()),
+            false => Err(EcError::InvalidPublicKey(format!(
+                "n * ({p:?}) != O, n: {}",
+                self.order()
+            ))),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::extension_field::Fp2;
+use std::fmt::{Debug, Display, Formatter};
+
+/// **EcPointTwist** -- affine point (x, y) with coordinates in GF(p^2) on sextic twist
+/// E'(GF(p^2)) of pairing-friendly curve, i.e. element of G2
+#[derive(Clone, Eq, PartialEq)]
+pub struct EcPointTwist {
+    pub x: Fp2,
+    pub y: Fp2,
+    pub is_inf: bool,
+}
+
+impl EcPointTwist {
+    pub fn new(x: &Fp2, y: &Fp2) -> Self {
+        EcPointTwist {
+            x: x.clone(),
+            y: y.clone(),
+            is_inf: false,
+        }
+    }
+
+    pub fn is_inf(&self) -> bool {
+        self.is_inf
+    }
+
+    /// **neutral** -- point at infinity
+    pub fn neutral() -> EcPointTwist {
+        EcPointTwist {
+            x: Fp2::zero(),
+            y: Fp2::one(),
+            is_inf: true,
+        }
+    }
+}
+
+impl Debug for EcPointTwist {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "x: {}, y: {}, is_inf: {}", self.x, self.y, self.is_inf)
+    }
+}
+
+impl Display for EcPointTwist {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "x: {}, y: {}, is_inf: {}", self.x, self.y, self.is_inf)
+    }
+}
@@ -1,10 +1,43 @@
 #[cfg(test)]
 mod tests {
     use num_bigint::{BigInt, BigUint};
-    use num_traits::{Num, One};
+    use num_traits::{Num, One, Zero};
     use rust_ec::affine_point::EcPointA;
+    use rust_ec::binary_curve::{BinaryCurve, BinaryEcInfo, PreGeneratedBinaryParams};
+    use rust_ec::binary_field::BinaryField;
+    use rust_ec::curve_point::CurvePoint;
+    use rust_ec::discrete_log::{bsgs, pollard_lambda, pollard_rho};
+    use rust_ec::edwards::{EdPointE, EdwardsCurve, EdwardsEcInfo, PreGeneratedEdwardsParams};
+    use rust_ec::encoding::PointFormat;
+    use rust_ec::extension_field::{Fp12, Fp2, Fp6};
+    use rust_ec::factorization::factorize;
+    use rust_ec::field::{BigPrimeField, PrimeField};
+    use rust_ec::generation::{
+        generate_curve, is_probable_prime, seed_to_c, verify_seed, SeedHash,
+    };
+    use rust_ec::glv::GlvEndomorphism;
+    use rust_ec::hash_to_curve::{
+        expand_message_xmd, expand_message_xof, hash_to_field, HashToCurveSuite, PreGeneratedSuite,
+        XmdHash, XofHash,
+    };
+    use rust_ec::helpers::{inverse, take_by_bigint_module, to_wnaf};
+    use rust_ec::jacobian_point::EcPointJ;
+    use rust_ec::ld_point::EcPointLD;
+    use rust_ec::modular_polynomial::ModularPolynomial;
+    use rust_ec::montgomery::{decode_u_coordinate, x25519, x448, MontgomeryCurve};
+    use rust_ec::pairing::{PairingFamily, PairingInfo, PreGeneratedPairingParams};
+    use rust_ec::point_counting::{
+        bsgs_order, naive_order, quadratic_twist, schoof_order, sea_order, trace_mod_l,
+    };
     use rust_ec::projective_point::EcPointP;
-    use rust_ec::{ECurve, Params, PreGeneratedParams};
+    use rust_ec::security::Criterion;
+    use rust_ec::sqrt::{jacobi_symbol, legendre_symbol, sqrt_mod};
+    use rust_ec::traits::{CurveModel, DomainParameters, Field, Group, PointEncoding};
+    use rust_ec::twist_point::EcPointTwist;
+    use rust_ec::{
+        CoordinateSystem, ECurve, EcError, EcInfo, FieldArithmetic, Params, PreGeneratedParams,
+    };
+    use std::sync::Arc;
 
     #[test]
     fn testing_ec_creation() {
@@ -77,12 +110,21 @@ mod tests {
 
     #[test]
     fn ec_add() {
+        check_ec_add(CoordinateSystem::Projective);
+        // curve has prime order 11, so complete formulas are applicable
+        check_ec_add(CoordinateSystem::Complete);
+    }
+
+    // the body is the original ec_add test, kept as is
+    #[allow(unused_variables, clippy::format_in_format_args)]
+    fn check_ec_add(coordinates: CoordinateSystem) {
         let curve = ECurve::new(Params {
             a: BigInt::from(11_u8),
             b: BigInt::from(7_u8),
             q: BigInt::from(13_u8),
         })
-        .unwrap();
+        .unwrap()
+        .with_coordinates(coordinates);
 
         let p1 = EcPointP::new(&BigInt::from(6), &BigInt::from(4), &BigInt::from(1));
         let p2 = curve.proj_point_add(&p1, &p1);
@@ -278,12 +320,21 @@ mod tests {
 
     #[test]
     fn ec_mul() {
+        check_ec_mul(CoordinateSystem::Projective);
+        // curve has prime order 11, so complete formulas are applicable
+        check_ec_mul(CoordinateSystem::Complete);
+    }
+
+    // the body is the original ec_mul test, kept as is
+    #[allow(unused_variables)]
+    fn check_ec_mul(coordinates: CoordinateSystem) {
         let curve = ECurve::new(Params {
             a: BigInt::from(11_u8),
             b: BigInt::from(7_u8),
             q: BigInt::from(13_u8),
         })
-        .unwrap();
+        .unwrap()
+        .with_coordinates(coordinates);
         let order = BigUint::from(11_u8);
 
         let p1 = EcPointP::new(&BigInt::from(6), &BigInt::from(4), &BigInt::from(1));
@@ -394,6 +445,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn ec_order_multiples() {
+        for coordinates in [CoordinateSystem::Projective, CoordinateSystem::Complete] {
+            let curve = ECurve::new(Params {
+                a: BigInt::from(11_u8),
+                b: BigInt::from(7_u8),
+                q: BigInt::from(13_u8),
+            })
+            .unwrap()
+            .with_coordinates(coordinates);
+
+            let p1 = EcPointP::new(&BigInt::from(6), &BigInt::from(4), &BigInt::from(1));
+            let p10 = curve.proj_point_mul(&p1, &BigUint::from(10_u8));
+            let p11 = curve.proj_point_add(&p10, &p1);
+            let p12 = curve.proj_point_add(&p11, &p1);
+            assert_eq!(p11, EcPointP::neutral());
+
+            let p22_d = curve.proj_point_add(&p10, &p12);
+            let p22_m = curve.proj_point_mul(&p1, &BigUint::from(22_u8));
+            assert!(
+                p22_d.is_inf() && p22_m.is_inf(),
+                "p22_d: {p22_d:?}, p22_m: {p22_m:?}"
+            );
+        }
+    }
+
     #[test]
     fn p192() {
         let curve = ECurve::new(Params::from(PreGeneratedParams::P192)).unwrap();
@@ -439,4 +516,2608 @@ mod tests {
                 && curve.check_projective_point(&g_rand3)
         )
     }
+
+    fn check_known_answers(
+        params: PreGeneratedParams,
+        g2: (&str, &str),
+        k: &str,
+        gk: (&str, &str),
+    ) {
+        let ec_info = EcInfo::from(&params);
+        let parse = |x: &str| BigInt::from_str_radix(x, 16).unwrap();
+        let complete = ec_info
+            .ecurve
+            .clone()
+            .with_coordinates(CoordinateSystem::Complete);
+
+        for curve in [&ec_info.ecurve, &complete] {
+            assert!(curve.check_projective_point(&ec_info.bp), "{params:?}");
+            assert!(
+                curve.proj_point_mul(&ec_info.bp, &ec_info.n).is_inf(),
+                "{params:?}"
+            );
+            assert_eq!(
+                curve
+                    .transform_proj_point(&curve.proj_point_add(&ec_info.bp, &ec_info.bp))
+                    .unwrap(),
+                EcPointP::new(&parse(g2.0), &parse(g2.1), &BigInt::one()),
+                "{params:?}"
+            );
+            assert_eq!(
+                curve
+                    .transform_proj_point(
+                        &curve
+                            .proj_point_mul(&ec_info.bp, &BigUint::from_str_radix(k, 16).unwrap())
+                    )
+                    .unwrap(),
+                EcPointP::new(&parse(gk.0), &parse(gk.1), &BigInt::one()),
+                "{params:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn p224() {
+        check_known_answers(
+            PreGeneratedParams::P224,
+            (
+                "706a46dc76dcb76798e60e6d89474788d16dc18032d268fd1a704fa6",
+                "1c2b76a7bc25e7702a704fa986892849fca629487acf3709d2e4e8bb",
+            ),
+            "c51e4753afdec1e6b6c6a5b992f43f8dd0c7a8933072708b6522468b2ffb06fd",
+            (
+                "d34c32b17fb23d018d9bf7c96979a99eb8cd0b394b9883e6ee222741",
+                "94b309737afc4eeb5b756a901775af891bddf077f379c09ebcdcbf74",
+            ),
+        );
+    }
+
+    #[test]
+    fn p256() {
+        check_known_answers(
+            PreGeneratedParams::P256,
+            (
+                "7cf27b188d034f7e8a52380304b51ac3c08969e277f21b35a60b48fc47669978",
+                "07775510db8ed040293d9ac69f7430dbba7dade63ce982299e04b79d227873d1",
+            ),
+            "c51e4753afdec1e6b6c6a5b992f43f8dd0c7a8933072708b6522468b2ffb06fd",
+            (
+                "942c9f408ead9d82d34a1b9a6a827ebe3e2ddf782b448d23be1b6143988ccef4",
+                "8c9eaf6c0d14d992fc63bad3e2496be2eee61cb5b97f65f428ca94a5d0ee19a1",
+            ),
+        );
+    }
+
+    #[test]
+    fn p384() {
+        check_known_answers(
+            PreGeneratedParams::P384,
+            (
+                "08d999057ba3d2d969260045c55b97f089025959a6f434d651d207d19fb96e9e4fe0e86ebe0e64f85b96a9c75295df61",
+                "8e80f1fa5b1b3cedb7bfe8dffd6dba74b275d875bc6cc43e904e505f256ab4255ffd43e94d39e22d61501e700a940e80",
+            ),
+            "c51e4753afdec1e6b6c6a5b992f43f8dd0c7a8933072708b6522468b2ffb06fd",
+            (
+                "b71966062f2c6ebd324b38723ba871ce136a7192754d0d7d8e934c81cef263499bb4ff7589095c2226f57f1d8721da99",
+                "a62144da11be9a85f10579495f4287a3d2251b42a315d33505519f0856e5863de347c639bfe874fda7e5deffea0f1faa",
+            ),
+        );
+    }
+
+    #[test]
+    fn p521() {
+        check_known_answers(
+            PreGeneratedParams::P521,
+            (
+                "433c219024277e7e682fcb288148c282747403279b1ccc06352c6e5505d769be97b3b204da6ef55507aa104a3a35c5af41cf2fa364d60fd967f43e3933ba6d783d",
+                "f4bb8cc7f86db26700a7f3eceeeed3f0b5c6b5107c4da97740ab21a29906c42dbbb3e377de9f251f6b93937fa99a3248f4eafcbe95edc0f4f71be356d661f41b02",
+            ),
+            "c51e4753afdec1e6b6c6a5b992f43f8dd0c7a8933072708b6522468b2ffb06fd",
+            (
+                "1bb39f500da5e267e9e6673d895a7e7e370ce7878e77791b61397996bb355498e50f76a671b20cbb85fbac255332555bcc135a53626fb2613b623dde5ff72271620",
+                "1b678f087404684d69786ada90371d5523cb3f36c4e5f92b7a0f424c0d0ff90190f89b2de12ecdb5d2a8db1b2a2d8d79fdf63ecf504ae7ffbc5778ad29152e54d4e",
+            ),
+        );
+    }
+
+    #[test]
+    fn ec_info_validation() {
+        let curve = ECurve::new(Params {
+            a: BigInt::from(11_u8),
+            b: BigInt::from(7_u8),
+            q: BigInt::from(13_u8),
+        })
+        .unwrap();
+        let p1 = EcPointP::new(&BigInt::from(6), &BigInt::from(4), &BigInt::from(1));
+
+        assert!(EcInfo::new(
+            p1.clone(),
+            BigUint::from(11_u8),
+            BigUint::one(),
+            curve.clone()
+        )
+        .is_ok());
+        // wrong order
+        assert!(EcInfo::new(
+            p1.clone(),
+            BigUint::from(7_u8),
+            BigUint::one(),
+            curve.clone()
+        )
+        .is_err());
+        // point isn't on the curve
+        let p_wrong = EcPointP::new(&BigInt::from(6), &BigInt::from(5), &BigInt::from(1));
+        assert!(EcInfo::new(p_wrong, BigUint::from(11_u8), BigUint::one(), curve.clone()).is_err());
+        assert!(EcInfo::new(
+            EcPointP::neutral(),
+            BigUint::from(11_u8),
+            BigUint::one(),
+            curve
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn secp256k1() {
+        check_known_answers(
+            PreGeneratedParams::Secp256k1,
+            (
+                "c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+                "1ae168fea63dc339a3c58419466ceaeef7f632653266d0e1236431a950cfe52a",
+            ),
+            "aa5e28d6a97a2479a65527f7290311a3624d4cc0fa1578598ee3c2613bf99522",
+            (
+                "34f9460f0e4f08393d192b3c5133a6ba099aa0ad9fd54ebccfacdfa239ff49c6",
+                "0b71ea9bd730fd8923f6d25a7a91e7dd7728a960686cb5a901bb419e0f2ca232",
+            ),
+        );
+    }
+
+    #[test]
+    fn glv_mul() {
+        let ec_info = EcInfo::from(PreGeneratedParams::Secp256k1);
+        let glv = ec_info.ecurve.get_endomorphism().unwrap();
+        // the same curve, but without endomorphism, so plain double-and-add is used
+        let plain_curve = ECurve::new(Params::from(PreGeneratedParams::Secp256k1)).unwrap();
+        let n = BigInt::from(ec_info.n.clone());
+
+        for k in [
+            BigUint::zero(),
+            BigUint::one(),
+            BigUint::from(2_u8),
+            BigUint::from(0xdeadbeef_u32),
+            &ec_info.n - BigUint::one(),
+            &ec_info.n + BigUint::from(5_u8),
+            BigUint::from_str_radix(
+                "aa5e28d6a97a2479a65527f7290311a3624d4cc0fa1578598ee3c2613bf99522",
+                16,
+            )
+            .unwrap(),
+            BigUint::from_str_radix(
+                "7e2b897b8cebc6361663ad410835639826d590f393d90a9538881735256dfae3",
+                16,
+            )
+            .unwrap(),
+        ] {
+            let (k1, k2) = glv.decompose(&k);
+            assert!(k1.bits() <= 129 && k2.bits() <= 129, "k1: {k1}, k2: {k2}");
+            assert_eq!(
+                take_by_bigint_module(&((&k1 + &k2 * &glv.lambda) % &n), &n),
+                BigInt::from(k.clone()) % &n
+            );
+
+            let (glv_res, plain_res) = (
+                ec_info.ecurve.proj_point_mul(&ec_info.bp, &k),
+                plain_curve.proj_point_mul(&ec_info.bp, &k),
+            );
+            assert_eq!(
+                glv_res.to_affine(&ec_info.ecurve).unwrap(),
+                plain_res.to_affine(&plain_curve).unwrap(),
+                "k: {k}"
+            );
+        }
+
+        // φ(G) = λG
+        assert_eq!(
+            glv.apply(&ec_info.ecurve, &ec_info.bp)
+                .to_affine(&ec_info.ecurve)
+                .unwrap(),
+            plain_curve
+                .proj_point_mul(&ec_info.bp, &glv.lambda.to_biguint().unwrap())
+                .to_affine(&plain_curve)
+                .unwrap()
+        );
+
+        // y^2 = x^3 + 3 over GF(10009) has order 3 * 3391, φ(P) = λP holds only for n * P = O,
+        // so points outside of the subgroup are multiplied without endomorphism in subgroup checks
+        let toy = ECurve::new(Params {
+            a: BigInt::zero(),
+            b: BigInt::from(3_u8),
+            q: BigInt::from(10009_u16),
+        })
+        .unwrap()
+        .with_endomorphism(GlvEndomorphism {
+            beta: BigInt::from(8964_u16),
+            lambda: BigInt::from(2835_u16),
+            n: BigInt::from(3391_u16),
+            a1: BigInt::from(55_u8),
+            b1: BigInt::from(-6),
+            a2: BigInt::from(6_u8),
+            b2: BigInt::from(61_u8),
+        });
+        let point =
+            |x: u16, y: u16| EcPointP::new(&BigInt::from(x), &BigInt::from(y), &BigInt::one());
+        let (n, h) = (BigUint::from(3391_u16), BigUint::from(3_u8));
+        let g = point(350, 1676);
+        let info = EcInfo::new(g.clone(), n.clone(), h.clone(), toy.clone()).unwrap();
+        let off = point(1, 2);
+        assert!(!info.is_in_subgroup(&off));
+        assert!(info.validate_proj_public_key(&off).is_err());
+        assert!(matches!(
+            EcInfo::new(off.clone(), n.clone(), h.clone(), toy.clone()),
+            Err(EcError::IncorrectBasePoint(_))
+        ));
+        let cleared = info.clear_cofactor(&off);
+        assert!(!cleared.is_inf() && info.is_in_subgroup(&cleared));
+        for k in [1_u16, 2, 1000, 3390, 3392, 9999] {
+            let k = BigUint::from(k);
+            assert_eq!(
+                toy.proj_point_mul(&g, &k).to_affine(&toy).unwrap(),
+                toy.proj_point_mul_ct(&g, &k, &n).to_affine(&toy).unwrap(),
+                "k: {k}"
+            );
+        }
+    }
+
+    #[test]
+    fn brainpool_p256r1() {
+        check_known_answers(
+            PreGeneratedParams::BrainpoolP256r1,
+            (
+                "743cf1b8b5cd4f2eb55f8aa369593ac436ef044166699e37d51a14c2ce13ea0e",
+                "36ed163337deba9c946fe0bb776529da38df059f69249406892ada097eeb7cd4",
+            ),
+            "c51e4753afdec1e6b6c6a5b992f43f8dd0c7a8933072708b6522468b2ffb06fd",
+            (
+                "937c72325ea4f92f0c81026e9ecfaa846c3be9a771471f1d75049c19d5cb2361",
+                "4d87b7e2fb41ad8bafce4cca01ee7d822261e5a546e148844403142b107e562c",
+            ),
+        );
+    }
+
+    // RFC 7027, appendix A.2: Q_A = d_A * G
+    #[test]
+    fn brainpool_p384r1() {
+        check_known_answers(
+            PreGeneratedParams::BrainpoolP384r1,
+            (
+                "2282bc382a2f4dfcb95c3495d7b4fd590ad520b3eb6be4d6ec2f80c4e0f70df87c4ba74a09b553ebb427b58df9d59fca",
+                "0edda83773ac68735768d14a24f37a57ce9bedbc170921ce4d89dd051728fc3eb4b4ea69ab64fc288f1b29502b6e1d30",
+            ),
+            "1e20f5e048a5886f1f157c74e91bde2b98c8b52d58e5003d57053fc4b0bd65d6f15eb5d1ee1610df870795143627d042",
+            (
+                "68b665dd91c195800650cdd363c625f4e742e8134667b767b1b476793588f885ab698c852d4a6e77a252d6380fcaf068",
+                "55bc91a39c9ec01dee36017b7d673a931236d2f1f5c83942d049e3fa20607493e0d038ff2fd30c2ab67d15c85f7faa59",
+            ),
+        );
+    }
+
+    // RFC 7027, appendix A.3: Q_A = d_A * G
+    #[test]
+    fn brainpool_p512r1() {
+        check_known_answers(
+            PreGeneratedParams::BrainpoolP512r1,
+            (
+                "9f4945f680edf9800a63285758f399b3d18d8141b8a18064a30d3035f4cb6581957877f3a8f0f72597116e702915a4f4f698f404089a4cc5080447def02f4850",
+                "6d6b4b188b699c5649826b716292f29d149ce1238d3f1e0f5a2c366b03e5d1b2fdf99bb1709c700fa5c3b602b0960cbf63a42e4181fd929ce269ad21be592e71",
+            ),
+            "16302ff0dbbb5a8d733dab7141c1b45acbc8715939677f6a56850a38bd87bd59b09e80279609ff333eb9d4c061231fb26f92eeb04982a5f1d1764cad57665422",
+            (
+                "0a420517e406aac0acdce90fcd71487718d3b953efd7fbec5f7f27e28c6149999397e91e029e06457db2d3e640668b392c2a7e737a7f0bf04436d11640fd09fd",
+                "72e6882e8db28aad36237cd25d580db23783961c8dc52dfa2ec138ad472a0fcef3887cf62b623b2a87de5c588301ea3e5fc269b373b60724f5e82a6ad147fde7",
+            ),
+        );
+    }
+
+    #[test]
+    fn brainpool_twist_isomorphism() {
+        let k = BigUint::from_str_radix(
+            "7e2b897b8cebc6361663ad410835639826d590f393d90a9538881735256dfae3",
+            16,
+        )
+        .unwrap();
+        for (r1, t1) in [
+            (
+                PreGeneratedParams::BrainpoolP256r1,
+                PreGeneratedParams::BrainpoolP256t1,
+            ),
+            (
+                PreGeneratedParams::BrainpoolP384r1,
+                PreGeneratedParams::BrainpoolP384t1,
+            ),
+            (
+                PreGeneratedParams::BrainpoolP512r1,
+                PreGeneratedParams::BrainpoolP512t1,
+            ),
+        ] {
+            let (r1_info, t1_info) = (EcInfo::from(&r1), EcInfo::from(&t1));
+            let (r1_to_t1, t1_to_r1) = (
+                r1.twist_isomorphism().unwrap(),
+                t1.twist_isomorphism().unwrap(),
+            );
+            assert!(r1_to_t1.target().is_same_curve(&t1_info.ecurve), "{t1:?}");
+            assert!(t1_to_r1.target().is_same_curve(&r1_info.ecurve), "{r1:?}");
+            assert_eq!(r1_to_t1.map_proj(&r1_info.bp), t1_info.bp, "{r1:?}");
+            assert_eq!(t1_to_r1.map_proj(&t1_info.bp), r1_info.bp, "{t1:?}");
+            assert_eq!(
+                r1_to_t1.map_ec_info(&r1_info).unwrap().bp,
+                t1_info.bp,
+                "{r1:?}"
+            );
+
+            // computing on tX form gives the same results as on rX
+            let r1_res = r1_info.ecurve.proj_point_mul(&r1_info.bp, &k);
+            let t1_res = t1_info
+                .ecurve
+                .proj_point_mul(&r1_to_t1.map_proj(&r1_info.bp), &k);
+            assert_eq!(
+                t1_to_r1
+                    .map_affine(&t1_res.to_affine(&t1_info.ecurve).unwrap())
+                    .to_projective(),
+                r1_info.ecurve.transform_proj_point(&r1_res).unwrap(),
+                "{r1:?}"
+            );
+            assert!(r1_info.ecurve.check_affine_point(
+                &t1_to_r1.map_affine(&t1_res.to_affine(&t1_info.ecurve).unwrap())
+            ));
+        }
+        assert!(PreGeneratedParams::P256.twist_isomorphism().is_none());
+    }
+
+    fn from_hex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn x25519_rfc7748() {
+        // https://datatracker.ietf.org/doc/html/rfc7748#section-5.2
+        for (k, u, res) in [
+            (
+                "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+                "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+                "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552",
+            ),
+            (
+                "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+                "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493",
+                "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957",
+            ),
+        ] {
+            assert_eq!(
+                x25519(
+                    &from_hex(k).try_into().unwrap(),
+                    &from_hex(u).try_into().unwrap()
+                )
+                .to_vec(),
+                from_hex(res)
+            );
+        }
+
+        // one iteration of the RFC 7748 iterative test
+        let mut base = [0_u8; 32];
+        base[0] = 9;
+        assert_eq!(
+            x25519(&base, &base).to_vec(),
+            from_hex("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079")
+        );
+
+        // the most significant bit is masked, wrong lengths are rejected
+        assert_eq!(
+            decode_u_coordinate(&[0xff; 32], 255).unwrap(),
+            (BigInt::one() << 255) - 1
+        );
+        assert!(decode_u_coordinate(&[], 255).is_err());
+        assert!(decode_u_coordinate(&[9; 31], 255).is_err());
+        assert!(decode_u_coordinate(&[9; 33], 255).is_err());
+    }
+
+    #[test]
+    fn x448_rfc7748() {
+        // https://datatracker.ietf.org/doc/html/rfc7748#section-6.2
+        let mut base = [0_u8; 56];
+        base[0] = 5;
+        let alice_priv: [u8; 56] = from_hex("9a8f4925d1519f5775cf46b04b5800d4ee9ee8bae8bc5565d498c28dd9c9baf574a9419744897391006382a6f127ab1d9ac2d8c0a598726b").try_into().unwrap();
+        let bob_priv: [u8; 56] = from_hex("1c306a7ac2a0e2e0990b294470cba339e6453772b075811d8fad0d1d6927c120bb5ee8972b0d3e21374c9c921b09d1b0366f10b65173992d").try_into().unwrap();
+        let (alice_pub, bob_pub) = (x448(&alice_priv, &base), x448(&bob_priv, &base));
+        assert_eq!(alice_pub.to_vec(), from_hex("9b08f7cc31b7e3e67d22d5aea121074a273bd2b83de09c63faa73d2c22c5d9bbc836647241d953d40c5b12da88120d53177f80e532c41fa0"));
+        assert_eq!(bob_pub.to_vec(), from_hex("3eb7a829b0cd20f5bcfc0b599b6feccf6da4627107bdb0d4f345b43027d8b972fc3e34fb4232a13ca706dcb57aec3dae07bdc1c67bf33609"));
+        let shared = from_hex("07fff4181ac6cc95ec1c16a94a0f74d12da232ce40a77552281d282bb60c0b56fd2464c335543936521c24403085d59a449a5037514a879d");
+        assert_eq!(x448(&alice_priv, &bob_pub).to_vec(), shared);
+        assert_eq!(x448(&bob_priv, &alice_pub).to_vec(), shared);
+
+        assert_eq!(
+            x448(
+                &from_hex("3d262fddf9ec8e88495266fea19a34d28882acef045104d0d1aae121700a779c984c24f8cdd78fbff44943eba368f54b29259a4f1c600ad3").try_into().unwrap(),
+                &from_hex("06fce640fa3487bfda5f6cf2d5263f8aad88334cbd07437f020f08f9814dc031ddbdc38c19c6da2583fa5429db94ada18aa7a7fb4ef8a086").try_into().unwrap()
+            )
+            .to_vec(),
+            from_hex("ce3e4ff95a60dc6697da1db1d85e6afbdf79b50a2412d7546d5f239fe14fbaadeb445fc66a01b0779d98223961111e21766282f73dd96b6f")
+        );
+    }
+
+    #[test]
+    fn montgomery_weierstrass_model() {
+        let k = BigUint::from_str_radix(
+            "7e2b897b8cebc6361663ad410835639826d590f393d90a9538881735256dfae3",
+            16,
+        )
+        .unwrap();
+        for (params, bits) in [
+            (PreGeneratedParams::Curve25519, 255),
+            (PreGeneratedParams::Curve448, 448),
+        ] {
+            let montgomery_params = params.montgomery_form().unwrap();
+            let montgomery = MontgomeryCurve::from(&montgomery_params);
+            let ec_info = EcInfo::from(&params);
+            let base = montgomery_params.base_point();
+            assert!(montgomery.check_point(&base));
+            assert_eq!(
+                montgomery.from_weierstrass_proj_point(&ec_info.bp).unwrap(),
+                base
+            );
+
+            // u(kP) from the ladder coincides with kP computed on Weierstrass model
+            let weierstrass_res = montgomery
+                .from_weierstrass_proj_point(&ec_info.ecurve.proj_point_mul(&ec_info.bp, &k))
+                .unwrap();
+            assert!(montgomery.check_point(&weierstrass_res));
+            assert_eq!(
+                montgomery.ladder(&base.get_x(), &k, bits),
+                weierstrass_res.get_x()
+            );
+            // nP = O
+            assert!(montgomery.ladder(&base.get_x(), &ec_info.n, bits).is_zero());
+        }
+    }
+
+    #[test]
+    fn edwards_unified_addition() {
+        let curve = EdwardsCurve::from(PreGeneratedEdwardsParams::Edwards25519);
+        let order = PreGeneratedEdwardsParams::Edwards25519.order();
+        let b = PreGeneratedEdwardsParams::Edwards25519.base_point();
+        let neutral = EdPointE::neutral();
+        assert!(curve.check_point(&b) && curve.check_point(&neutral));
+
+        // the same formula for addition, doubling and neutral point
+        let b2 = curve.point_add(&b, &b);
+        assert_eq!(
+            curve.normalize(&b2).unwrap(),
+            curve.normalize(&curve.point_double(&b)).unwrap()
+        );
+        assert_eq!(
+            curve.to_affine(&b2).unwrap(),
+            (
+                BigInt::from_str_radix(
+                    "24727413235106541002554574571675588834622768167397638456726423682521233608206",
+                    10
+                )
+                .unwrap(),
+                BigInt::from_str_radix(
+                    "15549675580280190176352668710449542251549572066445060580507079593062643049417",
+                    10
+                )
+                .unwrap()
+            )
+        );
+        assert_eq!(
+            curve.normalize(&curve.point_add(&b, &neutral)).unwrap(),
+            curve.normalize(&b).unwrap()
+        );
+        assert!(curve.is_neutral(&curve.point_add(&b, &b.negative())));
+        assert!(curve.is_neutral(&curve.point_add(&neutral, &neutral)));
+        assert!(curve.is_neutral(&curve.point_mul(&b, &order)));
+        assert!(curve.check_point(&curve.point_mul(&b, &(&order - BigUint::one()))));
+    }
+
+    #[test]
+    fn edwards_weierstrass_maps() {
+        let k = BigUint::from_str_radix(
+            "7e2b897b8cebc6361663ad410835639826d590f393d90a9538881735256dfae3",
+            16,
+        )
+        .unwrap();
+        for (params, kb) in [
+            (
+                PreGeneratedEdwardsParams::Edwards25519,
+                (
+                    "44521480356092437221601840541339433798421393083589018299689464898649420613953",
+                    "12838637523820306023029917995019244519755772507347376614923873069640600717819",
+                ),
+            ),
+            (
+                PreGeneratedEdwardsParams::Edwards448,
+                (
+                    "481638645883011764971306112132364868704159405988340644852064884539088933206720523148354639912814753962453798603285551831356545779817507",
+                    "65491979101877071012196109872590351102245615869853223157088853223747613098599335110892754182059961407460452108717205068162116807791669",
+                ),
+            ),
+        ] {
+            let curve = EdwardsCurve::from(&params);
+            let ec_info = EcInfo::from(&params);
+            let b = params.base_point();
+
+            let kb_ed = curve.point_mul(&b, &k);
+            assert!(curve.check_point(&kb_ed), "{params:?}");
+            assert_eq!(
+                curve.to_affine(&kb_ed).unwrap(),
+                (
+                    BigInt::from_str_radix(kb.0, 10).unwrap(),
+                    BigInt::from_str_radix(kb.1, 10).unwrap()
+                ),
+                "{params:?}"
+            );
+
+            // maps are homomorphisms between Edwards and Weierstrass forms
+            let kb_w = ec_info.ecurve.proj_point_mul(&ec_info.bp, &k);
+            assert_eq!(
+                curve.to_weierstrass_proj_point(&kb_ed).unwrap(),
+                ec_info.ecurve.transform_proj_point(&kb_w).unwrap(),
+                "{params:?}"
+            );
+            assert_eq!(
+                curve
+                    .normalize(&curve.from_weierstrass_proj_point(&kb_w).unwrap())
+                    .unwrap(),
+                curve.normalize(&kb_ed).unwrap(),
+                "{params:?}"
+            );
+
+            // special points
+            assert!(curve
+                .to_weierstrass_point(&EdPointE::neutral())
+                .unwrap()
+                .is_inf());
+            assert!(curve.is_neutral(&curve.from_weierstrass_proj_point(&EcPointP::neutral()).unwrap()));
+            let order2 = EdPointE::from_affine(&BigInt::zero(), &(curve.get_q() - BigInt::one()));
+            let order2_w = curve.to_weierstrass_proj_point(&order2).unwrap();
+            assert!(ec_info.ecurve.check_projective_point(&order2_w));
+            assert!(ec_info
+                .ecurve
+                .proj_point_add(&order2_w, &order2_w)
+                .is_inf());
+            assert_eq!(
+                curve
+                    .normalize(&curve.from_weierstrass_proj_point(&order2_w).unwrap())
+                    .unwrap(),
+                order2
+            );
+        }
+    }
+
+    #[test]
+    fn complete_formulas() {
+        // a = -3 and arbitrary a
+        for params in [
+            PreGeneratedParams::P256,
+            PreGeneratedParams::BrainpoolP256r1,
+        ] {
+            let ec_info = EcInfo::from(&params);
+            let curve = &ec_info.ecurve;
+            let g = &ec_info.bp;
+            let g2 = curve.proj_point_mul(g, &BigUint::from(2_u8));
+            let g3 = curve.proj_point_mul(g, &BigUint::from(3_u8));
+            // representation with Z != 1
+            let g_scaled = EcPointP::new(
+                &(&g.x * BigInt::from(5_u8)),
+                &(&g.y * BigInt::from(5_u8)),
+                &BigInt::from(5_u8),
+            );
+
+            let same = |a: &EcPointP, b: &EcPointP| {
+                assert_eq!(
+                    curve.transform_proj_point(a).unwrap(),
+                    curve.transform_proj_point(b).unwrap(),
+                    "{params:?}"
+                )
+            };
+            same(&curve.complete_point_add(g, &g2), &g3);
+            same(&curve.complete_point_add(g, &g_scaled), &g2);
+            same(&curve.complete_point_double(&g_scaled), &g2);
+            same(
+                &curve.complete_point_add(&g2, &g2),
+                &curve.complete_point_double(&g2),
+            );
+            same(&curve.complete_point_add(g, &EcPointP::neutral()), g);
+            same(&curve.complete_point_add(&EcPointP::neutral(), &g2), &g2);
+            assert!(curve.complete_point_add(g, &g.negative()).is_inf());
+            assert!(curve
+                .complete_point_add(&g_scaled, &g_scaled.negative())
+                .is_inf());
+            assert!(curve
+                .complete_point_add(&EcPointP::neutral(), &EcPointP::neutral())
+                .is_inf());
+            assert!(curve.complete_point_double(&EcPointP::neutral()).is_inf());
+        }
+    }
+
+    #[test]
+    fn fixed_width_field() {
+        let q = Params::from(PreGeneratedParams::P521).q;
+        let field = PrimeField::<9>::new(&q).unwrap();
+        let x = BigInt::from_str_radix(
+            "1c2b76a7bc25e7702a704fa986892849fca629487acf3709d2e4e8bb",
+            16,
+        )
+        .unwrap()
+        .pow(4_u32);
+        let y = -BigInt::from_str_radix("d34c32b17fb23d018d9bf7c96979a99eb8cd0b394b", 16).unwrap();
+        let (fx, fy) = (field.element(&x), field.element(&y));
+        let reduce = |v: BigInt| take_by_bigint_module(&(v % &q), &q);
+
+        assert_eq!((fx + fy).to_bigint(), reduce(&x + &y));
+        assert_eq!((fx - fy).to_bigint(), reduce(&x - &y));
+        assert_eq!((fy - fx).to_bigint(), reduce(&y - &x));
+        assert_eq!((-fx).to_bigint(), reduce(-&x));
+        assert_eq!((fx * fy).to_bigint(), reduce(&x * &y));
+        assert_eq!(fy.square().to_bigint(), reduce(&y * &y));
+        assert_eq!(fx * fx.inverse(), field.one());
+        assert!(field.zero().inverse().is_zero());
+        assert!(PrimeField::<4>::new(&q).is_err());
+        assert!(PrimeField::<1>::new(&BigInt::from(14_u8)).is_err());
+
+        // fixed-width scalar multiplication gives the same results as BigInt one
+        let curve = ECurve::new(Params {
+            a: BigInt::from(11_u8),
+            b: BigInt::from(7_u8),
+            q: BigInt::from(13_u8),
+        })
+        .unwrap();
+        let p1 = EcPointP::new(&BigInt::from(6), &BigInt::from(4), &BigInt::from(1));
+        for coordinates in [
+            CoordinateSystem::Projective,
+            CoordinateSystem::Jacobian,
+            CoordinateSystem::Complete,
+        ] {
+            let big = curve.clone().with_coordinates(coordinates);
+            let fixed = big.clone().with_arithmetic(FieldArithmetic::FixedWidth);
+            for k in 0..25_u8 {
+                let k = BigUint::from(k);
+                let (r_big, r_fixed) = (big.proj_point_mul(&p1, &k), fixed.proj_point_mul(&p1, &k));
+                assert_eq!(r_big.is_inf(), r_fixed.is_inf(), "{coordinates:?}, k: {k}");
+                assert_eq!(
+                    r_big.to_affine(&big).unwrap(),
+                    r_fixed.to_affine(&fixed).unwrap(),
+                    "{coordinates:?}, k: {k}"
+                );
+            }
+        }
+        for params in [
+            PreGeneratedParams::P521,
+            PreGeneratedParams::BrainpoolP384t1,
+        ] {
+            let ec_info = EcInfo::from(&params);
+            assert_eq!(ec_info.ecurve.get_arithmetic(), FieldArithmetic::FixedWidth);
+            let big = ec_info
+                .ecurve
+                .clone()
+                .with_arithmetic(FieldArithmetic::BigInt);
+            let k =
+                BigUint::from_str_radix("c51e4753afdec1e6b6c6a5b992f43f8dd0c7a8933072708b65", 16)
+                    .unwrap();
+            assert_eq!(
+                ec_info
+                    .ecurve
+                    .proj_point_mul(&ec_info.bp, &k)
+                    .to_affine(&big)
+                    .unwrap(),
+                big.proj_point_mul(&ec_info.bp, &k).to_affine(&big).unwrap(),
+                "{params:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn constant_time_ladder() {
+        let curve = ECurve::new(Params {
+            a: BigInt::from(11_u8),
+            b: BigInt::from(7_u8),
+            q: BigInt::from(13_u8),
+        })
+        .unwrap();
+        let p1 = EcPointP::new(&BigInt::from(6), &BigInt::from(4), &BigInt::from(1));
+        for k in 0..40_u8 {
+            let k = BigUint::from(k);
+            let expected = curve.proj_point_mul(&p1, &k);
+            // k >= 11 is reduced modulo the order, so there are always 4 steps
+            let ladder = curve.proj_point_mul_ct(&p1, &k, &BigUint::from(11_u8));
+            assert_eq!(expected.is_inf(), ladder.is_inf(), "k: {k}");
+            assert_eq!(
+                expected.to_affine(&curve).unwrap(),
+                ladder.to_affine(&curve).unwrap(),
+                "k: {k}"
+            );
+        }
+
+        let k = BigUint::from_str_radix(
+            "c51e4753afdec1e6b6c6a5b992f43f8dd0c7a8933072708b6522468b2ffb06fd",
+            16,
+        )
+        .unwrap();
+        for params in [
+            PreGeneratedParams::P256,
+            PreGeneratedParams::Secp256k1,
+            PreGeneratedParams::BrainpoolP512r1,
+        ] {
+            let ec_info = EcInfo::from(&params);
+            let curve = &ec_info.ecurve;
+            assert_eq!(
+                curve
+                    .proj_point_mul_ct(&ec_info.bp, &k, &ec_info.n)
+                    .to_affine(curve)
+                    .unwrap(),
+                curve
+                    .proj_point_mul(&ec_info.bp, &k)
+                    .to_affine(curve)
+                    .unwrap(),
+                "{params:?}"
+            );
+            assert!(curve
+                .proj_point_mul_ct(&ec_info.bp, &ec_info.n, &ec_info.n)
+                .is_inf());
+        }
+
+        // modulus doesn't fit into fixed-width field elements, so BigInt ladder is used
+        let curve = ECurve::new(Params {
+            a: BigInt::one(),
+            b: BigInt::from(4_u8),
+            q: (BigInt::one() << 599) + BigInt::from(323_u16),
+        })
+        .unwrap();
+        let p = EcPointP::new(&BigInt::zero(), &BigInt::from(2_u8), &BigInt::one());
+        // the order of p isn't known, but k < 2^600 isn't changed by the reduction
+        assert_eq!(
+            curve
+                .proj_point_mul_ct(&p, &k, &(BigUint::one() << 600))
+                .to_affine(&curve)
+                .unwrap(),
+            curve.proj_point_mul(&p, &k).to_affine(&curve).unwrap()
+        );
+
+        // Edwards ladder with complete addition, including the point of order 2
+        for params in [
+            PreGeneratedEdwardsParams::Edwards25519,
+            PreGeneratedEdwardsParams::Edwards448,
+        ] {
+            let ed = EdwardsEcInfo::from(&params);
+            let order_2 = EdPointE::from_affine(&BigInt::zero(), &(ed.curve.get_q() - 1_u8));
+            let nh = &ed.n * &ed.h;
+            for k in [
+                BigUint::zero(),
+                BigUint::one(),
+                BigUint::from(7_u8),
+                k.clone(),
+                &ed.n - 1_u8,
+                ed.n.clone(),
+            ] {
+                for p in [&ed.bp, &order_2] {
+                    assert_eq!(
+                        ed.curve
+                            .to_affine(&ed.curve.point_mul_ct(p, &k, &nh))
+                            .unwrap(),
+                        ed.curve.to_affine(&ed.curve.point_mul(p, &k)).unwrap(),
+                        "{params:?}, k: {k}"
+                    );
+                }
+            }
+        }
+
+        // Lopez-Dahab ladder: generator, the point of order 2 and a point out of the subgroup
+        let info = BinaryEcInfo::from(PreGeneratedBinaryParams::B163);
+        let curve = &info.curve;
+        let nh = &info.n * &info.h;
+        let order_2 = EcPointA::new(&BigInt::zero(), &curve.get_field().sqrt(&curve.get_b()));
+        let off_subgroup = (1_u32..)
+            .find_map(|x| curve.lift_x(&BigInt::from(x), false).ok())
+            .map(|p| EcPointLD::from_affine(&p))
+            .unwrap();
+        assert!(!curve.ld_point_mul(&off_subgroup, &info.n).is_inf());
+        for k in (0..20_u8).map(BigUint::from).chain([
+            k.clone(),
+            &info.n - 1_u8,
+            info.n.clone(),
+            &nh - 1_u8,
+        ]) {
+            for p in [
+                info.generator(),
+                EcPointLD::from_affine(&order_2),
+                off_subgroup.clone(),
+            ] {
+                assert_eq!(
+                    curve
+                        .ld_to_affine(&curve.ld_point_mul_ct(&p, &k, &nh))
+                        .unwrap(),
+                    curve.ld_to_affine(&curve.ld_point_mul(&p, &k)).unwrap(),
+                    "k: {k}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn wnaf() {
+        let k = BigUint::from_str_radix(
+            "c51e4753afdec1e6b6c6a5b992f43f8dd0c7a8933072708b6522468b2ffb06fd",
+            16,
+        )
+        .unwrap();
+        assert_eq!(to_wnaf(&BigUint::from(7_u8), 2), vec![-1, 0, 0, 1]);
+        assert_eq!(to_wnaf(&BigUint::from(7_u8), 3), vec![-1, 0, 0, 1]);
+        assert_eq!(to_wnaf(&BigUint::from(7_u8), 4), vec![7]);
+        assert!(to_wnaf(&BigUint::zero(), 5).is_empty());
+        for w in 2..=8_u8 {
+            let digits = to_wnaf(&k, w);
+            let restored = digits.iter().rev().fold(BigInt::zero(), |acc, d| {
+                acc * BigInt::from(2_u8) + BigInt::from(*d)
+            });
+            assert_eq!(restored, BigInt::from(k.clone()), "w: {w}");
+            assert!(digits.len() as u64 <= k.bits() + 1, "w: {w}");
+            for (i, d) in digits.iter().enumerate() {
+                if *d != 0 {
+                    assert!(d % 2 != 0 && d.unsigned_abs() < 1 << (w - 1), "w: {w}");
+                    assert!(digits[i + 1..].iter().take(w as usize - 1).all(|x| *x == 0));
+                }
+            }
+        }
+
+        // the same results for all coordinate systems and field arithmetic
+        let curve = ECurve::new(Params {
+            a: BigInt::from(11_u8),
+            b: BigInt::from(7_u8),
+            q: BigInt::from(13_u8),
+        })
+        .unwrap();
+        let p1 = EcPointP::new(&BigInt::from(6), &BigInt::from(4), &BigInt::from(1));
+        let ec_info = EcInfo::from(&PreGeneratedParams::P384);
+        for coordinates in [
+            CoordinateSystem::Projective,
+            CoordinateSystem::Jacobian,
+            CoordinateSystem::Complete,
+        ] {
+            for arithmetic in [FieldArithmetic::BigInt, FieldArithmetic::FixedWidth] {
+                let plain = curve
+                    .clone()
+                    .with_coordinates(coordinates)
+                    .with_arithmetic(arithmetic);
+                for w in [2, 4, 6] {
+                    let with_wnaf = plain.clone().with_wnaf(w).unwrap();
+                    assert_eq!(with_wnaf.get_wnaf(), Some(w));
+                    for k in 0..40_u8 {
+                        let k = BigUint::from(k);
+                        assert_eq!(
+                            with_wnaf.proj_point_mul(&p1, &k).to_affine(&curve).unwrap(),
+                            plain.proj_point_mul(&p1, &k).to_affine(&curve).unwrap(),
+                            "{coordinates:?}, {arithmetic:?}, w: {w}, k: {k}"
+                        );
+                    }
+                }
+
+                for w in [0, 1, 9] {
+                    assert!(matches!(
+                        plain.clone().with_wnaf(w),
+                        Err(EcError::IncorrectParameters(_))
+                    ));
+                }
+
+                let plain = ec_info
+                    .ecurve
+                    .clone()
+                    .with_coordinates(coordinates)
+                    .with_arithmetic(arithmetic);
+                assert_eq!(
+                    plain
+                        .clone()
+                        .with_wnaf(5)
+                        .unwrap()
+                        .proj_point_mul(&ec_info.bp, &k)
+                        .to_affine(&plain)
+                        .unwrap(),
+                    plain
+                        .proj_point_mul(&ec_info.bp, &k)
+                        .to_affine(&plain)
+                        .unwrap(),
+                    "{coordinates:?}, {arithmetic:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn fixed_base_table() {
+        // n = 11, so table has a single window and 11G = O is stored in it
+        let curve = ECurve::new(Params {
+            a: BigInt::from(11_u8),
+            b: BigInt::from(7_u8),
+            q: BigInt::from(13_u8),
+        })
+        .unwrap();
+        let p1 = EcPointP::new(&BigInt::from(6), &BigInt::from(4), &BigInt::from(1));
+        let ec_info = EcInfo::new(
+            p1.clone(),
+            BigUint::from(11_u8),
+            BigUint::one(),
+            curve.clone(),
+        )
+        .unwrap();
+        for k in 0..40_u8 {
+            let k = BigUint::from(k);
+            let (expected, table) = (curve.proj_point_mul(&p1, &k), ec_info.base_point_mul(&k));
+            assert_eq!(expected.is_inf(), table.is_inf(), "k: {k}");
+            assert_eq!(
+                expected.to_affine(&curve).unwrap(),
+                table.to_affine(&curve).unwrap(),
+                "k: {k}"
+            );
+        }
+
+        let k = BigUint::from_str_radix(
+            "c51e4753afdec1e6b6c6a5b992f43f8dd0c7a8933072708b6522468b2ffb06fd",
+            16,
+        )
+        .unwrap();
+        for params in [
+            PreGeneratedParams::P256,
+            PreGeneratedParams::Secp256k1,
+            PreGeneratedParams::BrainpoolP384r1,
+            PreGeneratedParams::Curve25519,
+        ] {
+            let ec_info = EcInfo::from(&params);
+            let curve = &ec_info.ecurve;
+            // table is built once and shared with clones
+            let copy = ec_info.clone();
+            assert!(format!("{copy:?}").contains("not built"));
+            let r = ec_info.base_point_mul(&k);
+            assert!(!format!("{copy:?}").contains("not built"), "{params:?}");
+            assert_eq!(copy, ec_info);
+            assert_eq!(
+                r.to_affine(curve).unwrap(),
+                curve
+                    .proj_point_mul(&ec_info.bp, &k)
+                    .to_affine(curve)
+                    .unwrap(),
+                "{params:?}"
+            );
+            assert!(copy.base_point_mul(&ec_info.n).is_inf(), "{params:?}");
+            assert!(copy.base_point_mul(&BigUint::zero()).is_inf(), "{params:?}");
+        }
+
+        // base point was replaced after the table had been built, so ladder is used
+        let mut ec_info = EcInfo::from(&PreGeneratedParams::P256);
+        ec_info.base_point_mul(&k);
+        let bp2 = ec_info
+            .ecurve
+            .proj_point_mul(&ec_info.bp, &BigUint::from(2_u8));
+        ec_info.bp = bp2.clone();
+        assert_eq!(
+            ec_info
+                .base_point_mul(&k)
+                .to_affine(&ec_info.ecurve)
+                .unwrap(),
+            ec_info
+                .ecurve
+                .proj_point_mul(&bp2, &k)
+                .to_affine(&ec_info.ecurve)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn multi_scalar_mul() {
+        let ec_info = EcInfo::from(&PreGeneratedParams::P256);
+        let g = &ec_info.bp;
+        // points and scalars include O, zero scalar and equal points
+        let terms: Vec<(EcPointP, BigUint)> = (0..rust_ec::multi_scalar::STRAUS_THRESHOLD as u32)
+            .map(|i| {
+                let p = match i % 7 {
+                    0 => EcPointP::neutral(),
+                    _ => ec_info.ecurve.proj_point_mul(g, &BigUint::from(i % 5 + 1)),
+                };
+                let k = BigUint::from(0xfedcba98_u32).pow(i % 9) * BigUint::from(i * 31 % 17);
+                (p, k)
+            })
+            .collect();
+        for coordinates in [CoordinateSystem::Jacobian, CoordinateSystem::Complete] {
+            for arithmetic in [FieldArithmetic::BigInt, FieldArithmetic::FixedWidth] {
+                let curve = ec_info
+                    .ecurve
+                    .clone()
+                    .with_coordinates(coordinates)
+                    .with_arithmetic(arithmetic);
+                // Straus for a few points and Pippenger from the threshold
+                for len in [0, 1, 2, 5, terms.len()] {
+                    let expected = terms[..len]
+                        .iter()
+                        .fold(EcPointP::neutral(), |acc, (p, k)| {
+                            curve.proj_point_add(&acc, &curve.proj_point_mul(p, k))
+                        });
+                    let r = curve.multi_scalar_mul(&terms[..len]);
+                    assert_eq!(
+                        r.to_affine(&curve).unwrap(),
+                        expected.to_affine(&curve).unwrap(),
+                        "{coordinates:?}, {arithmetic:?}, len: {len}"
+                    );
+                }
+            }
+        }
+        // u * G + (n - u) * G = O
+        let u = BigUint::from(123456789_u32);
+        assert!(ec_info
+            .ecurve
+            .multi_scalar_mul(&[(g.clone(), u.clone()), (g.clone(), &ec_info.n - &u)])
+            .is_inf());
+    }
+
+    #[test]
+    fn batch_normalization() {
+        let ec_info = EcInfo::from(&PreGeneratedParams::P256);
+        let curve = &ec_info.ecurve;
+        // non-normalized points from complete formulas and O in different forms
+        let complete = curve.clone().with_coordinates(CoordinateSystem::Complete);
+        let mut points: Vec<EcPointP> = (1..20_u32)
+            .map(|i| {
+                let p = curve.proj_point_mul(&ec_info.bp, &BigUint::from(i * 7919));
+                complete.proj_point_add(&p, &ec_info.bp)
+            })
+            .collect();
+        assert!(points.iter().any(|p| !p.z.is_one()));
+        points.insert(0, EcPointP::neutral());
+        points.insert(
+            5,
+            EcPointP::new(&BigInt::zero(), &BigInt::from(5), &BigInt::zero()),
+        );
+
+        let affine = curve.batch_to_affine(&points).unwrap();
+        let normalized = curve.batch_transform_proj_points(&points).unwrap();
+        assert_eq!(affine.len(), points.len());
+        for (i, p) in points.iter().enumerate() {
+            assert_eq!(affine[i], p.to_affine(curve).unwrap(), "i: {i}");
+            match i == 0 || i == 5 {
+                true => {
+                    assert!(affine[i].is_inf());
+                    assert_eq!(normalized[i], EcPointP::neutral());
+                }
+                false => assert_eq!(normalized[i], curve.transform_proj_point(p).unwrap()),
+            }
+        }
+
+        // Z = q is zero as well
+        let q = Params::from(PreGeneratedParams::P256).q;
+        let r = curve
+            .batch_to_affine(&[
+                EcPointP::new(&BigInt::zero(), &BigInt::one(), &q),
+                ec_info.bp.clone(),
+            ])
+            .unwrap();
+        assert!(r[0].is_inf());
+        assert_eq!(r[1], ec_info.bp.to_affine(curve).unwrap());
+
+        assert!(curve.batch_to_affine(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn sec1_encoding() {
+        // SEC 1 test vector: base point of P-256 (y is odd)
+        let ec_info = EcInfo::from(&PreGeneratedParams::P256);
+        let curve = &ec_info.ecurve;
+        let g = ec_info.bp.to_affine(curve).unwrap();
+        let gx = "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296";
+        let gy = "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5";
+        let cases = [
+            (PointFormat::Uncompressed, format!("04{gx}{gy}")),
+            (PointFormat::Compressed, format!("03{gx}")),
+            (PointFormat::Hybrid, format!("07{gx}{gy}")),
+        ];
+        for (format, expected) in cases {
+            let bytes = curve.encode_point(&g, format);
+            assert_eq!(bytes, from_hex(&expected), "{format:?}");
+            assert_eq!(curve.decode_point(&bytes).unwrap(), g, "{format:?}");
+            assert_eq!(curve.encode_proj_point(&ec_info.bp, format).unwrap(), bytes);
+        }
+        let inf = curve.encode_proj_point(&EcPointP::neutral(), PointFormat::Compressed);
+        assert_eq!(inf.unwrap(), vec![0x00]);
+        assert!(curve.decode_point(&[0x00]).unwrap().is_inf());
+
+        // round trip with both parities, P-224 needs general Tonelli-Shanks (q = 1 mod 2^96),
+        // coordinates of P-521 take 66 bytes
+        for params in [
+            PreGeneratedParams::P224,
+            PreGeneratedParams::P256,
+            PreGeneratedParams::P521,
+            PreGeneratedParams::BrainpoolP256r1,
+        ] {
+            let ec_info = EcInfo::from(&params);
+            let curve = &ec_info.ecurve;
+            let len = (Params::from(&params).q.bits() as usize).div_ceil(8);
+            for k in 1..10_u32 {
+                let p = curve
+                    .proj_point_mul(&ec_info.bp, &BigUint::from(k * 1_000_003))
+                    .to_affine(curve)
+                    .unwrap();
+                for format in [
+                    PointFormat::Uncompressed,
+                    PointFormat::Compressed,
+                    PointFormat::Hybrid,
+                ] {
+                    let bytes = curve.encode_point(&p, format);
+                    let expected_len = match format {
+                        PointFormat::Compressed => 1 + len,
+                        _ => 1 + 2 * len,
+                    };
+                    assert_eq!(bytes.len(), expected_len, "{params:?}, {format:?}");
+                    assert_eq!(curve.decode_point(&bytes).unwrap(), p, "{params:?}, k: {k}");
+                }
+            }
+        }
+
+        // malformed strings
+        let uncompressed = curve.encode_point(&g, PointFormat::Uncompressed);
+        let compressed = curve.encode_point(&g, PointFormat::Compressed);
+        let q = Params::from(PreGeneratedParams::P256).q;
+        let mut x_too_big = compressed.clone();
+        x_too_big[1..].copy_from_slice(&q.to_bytes_be().1);
+        let mut not_on_curve = uncompressed.clone();
+        not_on_curve[64] ^= 1;
+        let mut wrong_parity = curve.encode_point(&g, PointFormat::Hybrid);
+        wrong_parity[0] = 0x06;
+        // x = 1 gives 1 - 3 + b, that is non-residue for P-256
+        let mut non_residue = vec![0x02; 33];
+        non_residue[1..].copy_from_slice(&[0; 32]);
+        non_residue[32] = 1;
+        let malformed: Vec<(&str, Vec<u8>)> = vec![
+            ("empty", vec![]),
+            ("unknown tag", [&[0x05], &uncompressed[1..]].concat()),
+            ("infinity with data", vec![0x00, 0x00]),
+            ("short", uncompressed[..64].to_vec()),
+            ("long", [&compressed[..], &[0]].concat()),
+            ("x >= q", x_too_big),
+            ("not on curve", not_on_curve),
+            ("hybrid parity", wrong_parity),
+            ("non-residue", non_residue),
+        ];
+        for (name, bytes) in malformed {
+            match curve.decode_point(&bytes) {
+                Err(EcError::IncorrectPointEncoding(_)) => {}
+                r => panic!("{name}: {r:?}"),
+            }
+        }
+
+        // y = 0 is even: y^2 = x^3 + 1 over GF(13) has point (12, 0)
+        let toy_curve = ECurve::new(Params {
+            a: BigInt::zero(),
+            b: BigInt::one(),
+            q: BigInt::from(13_u8),
+        })
+        .unwrap();
+        let p = EcPointA::new(&BigInt::from(12_u8), &BigInt::zero());
+        assert_eq!(
+            toy_curve.encode_point(&p, PointFormat::Compressed),
+            vec![0x02, 12]
+        );
+        assert_eq!(toy_curve.decode_point(&[0x02, 12]).unwrap(), p);
+        assert!(toy_curve.decode_point(&[0x03, 12]).is_err());
+    }
+
+    #[test]
+    fn modular_sqrt() {
+        // p = 3 mod 4, p = 5 mod 8 (Atkin), p = 1 mod 8 (Tonelli-Shanks) against brute force
+        for p in [
+            3_u32, 7, 11, 13, 17, 29, 37, 41, 73, 97, 113, 241, 257, 65537,
+        ] {
+            let p_big = BigInt::from(p);
+            let mut squares = vec![false; p as usize];
+            for x in 0..p as u64 {
+                squares[(x * x % p as u64) as usize] = true;
+            }
+            for a in 0..p.min(300) {
+                let a_big = BigInt::from(a);
+                let expected_symbol = match a {
+                    0 => 0,
+                    _ if squares[a as usize] => 1,
+                    _ => -1,
+                };
+                assert_eq!(legendre_symbol(&a_big, &p_big), expected_symbol, "{a}, {p}");
+                assert_eq!(
+                    jacobi_symbol(&a_big, &p_big),
+                    Some(expected_symbol),
+                    "{a}, {p}"
+                );
+                match sqrt_mod(&a_big, &p_big) {
+                    Some(r) => {
+                        assert!(squares[a as usize] && r < p_big, "{a}, {p}");
+                        assert_eq!((&r * &r) % &p_big, a_big, "{a}, {p}");
+                    }
+                    None => assert!(!squares[a as usize], "{a}, {p}"),
+                }
+            }
+        }
+
+        // Jacobi symbol for composite n: (2 / 15) = 1, but 2 is a non-residue mod 15
+        assert_eq!(jacobi_symbol(&BigInt::from(2), &BigInt::from(15)), Some(1));
+        assert_eq!(jacobi_symbol(&BigInt::from(7), &BigInt::from(45)), Some(-1));
+        assert_eq!(jacobi_symbol(&BigInt::from(-1), &BigInt::from(21)), Some(1));
+        assert_eq!(jacobi_symbol(&BigInt::from(6), &BigInt::from(21)), Some(0));
+        // the symbol isn't defined for even and non-positive n
+        for n in [0, 2, 16, -3] {
+            assert_eq!(
+                jacobi_symbol(&BigInt::from(3), &BigInt::from(n)),
+                None,
+                "{n}"
+            );
+        }
+        assert_eq!(sqrt_mod(&BigInt::from(2), &BigInt::from(15)), None);
+        // odd perfect squares don't have non-residues, Tonelli-Shanks has to give up
+        assert_eq!(sqrt_mod(&BigInt::from(2), &BigInt::from(9)), None);
+        let square = BigInt::from((1_u64 << 61) - 1).pow(2);
+        assert_eq!(sqrt_mod(&BigInt::from(2), &square), None);
+
+        // big primes of every shape: P-256 (3 mod 4), 2^255 - 19 (5 mod 8), P-224 (1 mod 2^96)
+        for params in [
+            PreGeneratedParams::P256,
+            PreGeneratedParams::Curve25519,
+            PreGeneratedParams::P224,
+        ] {
+            let p = Params::from(&params).q;
+            for a in 1..30_u32 {
+                let a = BigInt::from(a).pow(7) * BigInt::from(0x1234567_u32);
+                match sqrt_mod(&a, &p) {
+                    Some(r) => assert_eq!((&r * &r) % &p, &a % &p, "{params:?}"),
+                    None => assert_eq!(legendre_symbol(&a, &p), -1, "{params:?}"),
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn lift_x() {
+        for params in [
+            PreGeneratedParams::P224,
+            PreGeneratedParams::P256,
+            PreGeneratedParams::Curve25519,
+            PreGeneratedParams::Secp256k1,
+        ] {
+            let ec_info = EcInfo::from(&params);
+            let curve = &ec_info.ecurve;
+            for k in 1..10_u32 {
+                let p = curve
+                    .proj_point_mul(&ec_info.bp, &BigUint::from(k * 65537))
+                    .to_affine(curve)
+                    .unwrap();
+                let odd = p.get_y().bit(0);
+                assert_eq!(curve.lift_x(&p.get_x(), odd).unwrap(), p, "{params:?}");
+                assert_eq!(
+                    curve.lift_x(&p.get_x(), !odd).unwrap(),
+                    p.negative().to_projective().to_affine(curve).unwrap(),
+                    "{params:?}"
+                );
+            }
+        }
+
+        // y^2 = x^3 + 1 over GF(13): x = 12 gives y = 0, x = 1 gives y^2 = 2, that is non-residue
+        let toy_curve = ECurve::new(Params {
+            a: BigInt::zero(),
+            b: BigInt::one(),
+            q: BigInt::from(13_u8),
+        })
+        .unwrap();
+        let x = BigInt::from(12_u8);
+        assert_eq!(
+            toy_curve.lift_x(&x, false).unwrap(),
+            EcPointA::new(&x, &BigInt::zero())
+        );
+        for (x, odd) in [(12_u8, true), (1, false), (1, true)] {
+            match toy_curve.lift_x(&BigInt::from(x), odd) {
+                Err(EcError::PointNotOnCurve(_)) => {}
+                r => panic!("x: {x}, {r:?}"),
+            }
+        }
+    }
+
+    fn trace(q: &BigInt, n: &BigUint, l: u64) -> u64 {
+        let t = q + BigInt::one() - BigInt::from(n.clone());
+        take_by_bigint_module(&(t % l), &BigInt::from(l))
+            .try_into()
+            .unwrap()
+    }
+
+    #[test]
+    fn point_counting() {
+        // t mod l from Schoof's algorithm against naive counting, j = 0 and j = 1728 included
+        for (q, a, b) in [
+            (10007_u32, 3, 7),
+            (10009, -3, 5),
+            (40009, 0, 7),
+            (40009, 5, 0),
+        ] {
+            let q = BigInt::from(q);
+            let curve = ECurve::new(Params {
+                a: BigInt::from(a),
+                b: BigInt::from(b),
+                q: q.clone(),
+            })
+            .unwrap();
+            let n = naive_order(&curve).unwrap();
+            for l in [2, 3, 5, 7, 11] {
+                assert_eq!(
+                    trace_mod_l(&curve, l).unwrap(),
+                    trace(&q, &n, l),
+                    "{q}, l: {l}"
+                );
+            }
+            assert_eq!(bsgs_order(&curve).unwrap(), n, "{q}");
+            assert_eq!(curve.order().unwrap(), n, "{q}");
+            let twist = quadratic_twist(&curve).unwrap();
+            assert_eq!(
+                naive_order(&twist).unwrap(),
+                BigUint::from(2_u8) * (q.to_biguint().unwrap() + 1_u8) - &n
+            );
+        }
+        assert!(trace_mod_l(
+            &ECurve::new(Params {
+                a: BigInt::one(),
+                b: BigInt::one(),
+                q: BigInt::from(13_u8),
+            })
+            .unwrap(),
+            13
+        )
+        .is_err());
+
+        // Schoof's algorithm with baby-step giant-step for the rest of candidates
+        let q = BigInt::from(281474976710597_u64);
+        let curve = ECurve::new(Params {
+            a: BigInt::from(-3),
+            b: BigInt::from(7),
+            q: q.clone(),
+        })
+        .unwrap();
+        let n = schoof_order(&curve).unwrap();
+        assert_eq!(bsgs_order(&curve).unwrap(), n);
+        assert_eq!(sea_order(&curve).unwrap(), n);
+        let twist = quadratic_twist(&curve).unwrap();
+        let twist_n = BigUint::from(2_u8) * (q.to_biguint().unwrap() + 1_u8) - &n;
+        for x in 1..10_u8 {
+            for (c, order) in [(&curve, &n), (&twist, &twist_n)] {
+                if let Ok(p) = c.lift_x(&BigInt::from(x), false) {
+                    assert!(c.proj_point_mul(&p.to_projective(), order).is_inf());
+                }
+            }
+        }
+
+        // known orders: t mod l for NIST curves
+        for params in [PreGeneratedParams::P192, PreGeneratedParams::P256] {
+            let ec_info = EcInfo::from(&params);
+            let n = &ec_info.n * &ec_info.h;
+            let q = Params::from(&params).q;
+            for l in [2, 3, 5] {
+                assert_eq!(
+                    trace_mod_l(&ec_info.ecurve, l).unwrap(),
+                    trace(&q, &n, l),
+                    "{params:?}, l: {l}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn sea_nist_orders() {
+        // G_3 = X^4 + 36X^3 + 270X^2 + (756 - Y)X + 729
+        let q = Params::from(&PreGeneratedParams::P192).q;
+        let field = PrimeField::<3>::new(&q).unwrap();
+        let g = ModularPolynomial::new(&field, 3).unwrap();
+        for (a, b, c) in [
+            (4, 0, 1),
+            (3, 0, 36),
+            (2, 0, 270),
+            (1, 0, 756),
+            (1, 1, -1),
+            (0, 0, 729),
+        ] {
+            assert_eq!(g.coef(a, b), field.element(&BigInt::from(c)), "X^{a} Y^{b}");
+        }
+        assert!(g.coef(0, 1).is_zero());
+        assert!(ModularPolynomial::new(&field, 9).is_err());
+
+        for params in [PreGeneratedParams::P192, PreGeneratedParams::P256] {
+            let ec_info = EcInfo::from(&params);
+            assert_eq!(
+                ec_info.ecurve.order().unwrap(),
+                &ec_info.n * &ec_info.h,
+                "{params:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn seeded_curve_generation() {
+        // seeds of NIST curves, ANSI X9.62 with SHA-1
+        let seeds = [
+            (
+                PreGeneratedParams::P192,
+                "3045ae6fc8422f64ed579528d38120eae12196d5",
+            ),
+            (
+                PreGeneratedParams::P224,
+                "bd71344799d5c7fcdc45b59fa3b9ab8f6a948bc5",
+            ),
+            (
+                PreGeneratedParams::P256,
+                "c49d360886e704936a6678e1139d26b7819f7e90",
+            ),
+            (
+                PreGeneratedParams::P384,
+                "a335926aa319a27a1d00896a6773a4827acdac73",
+            ),
+            (
+                PreGeneratedParams::P521,
+                "d09e8800291cb85396cc6717393284aaa0da64ba",
+            ),
+        ];
+        for (params, seed) in seeds {
+            let ec = ECurve::new(Params::from(&params)).unwrap();
+            let seed = BigUint::from_str_radix(seed, 16).unwrap().to_bytes_be();
+            assert!(verify_seed(&ec, &seed, SeedHash::Sha1), "{params:?}");
+            assert!(!verify_seed(&ec, &seed, SeedHash::Sha256), "{params:?}");
+            let mut wrong_seed = seed.clone();
+            wrong_seed[0] ^= 1;
+            assert!(!verify_seed(&ec, &wrong_seed, SeedHash::Sha1), "{params:?}");
+        }
+        let p256 = Params::from(PreGeneratedParams::P256);
+        let seed = BigUint::from_str_radix("c49d360886e704936a6678e1139d26b7819f7e90", 16)
+            .unwrap()
+            .to_bytes_be();
+        assert_eq!(
+            seed_to_c(&p256.q, &seed, SeedHash::Sha1).unwrap(),
+            BigInt::from_str_radix(
+                "7efba1662985be9403cb055c75d4f7e0ce8d84a9c5114abcaf3177680104fa0d",
+                16
+            )
+            .unwrap()
+        );
+        assert!(matches!(
+            seed_to_c(&p256.q, &seed, SeedHash::Sha256),
+            Err(EcError::IncorrectParameters(_))
+        ));
+
+        // primality test against trial division, strong pseudoprimes to base 2 and Carmichael numbers
+        for n in 0_u32..3000 {
+            let prime = n > 1 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0);
+            assert_eq!(is_probable_prime(&BigUint::from(n)), prime, "{n}");
+        }
+        for n in [2047_u32, 3277, 4033, 4681, 8321, 561, 1105, 1729, 294409] {
+            assert!(!is_probable_prime(&BigUint::from(n)), "{n}");
+        }
+        let p256_info = EcInfo::from(PreGeneratedParams::P256);
+        assert!(is_probable_prime(&p256_info.n));
+        assert!(!is_probable_prime(&(&p256_info.n * &p256_info.n)));
+
+        for (q, hash, seed_len) in [
+            ("ffffffffa9", SeedHash::Sha256, 32),
+            ("3ffffffd7", SeedHash::Sha1, 20),
+        ] {
+            let q = BigInt::from_str_radix(q, 16).unwrap();
+            let seed: Vec<u8> = (0..seed_len).map(|i: u8| i.wrapping_mul(17)).collect();
+            let curve = generate_curve(&q, &seed, hash, 200).unwrap();
+            let ec = &curve.info.ecurve;
+            assert!(curve.verify());
+            assert!(ec.is_a_minus_3());
+            assert!(verify_seed(ec, &curve.seed, hash));
+            assert!(is_probable_prime(&curve.info.n));
+            assert_eq!(ec.order().unwrap(), curve.info.n);
+            assert_eq!(curve.info.h, BigUint::one());
+            // found seed gives the same curve at once
+            let again = generate_curve(&q, &curve.seed, hash, 1).unwrap();
+            assert_eq!(again.seed, curve.seed);
+            assert_eq!(again.info, curve.info);
+
+            let mut tampered = curve.clone();
+            tampered.seed[0] ^= 1;
+            assert!(!tampered.verify());
+            let mut tampered = curve.clone();
+            tampered.info.bp = ec.proj_point_add(&curve.info.bp, &curve.info.bp);
+            assert!(!tampered.verify());
+            let mut tampered = curve.clone();
+            tampered.info.n += 2_u8;
+            assert!(!tampered.verify());
+        }
+        assert!(generate_curve(&BigInt::from(1000003), &[0; 32], SeedHash::Sha256, 0).is_err());
+
+        // the seed of P-224 gives P-224 (its b is even) with the base point derived from the seed
+        let p224 = Params::from(PreGeneratedParams::P224);
+        let seed = BigUint::from_str_radix("bd71344799d5c7fcdc45b59fa3b9ab8f6a948bc5", 16)
+            .unwrap()
+            .to_bytes_be();
+        let curve = generate_curve(&p224.q, &seed, SeedHash::Sha1, 1).unwrap();
+        assert!(curve.verify());
+        assert_eq!(curve.info.n, EcInfo::from(PreGeneratedParams::P224).n);
+        assert_eq!(curve.info.ecurve, ECurve::new(p224).unwrap());
+    }
+
+    #[test]
+    fn factorization() {
+        for n in 1_u32..2000 {
+            let f = factorize(&BigUint::from(n));
+            assert!(f.is_complete());
+            let product = f.factors.iter().fold(BigUint::one(), |acc, (p, e)| {
+                assert!(is_probable_prime(p));
+                acc * p.pow(*e)
+            });
+            assert_eq!(product, BigUint::from(n));
+        }
+        // product of primes, that are bigger than trial division bound, is split with rho
+        let (p, r) = (BigUint::from(1073741789_u32), BigUint::from(1000000007_u32));
+        let f = factorize(&(&p * &p * &r * 12_u8));
+        assert!(f.is_complete());
+        assert_eq!(
+            f.factors,
+            vec![
+                (BigUint::from(2_u8), 2),
+                (BigUint::from(3_u8), 1),
+                (r.clone(), 1),
+                (p.clone(), 2)
+            ]
+        );
+        assert_eq!(f.largest_factor(), Some(&p));
+
+        // twist of P-256: 3 * 5 * 13 * 179 * p
+        let p256 = EcInfo::from(PreGeneratedParams::P256);
+        let q = BigUint::from_str_radix(
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            16,
+        )
+        .unwrap();
+        let twist = (&q << 1_u8) + 2_u8 - &p256.n;
+        let f = factorize(&twist);
+        assert!(f.is_complete());
+        assert_eq!(
+            f.factors.iter().map(|(_, e)| *e).collect::<Vec<_>>(),
+            vec![1; 5]
+        );
+        assert_eq!(
+            f.factors[..4]
+                .iter()
+                .map(|(p, _)| p.clone())
+                .collect::<Vec<_>>(),
+            [3_u8, 5, 13, 179].map(BigUint::from).to_vec()
+        );
+        assert_eq!(f.largest_factor().unwrap().bits(), 241);
+    }
+
+    #[test]
+    fn security_audit() {
+        let p256 = EcInfo::from(PreGeneratedParams::P256)
+            .security_report()
+            .unwrap();
+        assert!(p256.is_secure(), "{:?}", p256.failed());
+        assert_eq!(p256.curve_order, EcInfo::from(PreGeneratedParams::P256).n);
+        assert_eq!(p256.checks.len(), 7);
+        assert!((p256.rho_bits - 127.8).abs() < 0.1);
+        assert_eq!(p256.embedding_degree, None);
+        assert!((p256.twist_rho_bits - 120.0).abs() < 0.5);
+        assert!(p256.cm_discriminant.bits() > 250);
+
+        // secp256k1 has j = 0, its CM discriminant is -3
+        let k1 = EcInfo::from(PreGeneratedParams::Secp256k1)
+            .security_report()
+            .unwrap();
+        assert_eq!(k1.cm_discriminant, BigInt::from(-3));
+        assert_eq!(
+            k1.failed().iter().map(|c| c.criterion).collect::<Vec<_>>(),
+            vec![Criterion::CmDiscriminant]
+        );
+        assert!((k1.twist_rho_bits - 109.5).abs() < 0.5);
+
+        // twist of P-224 isn't secure (SafeCurves: 2^58.4), its order isn't fully factored,
+        // so the lower bound is used
+        let p224 = EcInfo::from(PreGeneratedParams::P224)
+            .security_report()
+            .unwrap();
+        assert_eq!(
+            p224.failed()
+                .iter()
+                .map(|c| c.criterion)
+                .collect::<Vec<_>>(),
+            vec![Criterion::Twist]
+        );
+        assert!(p224.twist_rho_bits <= 58.4);
+
+        let c25519 = EcInfo::from(PreGeneratedParams::Curve25519)
+            .security_report()
+            .unwrap();
+        assert!(c25519.is_secure(), "{:?}", c25519.failed());
+        assert_eq!(
+            c25519.check(Criterion::Cofactor).details,
+            "h = 8, at most 8 is accepted"
+        );
+
+        let toy_info = |a: i64, b: i64, q: u64, h: u32| {
+            let ec = ECurve::new(Params {
+                a: BigInt::from(a),
+                b: BigInt::from(b),
+                q: BigInt::from(q),
+            })
+            .unwrap();
+            let bp = (1_u64..)
+                .filter_map(|x| ec.lift_x(&BigInt::from(x), false).ok())
+                .map(|p| ec.proj_point_mul(&EcPointP::from_affine(&p), &BigUint::from(h)))
+                .find(|p| !p.is_inf())
+                .unwrap();
+            let n = ec.order().unwrap() / h;
+            EcInfo::new(bp, n, BigUint::from(h), ec).unwrap()
+        };
+
+        // supersingular y^2 = x^3 + x, q = 3 mod 4: #E = q + 1, k = 2
+        let supersingular = toy_info(1, 0, 1048867, 4).security_report().unwrap();
+        assert_eq!(supersingular.trace, BigInt::zero());
+        assert_eq!(supersingular.embedding_degree, Some(2));
+        assert!(!supersingular.check(Criterion::Transfer).passed);
+        assert!(supersingular.check(Criterion::SubgroupOrder).passed);
+        assert!(supersingular.check(Criterion::Anomalous).passed);
+        assert!(!supersingular.check(Criterion::Rho).passed);
+
+        // anomalous curve: #E = q
+        let anomalous = toy_info(2, 45, 1009, 1).security_report().unwrap();
+        assert_eq!(anomalous.trace, BigInt::one());
+        assert!(!anomalous.check(Criterion::Anomalous).passed);
+        assert!(anomalous.check(Criterion::SubgroupOrder).passed);
+
+        // wrong cofactor is detected by point counting
+        let mut wrong = toy_info(1, 0, 1048867, 4);
+        wrong.h = BigUint::from(2_u8);
+        let report = wrong.security_report().unwrap();
+        assert_eq!(report.curve_order, BigUint::from(1048868_u32));
+        assert!(!report.check(Criterion::SubgroupOrder).passed);
+    }
+
+    #[test]
+    fn subgroup_checks() {
+        // y^2 = x^3 + x over GF(163): #E = 164 = 4 * 41, orders are checked by repeated addition
+        let ec = ECurve::new(Params {
+            a: BigInt::one(),
+            b: BigInt::zero(),
+            q: BigInt::from(163_u8),
+        })
+        .unwrap();
+        let points: Vec<EcPointP> = (0_u8..163)
+            .flat_map(|x| [false, true].map(|odd| ec.lift_x(&BigInt::from(x), odd)))
+            .filter_map(|p| p.ok())
+            .map(|p| EcPointP::from_affine(&p))
+            .collect();
+        let bp = points
+            .iter()
+            .map(|p| ec.proj_point_mul(p, &BigUint::from(4_u8)))
+            .find(|p| !p.is_inf())
+            .unwrap();
+        let toy = EcInfo::new(bp, BigUint::from(41_u8), BigUint::from(4_u8), ec.clone()).unwrap();
+        for p in &points {
+            let mut order = 1_u32;
+            let mut acc = p.clone();
+            while !acc.is_inf() {
+                acc = ec.proj_point_add(&acc, p);
+                order += 1;
+            }
+            assert_eq!(toy.point_order(p).unwrap(), BigUint::from(order));
+            assert_eq!(toy.is_in_subgroup(p), order == 41);
+            let cleared = toy.clear_cofactor(p);
+            assert!(toy.is_in_subgroup(&cleared));
+            assert_eq!(
+                toy.validate_proj_public_key(p).is_ok(),
+                order == 41,
+                "{p}, order {order}"
+            );
+        }
+        assert_eq!(
+            toy.point_order(&EcPointP::neutral()).unwrap(),
+            BigUint::one()
+        );
+        assert!(toy.is_in_subgroup(&EcPointP::neutral()));
+        assert!(matches!(
+            toy.point_order(&EcPointP::new(
+                &BigInt::one(),
+                &BigInt::one(),
+                &BigInt::one()
+            )),
+            Err(EcError::PointNotOnCurve(_))
+        ));
+
+        // Wei25519 has cofactor 8: points of small order are rejected
+        let c25519 = EcInfo::from(PreGeneratedParams::Curve25519);
+        let p = (1_u8..)
+            .filter_map(|x| c25519.ecurve.lift_x(&BigInt::from(x), false).ok())
+            .map(|p| EcPointP::from_affine(&p))
+            .find(|p| !c25519.is_in_subgroup(p))
+            .unwrap();
+        let order = c25519.point_order(&p).unwrap();
+        assert_eq!(&order % &c25519.n, BigUint::zero());
+        assert!(order > c25519.n);
+        let cleared = c25519.clear_cofactor(&p);
+        assert!(c25519.is_in_subgroup(&cleared));
+        assert_eq!(c25519.point_order(&cleared).unwrap(), c25519.n);
+        let small = c25519.ecurve.proj_point_mul(&p, &c25519.n);
+        let small_order = c25519.point_order(&small).unwrap();
+        assert!(
+            small_order > BigUint::one() && BigUint::from(8_u8) % &small_order == BigUint::zero()
+        );
+        assert!(matches!(
+            c25519.validate_proj_public_key(&small),
+            Err(EcError::InvalidPublicKey(_))
+        ));
+        assert!(c25519.validate_proj_public_key(&cleared).is_ok());
+
+        // SEC 1 public key validation
+        let p256 = EcInfo::from(PreGeneratedParams::P256);
+        assert_eq!(p256.point_order(&p256.bp).unwrap(), p256.n);
+        assert_eq!(p256.clear_cofactor(&p256.bp), p256.bp);
+        let q = p256.bp.to_affine(&p256.ecurve).unwrap();
+        assert!(p256.validate_public_key(&q).is_ok());
+        let modulus = Params::from(PreGeneratedParams::P256).q;
+        let invalid = [
+            EcPointP::neutral().to_affine(&p256.ecurve).unwrap(),
+            EcPointA::new(&BigInt::zero(), &BigInt::zero()),
+            EcPointA::new(&(q.get_x() + &modulus), &q.get_y()),
+            EcPointA::new(&q.get_x(), &(q.get_y() - &modulus)),
+            EcPointA::new(&q.get_x(), &(q.get_y() + BigInt::one())),
+        ];
+        for p in invalid {
+            assert!(matches!(
+                p256.validate_public_key(&p),
+                Err(EcError::InvalidPublicKey(_))
+            ));
+        }
+        assert!(p256.validate_proj_public_key(&EcPointP::neutral()).is_err());
+        let scaled = EcPointP::new(&(q.get_x() * 5), &(q.get_y() * 5), &BigInt::from(5_u8));
+        assert!(p256.validate_proj_public_key(&scaled).is_ok());
+    }
+
+    #[test]
+    fn discrete_log_solvers() {
+        // the curve from `ec_mul`: P = (6, 4) has prime order 11
+        let toy = ECurve::new(Params {
+            a: BigInt::from(11_u8),
+            b: BigInt::from(7_u8),
+            q: BigInt::from(13_u8),
+        })
+        .unwrap();
+        let p = EcPointP::new(&BigInt::from(6), &BigInt::from(4), &BigInt::from(1));
+        let order = BigUint::from(11_u8);
+        let toy_info = EcInfo::new(p.clone(), order.clone(), BigUint::one(), toy.clone()).unwrap();
+        for k in 0_u8..11 {
+            let k = BigUint::from(k);
+            let q = toy.proj_point_mul(&p, &k);
+            assert_eq!(bsgs(&toy, &p, &q, &order).unwrap().k, k);
+            assert_eq!(pollard_rho(&toy, &p, &q, &order).unwrap().k, k);
+            let lambda = pollard_lambda(&toy, &p, &q, &BigUint::zero(), &BigUint::from(10_u8));
+            // the interval is wider than the order, so k is found up to a multiple of 11
+            assert_eq!(lambda.unwrap().k % &order, k);
+            assert_eq!(toy_info.discrete_log(&q).unwrap().k, k);
+        }
+        let off_curve = EcPointP::new(&BigInt::from(1), &BigInt::from(1), &BigInt::from(1));
+        assert!(toy_info.discrete_log(&off_curve).is_err());
+
+        // prime order curve over 30-bit field
+        let q = BigInt::from(1073741789_u32);
+        let curve = generate_curve(&q, &[0x5a; 32], SeedHash::Sha256, 100).unwrap();
+        let (ec, bp, n) = (&curve.info.ecurve, &curve.info.bp, &curve.info.n);
+        let k = BigUint::from(987654321_u32) % n;
+        let target = ec.proj_point_mul(bp, &k);
+        let sqrt_n = n.sqrt().to_u64_digits()[0];
+
+        let solution = bsgs(ec, bp, &target, n).unwrap();
+        assert_eq!(solution.k, k);
+        assert!(solution.iterations <= 2 * sqrt_n + 2);
+        let solution = pollard_rho(ec, bp, &target, n).unwrap();
+        assert_eq!(solution.k, k);
+        assert!(
+            solution.iterations <= 10 * sqrt_n,
+            "{}",
+            solution.iterations
+        );
+        let solution = curve.info.discrete_log(&target).unwrap();
+        assert_eq!(solution.k, k);
+
+        // k is known to lie in interval of width 2^20
+        let lower = &k - BigUint::from(300000_u32);
+        let upper = &lower + BigUint::from(1_u32 << 20);
+        let solution = pollard_lambda(ec, bp, &target, &lower, &upper).unwrap();
+        assert_eq!(solution.k, k);
+        assert!(
+            solution.iterations <= 16 * (1 << 10),
+            "{}",
+            solution.iterations
+        );
+        let below = pollard_lambda(ec, bp, &target, &(&k + 1_u8), &(&k + 1000_u32));
+        assert!(matches!(below, Err(EcError::DiscreteLogNotFound(_))));
+
+        // #E = 2^8 * 5^2 * 37 * 41 * 269 * 421 is smooth, Pohlig-Hellman works with BSGS only
+        let smooth = ECurve::new(Params {
+            a: BigInt::from(169_u8),
+            b: BigInt::one(),
+            q: BigInt::from(1099511627689_u64),
+        })
+        .unwrap();
+        let group_order = BigUint::from(1099511891200_u64);
+        let bp = EcPointP::from_affine(&smooth.lift_x(&BigInt::from(3_u8), false).unwrap());
+        let smooth_info =
+            EcInfo::new(bp.clone(), group_order, BigUint::one(), smooth.clone()).unwrap();
+        let bp_order = smooth_info.point_order(&bp).unwrap();
+        let k = BigUint::from(123456789012_u64) % &bp_order;
+        let solution = smooth_info
+            .discrete_log(&smooth.proj_point_mul(&bp, &k))
+            .unwrap();
+        assert_eq!(solution.k, k);
+        // sum of 2sqrt(p_i) for every digit, much less than sqrt(#E) = 2^20
+        assert!(solution.iterations < 1 << 12, "{}", solution.iterations);
+    }
+
+    #[test]
+    fn jacobian_cross_check() {
+        let toy_curve = ECurve::new(Params {
+            a: BigInt::from(11_u8),
+            b: BigInt::from(7_u8),
+            q: BigInt::from(13_u8),
+        })
+        .unwrap();
+        let toy_p1 = EcPointP::new(&BigInt::from(6), &BigInt::from(4), &BigInt::from(1));
+        let p256 = EcInfo::from(PreGeneratedParams::P256);
+        assert_eq!(p256.ecurve.get_coordinates(), CoordinateSystem::Jacobian);
+        assert!(p256.ecurve.is_a_minus_3() && !toy_curve.is_a_minus_3());
+
+        // general a and a = -3 doubling
+        for (curve, p) in [(&toy_curve, &toy_p1), (&p256.ecurve, &p256.bp)] {
+            let pj = EcPointJ::from_projective(p);
+            assert!(curve.check_jacobian_point(&pj));
+            assert_eq!(pj.to_affine(curve).unwrap(), p.to_affine(curve).unwrap());
+            assert_eq!(
+                EcPointJ::from_affine(&p.to_affine(curve).unwrap()),
+                EcPointJ::from_projective(&curve.transform_proj_point(p).unwrap())
+            );
+
+            let mut proj_acc = p.clone();
+            let mut jac_acc = pj.clone();
+            let mut jac_acc_full = pj.clone();
+            // the same point as pj, i.e. (2^2 X : 2^3 Y : 2Z)
+            let pj_scaled = EcPointJ::new(
+                &(BigInt::from(4_u8) * &pj.x),
+                &(BigInt::from(8_u8) * &pj.y),
+                &BigInt::from(2_u8),
+            );
+            for k in 2..30_u32 {
+                proj_acc = curve.proj_point_add(&proj_acc, p);
+                // mixed addition, since pj has Z = 1
+                jac_acc = curve.jacobian_point_add(&jac_acc, &pj);
+                // general addition, since pj_scaled has Z = 2
+                jac_acc_full = curve.jacobian_point_add(&jac_acc_full, &pj_scaled);
+
+                assert_eq!(
+                    jac_acc.to_affine(curve).unwrap(),
+                    proj_acc.to_affine(curve).unwrap(),
+                    "k: {k}"
+                );
+                assert_eq!(
+                    jac_acc_full.to_affine(curve).unwrap(),
+                    proj_acc.to_affine(curve).unwrap(),
+                    "k: {k}"
+                );
+                assert_eq!(
+                    curve
+                        .jacobian_point_mul(&pj, &BigUint::from(k))
+                        .to_affine(curve)
+                        .unwrap(),
+                    proj_acc.to_affine(curve).unwrap(),
+                    "k: {k}"
+                );
+                assert_eq!(
+                    curve
+                        .jacobian_point_double(&jac_acc)
+                        .to_affine(curve)
+                        .unwrap(),
+                    curve
+                        .proj_point_add(&proj_acc, &proj_acc)
+                        .to_affine(curve)
+                        .unwrap(),
+                    "k: {k}"
+                );
+                if !jac_acc.is_inf() {
+                    assert!(curve.check_jacobian_point(&jac_acc));
+                    assert!(curve.check_projective_point(&jac_acc.to_projective(curve)));
+                }
+            }
+        }
+
+        // Jacobian engine gives the same results as projective one
+        let projective_curve = ECurve::new(Params::from(PreGeneratedParams::P256)).unwrap();
+        let k = BigUint::from_str_radix(
+            "7e2b897b8cebc6361663ad410835639826d590f393d90a9538881735256dfae3",
+            16,
+        )
+        .unwrap();
+        assert_eq!(
+            p256.ecurve
+                .proj_point_mul(&p256.bp, &k)
+                .to_affine(&p256.ecurve)
+                .unwrap(),
+            projective_curve
+                .proj_point_mul(&p256.bp, &k)
+                .to_affine(&projective_curve)
+                .unwrap()
+        );
+        assert_eq!(
+            p256.ecurve.proj_point_mul(&p256.bp, &p256.n),
+            EcPointP::neutral()
+        );
+        assert!(EcPointJ::neutral().to_projective(&p256.ecurve).is_inf());
+    }
+
+    #[test]
+    fn hash_to_curve() {
+        let parse = |x: &str| BigInt::from_str_radix(x, 16).unwrap();
+        // DST of 256 bytes
+        let long_dst =
+            |prefix: &str| format!("{prefix}-long-DST-{}", "1".repeat(246 - prefix.len()));
+        let q128 = format!("q128_{}", "q".repeat(128));
+
+        // RFC 9380, appendix K.1 and K.5
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        for (msg, len, expected) in [
+            ("", 32, "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"),
+            (&q128, 32, "b23a1d2b4d97b2ef7785562a7e8bac7eed54ed6e97e29aa51bfe3f12ddad1ff9"),
+            ("abc", 128, "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40"),
+        ] {
+            let bytes = expand_message_xmd(XmdHash::Sha256, msg.as_bytes(), dst, len).unwrap();
+            assert_eq!(bytes.len(), len);
+            assert_eq!(BigInt::from_bytes_be(num_bigint::Sign::Plus, &bytes), parse(expected));
+        }
+        let dst = long_dst("QUUX-V01-CS02-with-expander-SHA256-128");
+        let bytes = expand_message_xmd(XmdHash::Sha256, b"abc", dst.as_bytes(), 32).unwrap();
+        assert_eq!(
+            BigInt::from_bytes_be(num_bigint::Sign::Plus, &bytes),
+            parse("52dbf4f36cf560fca57dedec2ad924ee9c266341d8f3d6afe5171733b16bbb12")
+        );
+        assert!(expand_message_xmd(XmdHash::Sha256, b"", dst.as_bytes(), 256 * 32).is_err());
+
+        let dst = b"QUUX-V01-CS02-with-expander-SHAKE128";
+        for (msg, len, expected) in [
+            ("", 32, "86518c9cd86581486e9485aa74ab35ba150d1c75c88e26b7043e44e2acd735a2"),
+            ("abc", 128, "c952f0c8e529ca8824acc6a4cab0e782fc3648c563ddb00da7399f2ae35654f4860ec671db2356ba7baa55a34a9d7f79197b60ddae6e64768a37d699a78323496db3878c8d64d909d0f8a7de4927dcab0d3dbbc26cb20a49eceb0530b431cdf47bc8c0fa3e0d88f53b318b6739fbed7d7634974f1b5c386d6230c76260d5337a"),
+        ] {
+            let bytes = expand_message_xof(XofHash::Shake128, msg.as_bytes(), dst, len).unwrap();
+            assert_eq!(BigInt::from_bytes_be(num_bigint::Sign::Plus, &bytes), parse(expected));
+        }
+        let dst = long_dst("QUUX-V01-CS02-with-expander-SHAKE128");
+        let bytes = expand_message_xof(XofHash::Shake128, b"", dst.as_bytes(), 32).unwrap();
+        assert_eq!(
+            BigInt::from_bytes_be(num_bigint::Sign::Plus, &bytes),
+            parse("827c6216330a122352312bccc0c8d6e7a146c5257a776dbd9ad9d75cd880fc53")
+        );
+
+        // RFC 9380, appendix J: (suite, msg, P.x, P.y)
+        let vectors = [
+            (
+                PreGeneratedSuite::P256XmdSha256Sswu,
+                "",
+                "2c15230b26dbc6fc9a37051158c95b79656e17a1a920b11394ca91c44247d3e4",
+                "8a7a74985cc5c776cdfe4b1f19884970453912e9d31528c060be9ab5c43e8415",
+            ),
+            (
+                PreGeneratedSuite::P256XmdSha256Sswu,
+                &q128,
+                "4be61ee205094282ba8a2042bcb48d88dfbb609301c49aa8b078533dc65a0b5d",
+                "98f8df449a072c4721d241a3b1236d3caccba603f916ca680f4539d2bfb3c29e",
+            ),
+            (
+                PreGeneratedSuite::P384XmdSha384Sswu,
+                "abc",
+                "e02fc1a5f44a7519419dd314e29863f30df55a514da2d655775a81d413003c4d4e7fd59af0826dfaad4200ac6f60abe1",
+                "01f638d04d98677d65bef99aef1a12a70a4cbb9270ec55248c04530d8bc1f8f90f8a6a859a7c1f1ddccedf8f96d675f6",
+            ),
+            (
+                PreGeneratedSuite::P521XmdSha512Sswu,
+                "",
+                "00fd767cebb2452030358d0e9cf907f525f50920c8f607889a6a35680727f64f4d66b161fafeb2654bea0d35086bec0a10b30b14adef3556ed9f7f1bc23cecc9c088",
+                "0169ba78d8d851e930680322596e39c78f4fe31b97e57629ef6460ddd68f8763fd7bd767a4e94a80d3d21a3c2ee98347e024fc73ee1c27166dc3fe5eeef782be411d",
+            ),
+            (
+                PreGeneratedSuite::Secp256k1XmdSha256Sswu,
+                "",
+                "c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346",
+                "64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067",
+            ),
+            (
+                PreGeneratedSuite::Secp256k1XmdSha256Sswu,
+                "abc",
+                "3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b",
+                "7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6",
+            ),
+        ];
+        for (suite, msg, x, y) in vectors {
+            let dst = format!("QUUX-V01-CS02-with-{}", suite.suite_id(true));
+            let p = HashToCurveSuite::from(suite)
+                .hash_to_curve(msg.as_bytes(), dst.as_bytes())
+                .unwrap();
+            assert_eq!(p, EcPointA::new(&parse(x), &parse(y)), "{dst}, msg {msg}");
+        }
+
+        let p256 = HashToCurveSuite::from(PreGeneratedSuite::P256XmdSha256Sswu);
+        let dst = b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_RO_";
+        let u = hash_to_field(
+            b"abc",
+            dst,
+            2,
+            &parse("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"),
+            p256.l,
+            p256.expander,
+        )
+        .unwrap();
+        assert_eq!(
+            u[0],
+            parse("afe47f2ea2b10465cc26ac403194dfb68b7f5ee865cda61e9f3e07a537220af1")
+        );
+        assert_eq!(
+            u[1],
+            parse("379a27833b0bfe6f7bdca08e1e83c760bf9a338ab335542704edcd69ce9e46e0")
+        );
+        assert_eq!(
+            p256.map_to_curve(&u[0]).unwrap(),
+            EcPointA::new(
+                &parse("5219ad0ddef3cc49b714145e91b2f7de6ce0a7a7dc7406c7726c7e373c58cb48"),
+                &parse("7950144e52d30acbec7b624c203b1996c99617d0b61c2442354301b191d93ecf")
+            )
+        );
+        let dst = b"QUUX-V01-CS02-with-P256_XMD:SHA-256_SSWU_NU_";
+        assert_eq!(
+            p256.encode_to_curve(b"", dst).unwrap(),
+            EcPointA::new(
+                &parse("f871caad25ea3b59c16cf87c1894902f7e7b2c822c3d3f73596c5ace8ddd14d1"),
+                &parse("87b9ae23335bee057b99bac1e68588b18b5691af476234b8971bc4f011ddc99b")
+            )
+        );
+
+        // edwards25519 suites use the same Elligator 2 map, the result is moved to edwards25519
+        // with (u, v) -> (sqrt(-486664) * u / v, (u - 1) / (u + 1)), where sgn0(sqrt(-486664)) = 0
+        let suite = HashToCurveSuite::from(PreGeneratedSuite::Curve25519XmdSha512Ell2);
+        let montgomery =
+            MontgomeryCurve::from(PreGeneratedParams::Curve25519.montgomery_form().unwrap());
+        let q = montgomery.get_q();
+        let c1 = sqrt_mod(&BigInt::from(-486664), &q).unwrap();
+        let c1 = match c1.bit(0) {
+            true => &q - c1,
+            false => c1,
+        };
+        let to_edwards = |p: &EcPointA| {
+            let p = montgomery.from_weierstrass_point(p).unwrap();
+            let x = &c1 * &p.get_x() * inverse(&p.get_y(), &q).unwrap();
+            let y = (p.get_x() - 1) * inverse(&(p.get_x() + 1), &q).unwrap();
+            (
+                take_by_bigint_module(&(x % &q), &q),
+                take_by_bigint_module(&(y % &q), &q),
+            )
+        };
+        for (random_oracle, msg, x, y) in [
+            (
+                true,
+                "",
+                "3c3da6925a3c3c268448dcabb47ccde5439559d9599646a8260e47b1e4822fc6",
+                "09a6c8561a0b22bef63124c588ce4c62ea83a3c899763af26d795302e115dc21",
+            ),
+            (
+                true,
+                "abc",
+                "608040b42285cc0d72cbb3985c6b04c935370c7361f4b7fbdb1ae7f8c1a8ecad",
+                "1a8395b88338f22e435bbd301183e7f20a5f9de643f11882fb237f88268a5531",
+            ),
+            (
+                false,
+                "",
+                "1ff2b70ecf862799e11b7ae744e3489aa058ce805dd323a936375a84695e76da",
+                "222e314d04a4d5725e9f2aff9fb2a6b69ef375a1214eb19021ceab2d687f0f9b",
+            ),
+        ] {
+            let p = match random_oracle {
+                true => suite.hash_to_curve(
+                    msg.as_bytes(),
+                    b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_RO_",
+                ),
+                false => suite.encode_to_curve(
+                    msg.as_bytes(),
+                    b"QUUX-V01-CS02-with-edwards25519_XMD:SHA-512_ELL2_NU_",
+                ),
+            }
+            .unwrap();
+            assert!(suite.info.is_in_subgroup(&p.to_projective()));
+            assert_eq!(to_edwards(&p), (parse(x), parse(y)));
+        }
+
+        // RFC 9380, appendix J.7: curve25519 suites, (u, v) in Montgomery model
+        for (random_oracle, msg, u, v) in [
+            (
+                true,
+                "",
+                "2de3780abb67e861289f5749d16d3e217ffa722192d16bbd9d1bfb9d112b98c0",
+                "3b5dc2a498941a1033d176567d457845637554a2fe7a3507d21abd1c1bd6e878",
+            ),
+            (
+                true,
+                "abc",
+                "2b4419f1f2d48f5872de692b0aca72cc7b0a60915dd70bde432e826b6abc526d",
+                "1b8235f255a268f0a6fa8763e97eb3d22d149343d495da1160eff9703f2d07dd",
+            ),
+            (
+                true,
+                "abcdef0123456789",
+                "68ca1ea5a6acf4e9956daa101709b1eee6c1bb0df1de3b90d4602382a104c036",
+                "2a375b656207123d10766e68b938b1812a4a6625ff83cb8d5e86f58a4be08353",
+            ),
+            (
+                true,
+                &q128,
+                "096e9c8bae6c06b554c1ee69383bb0e82267e064236b3a30608d4ed20b73ac5a",
+                "1eb5a62612cafb32b16c3329794645b5b948d9f8ffe501d4e26b073fef6de355",
+            ),
+            (
+                false,
+                "",
+                "1bb913f0c9daefa0b3375378ffa534bda5526c97391952a7789eb976edfe4d08",
+                "4548368f4f983243e747b62a600840ae7c1dab5c723991f85d3a9768479f3ec4",
+            ),
+            (
+                false,
+                "abc",
+                "7c22950b7d900fa866334262fcaea47a441a578df43b894b4625c9b450f9a026",
+                "5547bc00e4c09685dcbc6cb6765288b386d8bdcb595fa5a6e3969e08097f0541",
+            ),
+        ] {
+            let dst = format!(
+                "QUUX-V01-CS02-with-{}",
+                PreGeneratedSuite::Curve25519XmdSha512Ell2.suite_id(random_oracle)
+            );
+            let p = match random_oracle {
+                true => suite.hash_to_curve(msg.as_bytes(), dst.as_bytes()),
+                false => suite.encode_to_curve(msg.as_bytes(), dst.as_bytes()),
+            }
+            .unwrap();
+            assert!(suite.info.is_in_subgroup(&p.to_projective()));
+            let p = montgomery.from_weierstrass_point(&p).unwrap();
+            assert_eq!(
+                (p.get_x(), p.get_y()),
+                (parse(u), parse(v)),
+                "{dst}, msg {msg}"
+            );
+        }
+    }
+
+    #[test]
+    fn binary_field_curves() {
+        // AES field, x^8 + x^4 + x^3 + x + 1
+        let gf256 = BinaryField::new(8, &[4, 3, 1]).unwrap();
+        let e = |x: u16| BigInt::from(x);
+        assert_eq!(gf256.mul(&e(0x57), &e(0x83)), e(0xc1));
+        assert_eq!(gf256.inverse(&e(0x53)).unwrap(), e(0xca));
+        assert!(gf256.inverse(&e(0)).is_err());
+        for x in 1..256 {
+            let x = e(x);
+            assert!(gf256.mul(&x, &gf256.inverse(&x).unwrap()).is_one());
+            assert_eq!(gf256.square(&x), gf256.mul(&x, &x));
+            assert_eq!(gf256.sqrt(&gf256.square(&x)), x);
+            assert_eq!(gf256.pow(&x, &e(255)), e(1));
+            // even m, IEEE 1363 method
+            match gf256.solve_quadratic(&x) {
+                Some(z) => {
+                    assert_eq!(gf256.trace(&x), 0);
+                    assert_eq!(gf256.square(&z) ^ z, x);
+                }
+                None => assert_eq!(gf256.trace(&x), 1),
+            }
+        }
+        // x^4 + x^2 + 1 = (x^2 + x + 1)^2
+        assert!(BinaryField::new(4, &[2]).is_err());
+        assert!(BinaryField::new(4, &[4]).is_err());
+        for m in [163, 167, 173, 179, 191, 233, 257, 307, 367, 431] {
+            assert_eq!(BinaryField::dstu4145(m).unwrap().get_m(), m);
+        }
+        assert!(BinaryField::dstu4145(160).is_err());
+
+        let field = BinaryField::new(4, &[1]).unwrap();
+        assert!(BinaryCurve::new(&e(1), &e(0), field.clone()).is_err());
+        let toy = BinaryCurve::new(&e(8), &e(9), field).unwrap();
+        // all points of y^2 + xy = x^3 + x^3 * x^2 + (x^3 + 1) over GF(2^4)
+        let mut points = vec![];
+        for x in 0..16 {
+            for y in 0..16 {
+                let p = EcPointA::new(&e(x), &e(y));
+                if toy.check_affine_point(&p) {
+                    points.push(p);
+                }
+            }
+        }
+        let order = BigUint::from(points.len() + 1);
+        for p in &points {
+            assert!(toy.affine_point_mul(p, &order).unwrap().is_inf());
+            let odd = toy
+                .get_field()
+                .mul(
+                    &p.get_y(),
+                    &toy.get_field().inverse(&p.get_x()).unwrap_or_default(),
+                )
+                .bit(0);
+            assert_eq!(&toy.lift_x(&p.get_x(), odd).unwrap(), p);
+            for q in &points {
+                let sum = toy.affine_point_add(p, q).unwrap();
+                assert!(toy.check_affine_point(&sum));
+                let ld = toy.ld_point_add(
+                    &toy.ld_point_double(&EcPointLD::from_affine(p)),
+                    &EcPointLD::from_affine(q),
+                );
+                assert!(toy.check_ld_point(&ld));
+                assert_eq!(
+                    ld.to_affine(&toy).unwrap(),
+                    toy.affine_point_add(&toy.affine_point_double(p).unwrap(), q)
+                        .unwrap()
+                );
+            }
+            let minus_p = toy.affine_point_negative(p);
+            assert!(toy.affine_point_add(p, &minus_p).unwrap().is_inf());
+            assert_eq!(
+                toy.ld_to_affine(&toy.ld_point_negative(&EcPointLD::from_affine(p)))
+                    .unwrap(),
+                minus_p
+            );
+        }
+
+        for params in [
+            PreGeneratedBinaryParams::K163,
+            PreGeneratedBinaryParams::B163,
+            PreGeneratedBinaryParams::K233,
+            PreGeneratedBinaryParams::B233,
+            PreGeneratedBinaryParams::K283,
+            PreGeneratedBinaryParams::B283,
+            PreGeneratedBinaryParams::K409,
+            PreGeneratedBinaryParams::B409,
+            PreGeneratedBinaryParams::K571,
+            PreGeneratedBinaryParams::B571,
+            PreGeneratedBinaryParams::Dstu4145M163,
+        ] {
+            // n * bp = O is checked by BinaryEcInfo::new
+            let info = BinaryEcInfo::from(&params);
+            let curve = &info.curve;
+            let f = curve.get_field();
+            let bp = &info.bp;
+            let odd = f.mul(&bp.get_y(), &f.inverse(&bp.get_x()).unwrap()).bit(0);
+            assert_eq!(&curve.lift_x(&bp.get_x(), odd).unwrap(), bp, "{params:?}");
+
+            let (k1, k2) = (
+                BigUint::from(0xdeadbeef_u32) << 100,
+                &info.n - BigUint::from(12345_u32),
+            );
+            let p1 = curve.ld_point_mul(&EcPointLD::from_affine(bp), &k1);
+            let p2 = curve.ld_point_mul(&EcPointLD::from_affine(bp), &k2);
+            assert!(curve.check_ld_point(&p1) && curve.check_ld_point(&p2));
+            let sum = curve.ld_point_add(&p1, &p2).to_affine(curve).unwrap();
+            assert_eq!(sum, info.base_point_mul(&((&k1 + &k2) % &info.n)).unwrap());
+            assert_eq!(
+                info.base_point_mul(&(&info.n - 1_u8)).unwrap(),
+                curve.affine_point_negative(bp)
+            );
+        }
+
+        let dstu = BinaryEcInfo::from(PreGeneratedBinaryParams::Dstu4145M163);
+        assert!(BinaryEcInfo::new(
+            dstu.bp.clone(),
+            &dstu.n - 1_u8,
+            dstu.h.clone(),
+            dstu.curve.clone()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn pairings() {
+        let fp12 = |c: [&str; 12]| {
+            let fp2 = |i: usize| {
+                Fp2::new(
+                    &BigInt::from_str_radix(c[2 * i], 16).unwrap(),
+                    &BigInt::from_str_radix(c[2 * i + 1], 16).unwrap(),
+                )
+            };
+            let fp6 = |i: usize| Fp6 {
+                c0: fp2(3 * i),
+                c1: fp2(3 * i + 1),
+                c2: fp2(3 * i + 2),
+            };
+            Fp12 {
+                c0: fp6(0),
+                c1: fp6(1),
+            }
+        };
+        // e(g1, g2) computed with arkworks 0.4
+        let vectors = [
+            (
+                PreGeneratedPairingParams::Bn254,
+                fp12([
+                    "262b253feda94cfe0da01bde280a3ed6f87e5feb898578b55e1f63739d870e95",
+                    "2e02d2cc795a2000a1b1f823879abbd397c4dea0918ed66b49d34b48efb8a4a",
+                    "13a9f2d6e29b128da5b1ad44b31977935fd2957387ecb1fc4e135402fdbd1de0",
+                    "40ba9fa500f1a5c4b31984a74e68659c4b420bd699ce630b130b08a6ea1162b",
+                    "afc2f3fd870678fbe359d7f9873f052478f590b211ce30bf5e3eeaef89eafdb",
+                    "1c54a530398c9064bdc662d929e645cadda9a712cc5a8243f9cddbd2d98dd1f0",
+                    "95c0fbf5d5a1ac023794a0d856f92591ba990ecfd4b7aef5c0d58c5dc2429fe",
+                    "14d3d6ca72d8a950a31dc10f7b4053c9e9ad9ebb590cb4a60f8215d4b99f2b4a",
+                    "1dc0e7bbc3d70e6689dc206b4b91c85759dc1a23043c585fdfaf545838ca7429",
+                    "b53320e5a6488cb98a855ffc837d2a75ab90d61ac16cc1b7ab2cd3ed5e22b97",
+                    "13a8afd3085dae4c6c91476ef36cd1d318ce07bac42a9c0f9bd7fddaf5ebd723",
+                    "f97b5221474526b601f3730a3afa965ceee1b343940c383e5314859e762c97",
+                ]),
+            ),
+            (
+                PreGeneratedPairingParams::Bls12_381,
+                fp12([
+                    "1250ebd871fc0a92a7b2d83168d0d727272d441befa15c503dd8e90ce98db3e7b6d194f60839c508a84305aaca1789b6",
+                    "89a1c5b46e5110b86750ec6a532348868a84045483c92b7af5af689452eafabf1a8943e50439f1d59882a98eaa0170f",
+                    "1368bb445c7c2d209703f239689ce34c0378a68e72a6b3b216da0e22a5031b54ddff57309396b38c881c4c849ec23e87",
+                    "193502b86edb8857c273fa075a50512937e0794e1e65a7617c90d8bd66065b1fffe51d7a579973b1315021ec3c19934f",
+                    "1b2f522473d171391125ba84dc4007cfbf2f8da752f7c74185203fcca589ac719c34dffbbaad8431dad1c1fb597aaa5",
+                    "18107154f25a764bd3c79937a45b84546da634b8f6be14a8061e55cceba478b23f7dacaa35c8ca78beae9624045b4b6",
+                    "19f26337d205fb469cd6bd15c3d5a04dc88784fbb3d0b2dbdea54d43b2b73f2cbb12d58386a8703e0f948226e47ee89d",
+                    "6fba23eb7c5af0d9f80940ca771b6ffd5857baaf222eb95a7d2809d61bfe02e1bfd1b68ff02f0b8102ae1c2d5d5ab1a",
+                    "11b8b424cd48bf38fcef68083b0b0ec5c81a93b330ee1a677d0d15ff7b984e8978ef48881e32fac91b93b47333e2ba57",
+                    "3350f55a7aefcd3c31b4fcb6ce5771cc6a0e9786ab5973320c806ad360829107ba810c5a09ffdd9be2291a0c25a99a2",
+                    "4c581234d086a9902249b64728ffd21a189e87935a954051c7cdba7b3872629a4fafc05066245cb9108f0242d0fe3ef",
+                    "f41e58663bf08cf068672cbd01a7ec73baca4d72ca93544deff686bfd6df543d48eaa24afe47e1efde449383b676631",
+                ]),
+            ),
+        ];
+        for (params, expected) in vectors {
+            let info = PairingInfo::from(&params);
+            let tower = info.twist.get_tower();
+            let g1 = info.g1.bp.to_affine(&info.g1.ecurve).unwrap();
+            let e = info.pairing(&g1, &info.g2).unwrap();
+            // hard part of final exponentiation in arkworks gives e(P, Q)^(2u(6u^2 + 3u + 1))
+            // for BN curves and e(P, Q)^3 for BLS12 curves
+            let k = match &info.family {
+                PairingFamily::Bn { u } => 2 * u * (6 * u * u + 3 * u + 1),
+                PairingFamily::Bls12 { .. } => BigInt::from(3_u8),
+            };
+            assert_eq!(tower.fp12_pow(&e, &k), expected, "{params:?}");
+            assert!(!e.is_one());
+            let n = BigInt::from(info.g1.n.clone());
+            assert!(tower.fp12_pow(&e, &n).is_one());
+
+            // e(aP, bQ) = e(P, Q)^(ab)
+            let (a, b) = (BigUint::from(0xdeadbeef_u32), &info.g1.n - 7_u8);
+            let ap = info
+                .g1
+                .base_point_mul(&a)
+                .to_affine(&info.g1.ecurve)
+                .unwrap();
+            let bq = info.g2_mul(&b).unwrap();
+            assert_eq!(
+                info.pairing(&ap, &bq).unwrap(),
+                tower.fp12_pow(&e, &BigInt::from((&a * &b) % &info.g1.n))
+            );
+            // e(-P, Q) = e(P, Q)^(-1), e(P, Q + Q) = e(P, Q)^2
+            assert_eq!(
+                info.pairing(&g1.negative(), &info.g2).unwrap(),
+                tower.fp12_conjugate(&e)
+            );
+            let q2 = info.twist.point_double(&info.g2).unwrap();
+            assert_eq!(info.pairing(&g1, &q2).unwrap(), tower.fp12_square(&e));
+            assert!(info
+                .pairing(&g1, &EcPointTwist::neutral())
+                .unwrap()
+                .is_one());
+
+            // Frobenius endomorphism acts on G2 as multiplication by p
+            let p = BigUint::try_from(tower.get_p()).unwrap();
+            assert_eq!(
+                info.twist.frobenius(&info.g2),
+                info.g2_mul(&(p % &info.g1.n)).unwrap()
+            );
+
+            let off_curve = EcPointA::new(&g1.get_x(), &(g1.get_y() + 1));
+            assert!(info.pairing(&off_curve, &info.g2).is_err());
+            let off_twist = EcPointTwist::new(&info.g2.x, &info.g2.x);
+            assert!(info.pairing(&g1, &off_twist).is_err());
+        }
+
+        // (0, 2) lies on BLS12-381, but not in G1
+        let info = PairingInfo::from(PreGeneratedPairingParams::Bls12_381);
+        let p = EcPointA::new(&BigInt::zero(), &BigInt::from(2_u8));
+        assert!(info.g1.ecurve.check_affine_point(&p));
+        assert!(info.pairing(&p, &info.g2).is_err());
+    }
+
+    #[test]
+    fn curve_traits() {
+        fn check_field<F: Field>(f: &F, a: &F::Element, b: &F::Element) {
+            let ab = f.mul(a, b);
+            assert_eq!(f.mul(&ab, &f.inverse(b).unwrap()), *a);
+            assert_eq!(f.add(&f.sub(a, b), b), *a);
+            assert!(f.is_zero(&f.add(a, &f.neg(a))));
+            assert_eq!(f.pow(a, &BigUint::from(3_u8)), f.mul(&f.square(a), a));
+            assert!(f.inverse(&f.zero()).is_err());
+        }
+        check_field(
+            &BigPrimeField::new(&BigInt::from(1_000_000_007_u64)),
+            &BigInt::from(123456789_u32),
+            &BigInt::from(987654321_u32),
+        );
+        let gf = BinaryField::dstu4145(163).unwrap();
+        check_field(&gf, &BigInt::from(0xdeadbeef_u32), &(BigInt::one() << 150));
+        assert_eq!(Field::bits(&gf), 163);
+        assert_eq!(
+            Field::bits(&BigPrimeField::new(&BigInt::from(1_000_000_007_u64))),
+            30
+        );
+
+        fn check_group<D: DomainParameters>(info: &D) {
+            let curve = info.curve();
+            let g = info.generator();
+            let (a, b) = (BigUint::from(0xdeadbeef_u32), &info.order() - 12345_u32);
+            let (ag, bg) = (info.base_point_mul(&a), info.base_point_mul(&b));
+            assert!(curve.contains(&ag) && curve.contains(&bg));
+            assert!(curve.is_identity(&info.base_point_mul(&info.order())));
+            assert!(curve.point_eq(
+                &curve.add(&ag, &bg),
+                &info.base_point_mul(&((&a + &b) % info.order()))
+            ));
+            assert!(curve.point_eq(&curve.double(&ag), &curve.mul(&g, &(&a * 2_u8))));
+            assert!(curve.is_identity(&curve.add(&ag, &curve.neg(&ag))));
+            assert!(curve.point_eq(&curve.sub(&ag, &ag), &curve.identity()));
+            assert!(curve.point_eq(
+                &curve.multi_mul(&[(g.clone(), a.clone()), (ag.clone(), b.clone())]),
+                &curve.mul(&g, &((&a + &a * &b) % info.order()))
+            ));
+            assert!(curve.point_eq(&info.secret_mul(&g, &a), &ag));
+            let (x, y) = curve.to_affine(&curve.normalize(&ag).unwrap()).unwrap();
+            assert_eq!(curve.to_affine(&ag).unwrap(), (x, y));
+            assert!(info.validate_point(&ag).is_ok());
+            assert!(info.validate_point(&curve.identity()).is_err());
+        }
+        check_group(&EcInfo::from(PreGeneratedParams::P256));
+        check_group(&EcInfo::from(PreGeneratedParams::Secp256k1));
+        check_group(&BinaryEcInfo::from(PreGeneratedBinaryParams::B163));
+        check_group(&BinaryEcInfo::from(PreGeneratedBinaryParams::K233));
+        check_group(&EdwardsEcInfo::from(
+            PreGeneratedEdwardsParams::Edwards25519,
+        ));
+
+        // small order point of Edwards25519 doesn't pass validation
+        let ed = EdwardsEcInfo::from(PreGeneratedEdwardsParams::Edwards25519);
+        let order_2 = EdPointE::from_affine(&BigInt::zero(), &(ed.curve.get_q() - 1_u8));
+        assert!(ed.curve.contains(&order_2));
+        assert!(ed.validate_point(&order_2).is_err());
+
+        let p256 = EcInfo::from(PreGeneratedParams::P256);
+        let p = p256.base_point_mul(&BigUint::from(777_u16));
+        let decoded = p256
+            .ecurve
+            .decode(&p256.ecurve.encode(&p).unwrap())
+            .unwrap();
+        assert!(p256.ecurve.point_eq(&decoded, &p));
+        assert!(p256.ecurve.decode(&[0x02; 10]).is_err());
+    }
+
+    #[test]
+    fn curve_point_operators() {
+        let p192 = Arc::new(EcInfo::from(PreGeneratedParams::P192));
+        let g = CurvePoint::generator(&p192);
+        let (a, b) = (BigUint::from(0xdeadbeef_u32), BigUint::from(0xcafebabe_u32));
+        let (ag, bg) = (&g * &a, &g * &b);
+        assert_eq!(&ag + &bg, &g * &(&a + &b));
+        assert_eq!(
+            &ag - &bg,
+            &g * &(BigInt::from(a.clone()) - BigInt::from(b.clone()))
+        );
+        assert_eq!(-&ag, &g * &-BigInt::from(a.clone()));
+        assert!((&ag - &ag).is_identity());
+        assert_eq!(&g * &p192.n, CurvePoint::identity(&p192));
+        assert_eq!(
+            CurvePoint::new(&p192, &p192.ecurve.proj_point_mul(&p192.bp, &a)).unwrap(),
+            ag
+        );
+        assert!(CurvePoint::new(
+            &p192,
+            &EcPointP::new(&BigInt::one(), &BigInt::one(), &BigInt::one())
+        )
+        .is_err());
+
+        let mut acc = ag.clone();
+        acc += &bg;
+        acc -= bg.clone();
+        acc += g.clone();
+        assert_eq!(acc, ag.clone() + g.clone());
+        let points = vec![g.clone(), ag.clone(), bg.clone()];
+        assert_eq!(CurvePoint::sum(&p192, &points).unwrap(), &g + &(&ag + &bg));
+        assert_eq!(points.iter().sum::<CurvePoint>(), &g * &(&a + &b + 1_u8));
+        assert_eq!(
+            points.iter().cloned().sum::<Option<CurvePoint>>(),
+            Some(&g * &(&a + &b + 1_u8))
+        );
+        assert_eq!(
+            Vec::<CurvePoint>::new().into_iter().sum::<Option<_>>(),
+            None
+        );
+        assert!(
+            std::panic::catch_unwind(|| Vec::<CurvePoint>::new().iter().sum::<CurvePoint>())
+                .is_err()
+        );
+        assert_eq!(
+            CurvePoint::sum(&p192, points).unwrap(),
+            &g * &(&a + &b + 1_u8)
+        );
+        assert!(CurvePoint::sum(&p192, Vec::<CurvePoint>::new())
+            .unwrap()
+            .is_identity());
+
+        // equal parameters in different Arc are the same curve, other ones are rejected
+        let p192_copy = Arc::new(EcInfo::from(PreGeneratedParams::P192));
+        assert_eq!(
+            CurvePoint::generator(&p192_copy) + g.clone(),
+            &g * &BigUint::from(2_u8)
+        );
+        let other = CurvePoint::generator(&Arc::new(EcInfo::from(PreGeneratedParams::P256)));
+        assert!(!other.is_same_curve(&g));
+        assert_ne!(other, g);
+        assert!(matches!(
+            g.checked_add(&other),
+            Err(EcError::CurveMismatch(_))
+        ));
+        assert!(matches!(
+            g.checked_sub(&other),
+            Err(EcError::CurveMismatch(_))
+        ));
+        assert!(std::panic::catch_unwind(|| &g + &other).is_err());
+        assert!(matches!(
+            CurvePoint::sum(&p192, [&g, &other]),
+            Err(EcError::CurveMismatch(_))
+        ));
+        assert_eq!([&g, &other].into_iter().sum::<Option<_>>(), None);
+        assert!(std::panic::catch_unwind(|| [&g, &other].into_iter().sum::<CurvePoint>()).is_err());
+
+        // the same curve with other settings of scalar multiplication is the same curve
+        let p256 = Arc::new(EcInfo::from(PreGeneratedParams::P256));
+        let p256_wnaf = Arc::new(
+            EcInfo::new(
+                p256.bp.clone(),
+                p256.n.clone(),
+                p256.h.clone(),
+                p256.ecurve
+                    .clone()
+                    .with_wnaf(5)
+                    .unwrap()
+                    .with_coordinates(CoordinateSystem::Jacobian),
+            )
+            .unwrap(),
+        );
+        assert_ne!(*p256, *p256_wnaf);
+        let (g256, g256_wnaf) = (
+            CurvePoint::generator(&p256),
+            CurvePoint::generator(&p256_wnaf),
+        );
+        assert!(g256.is_same_curve(&g256_wnaf));
+        assert_eq!(g256, g256_wnaf);
+        assert_eq!(&g256 + &g256_wnaf, &g256_wnaf * &BigUint::from(2_u8));
+        assert_eq!(
+            [g256.clone(), &g256_wnaf * &a]
+                .into_iter()
+                .sum::<CurvePoint>(),
+            &g256 * &(&a + 1_u8)
+        );
+        assert!((&g256 - &g256_wnaf).is_identity());
+
+        let b163 = Arc::new(BinaryEcInfo::from(PreGeneratedBinaryParams::B163));
+        let bg = CurvePoint::generator(&b163);
+        assert_eq!(&bg * &a + &bg * &b, &bg * &(&a + &b));
+        assert!((&bg * &b163.n).is_identity());
+    }
 }